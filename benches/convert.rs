@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_convert_2(c: &mut Criterion, size: usize) {
+    let small = (0u32, 1u32);
+    c.bench_function(&format!("from_vec_small_2x_{}", size), |b| {
+        b.iter_batched(
+            || [small].repeat(size),
+            |vec| black_box(ParallelVec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("into_vec_small_2x_{}", size), |b| {
+        b.iter_batched(
+            || ParallelVec::from(vec![small]).repeat(size),
+            |vec| black_box(Vec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+    let mixed = ([0u64; 32], 1u32);
+    c.bench_function(&format!("from_vec_mixed_2x_{}", size), |b| {
+        b.iter_batched(
+            || [mixed].repeat(size),
+            |vec| black_box(ParallelVec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("into_vec_mixed_2x_{}", size), |b| {
+        b.iter_batched(
+            || ParallelVec::from(vec![mixed]).repeat(size),
+            |vec| black_box(Vec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_convert_4(c: &mut Criterion, size: usize) {
+    let small = (0u32, 1u32, 2u32, 3u32);
+    c.bench_function(&format!("from_vec_small_4x_{}", size), |b| {
+        b.iter_batched(
+            || [small].repeat(size),
+            |vec| black_box(ParallelVec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("into_vec_small_4x_{}", size), |b| {
+        b.iter_batched(
+            || ParallelVec::from(vec![small]).repeat(size),
+            |vec| black_box(Vec::from(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_convert_2(c, size);
+        bench_convert_4(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -29,7 +29,7 @@ impl Inc for Big {
 fn bench_get_2(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1));
     let mut rng = rand::thread_rng();
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("get_vec_small_2x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -48,7 +48,7 @@ fn bench_get_2(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1));
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("get_vec_mixed_2x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -67,7 +67,7 @@ fn bench_get_2(c: &mut Criterion, size: usize) {
         })
     });
     let big = (Big::default(), Big::default());
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("get_vec_big_2x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -90,7 +90,7 @@ fn bench_get_2(c: &mut Criterion, size: usize) {
 fn bench_get_3(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2));
     let mut rng = rand::thread_rng();
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("get_vec_small_3x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -111,7 +111,7 @@ fn bench_get_3(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1), Big::default());
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("get_vec_mixed_3x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -132,7 +132,7 @@ fn bench_get_3(c: &mut Criterion, size: usize) {
         })
     });
     let big = (Big::default(), Big::default(), Big::default());
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("get_vec_big_3x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -157,7 +157,7 @@ fn bench_get_3(c: &mut Criterion, size: usize) {
 fn bench_get_4(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2), Small(3));
     let mut rng = rand::thread_rng();
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("get_vec_small_4x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -180,7 +180,7 @@ fn bench_get_4(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1), Big::default(), Small(2));
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("get_vec_mixed_4x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -208,7 +208,7 @@ fn bench_get_4(c: &mut Criterion, size: usize) {
         Big::default(),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("get_vec_big_4x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -235,7 +235,7 @@ fn bench_get_4(c: &mut Criterion, size: usize) {
 fn bench_get_5(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2), Small(3), Small(4));
     let mut rng = rand::thread_rng();
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("get_vec_small_5x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d, e)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -266,7 +266,7 @@ fn bench_get_5(c: &mut Criterion, size: usize) {
         Small(2),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("get_vec_mixed_5x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d, e)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -297,7 +297,7 @@ fn bench_get_5(c: &mut Criterion, size: usize) {
         Big::default(),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("get_vec_big_5x_{}", size), |b| {
         b.iter(|| {
             if let Some((a, b, c, d, e)) = black_box(vec.get_mut(rng.next_u32() as usize % size)) {
@@ -323,12 +323,37 @@ fn bench_get_5(c: &mut Criterion, size: usize) {
     });
 }
 
+fn bench_get_unchecked_2(c: &mut Criterion, size: usize) {
+    let small = (Small(0), Small(1));
+    let mut rng = rand::thread_rng();
+    let mut vec = [small].repeat(size);
+    c.bench_function(&format!("get_unchecked_vec_small_2x_{}", size), |b| {
+        b.iter(|| {
+            let (a, b) = unsafe { vec.get_unchecked_mut(rng.next_u32() as usize % size) };
+            black_box(a).inc();
+            black_box(b).inc();
+        })
+    });
+    let mut vec = ParallelVec::from(vec![small]).repeat(size);
+    c.bench_function(
+        &format!("get_unchecked_parallelvec_small_2x_{}", size),
+        |b| {
+            b.iter(|| {
+                let (a, b) = unsafe { vec.get_unchecked_mut(rng.next_u32() as usize % size) };
+                black_box(a).inc();
+                black_box(b).inc();
+            })
+        },
+    );
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     for size in [1000, 100000, 1000000] {
         bench_get_2(c, size);
         bench_get_3(c, size);
         bench_get_4(c, size);
         bench_get_5(c, size);
+        bench_get_unchecked_2(c, size);
     }
 }
 
@@ -0,0 +1,210 @@
+use crate::{ParallelParam, ParallelVec};
+use alloc::collections::BTreeMap;
+use core::borrow::Borrow;
+
+/// A keyed collection of [`ParallelVec`]s, grouping rows by an external key.
+///
+/// This is the archetype storage pattern: every group stores its rows
+/// contiguously in its own [`ParallelVec`], and rows can be moved between
+/// groups with [`move_row`]. Removing or moving a row uses swap removal, so
+/// the last row in the source group is moved into the vacated index.
+///
+/// Groups are kept even when emptied so that their allocations can be reused.
+/// Use [`remove_group`] to release a group.
+///
+/// [`move_row`]: Self::move_row
+/// [`remove_group`]: Self::remove_group
+pub struct ArchetypeMap<K, Param: ParallelParam> {
+    groups: BTreeMap<K, ParallelVec<Param>>,
+}
+
+impl<K: Ord, Param: ParallelParam> ArchetypeMap<K, Param> {
+    /// Constructs a new, empty `ArchetypeMap`.
+    pub fn new() -> Self {
+        Self {
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the total number of rows across all groups.
+    pub fn len(&self) -> usize {
+        self.groups.values().map(|group| group.len()).sum()
+    }
+
+    /// Returns `true` if there are no rows in any group.
+    pub fn is_empty(&self) -> bool {
+        self.groups.values().all(|group| group.is_empty())
+    }
+
+    /// Returns the number of groups, including empty ones.
+    pub fn group_count(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Appends a row to the group for `key`, creating the group if it does not
+    /// exist.
+    ///
+    /// Returns the index of the row within the group.
+    pub fn push(&mut self, key: K, value: Param) -> usize {
+        let group = self.groups.entry(key).or_default();
+        group.push(value);
+        group.len() - 1
+    }
+
+    /// Removes the row at `index` from the group for `key` and returns it.
+    ///
+    /// The removed row is replaced by the last row of the group.
+    ///
+    /// Returns `None` if the group does not exist or `index` is out of bounds.
+    pub fn swap_remove<Q>(&mut self, key: &Q, index: usize) -> Option<Param>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let group = self.groups.get_mut(key)?;
+        if index >= group.len() {
+            return None;
+        }
+        Some(group.swap_remove(index))
+    }
+
+    /// Moves the row at `index` in the group for `from` to the end of the group
+    /// for `to`, creating the destination group if it does not exist.
+    ///
+    /// The moved row is replaced in the source group by the last row of the
+    /// source group. Returns the index of the row within the destination group,
+    /// or `None` if the source group does not exist or `index` is out of bounds.
+    pub fn move_row<Q>(&mut self, from: &Q, index: usize, to: K) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if from == to.borrow() {
+            let group = self.groups.get(from)?;
+            return (index < group.len()).then_some(index);
+        }
+        let value = self.swap_remove(from, index)?;
+        Some(self.push(to, value))
+    }
+
+    /// Gets the group for `key`, if it exists.
+    pub fn group<Q>(&self, key: &Q) -> Option<&ParallelVec<Param>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.groups.get(key)
+    }
+
+    /// Gets a mutable reference to the group for `key`, if it exists.
+    pub fn group_mut<Q>(&mut self, key: &Q) -> Option<&mut ParallelVec<Param>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.groups.get_mut(key)
+    }
+
+    /// Removes the group for `key` and returns its rows.
+    pub fn remove_group<Q>(&mut self, key: &Q) -> Option<ParallelVec<Param>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.groups.remove(key)
+    }
+
+    /// Returns an iterator over all of the groups, in key order.
+    pub fn groups(&self) -> impl Iterator<Item = (&K, &ParallelVec<Param>)> {
+        self.groups.iter()
+    }
+
+    /// Returns an iterator over mutable references to all of the groups, in key order.
+    pub fn groups_mut(&mut self) -> impl Iterator<Item = (&K, &mut ParallelVec<Param>)> {
+        self.groups.iter_mut()
+    }
+
+    /// Returns an iterator over every row in every group, alongside the key of
+    /// the group it belongs to.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Param::Ref<'_>)> {
+        self.groups
+            .iter()
+            .flat_map(|(key, group)| group.iter().map(move |row| (key, row)))
+    }
+
+    /// Removes all rows from every group, keeping the groups and their allocations.
+    pub fn clear(&mut self) {
+        for group in self.groups.values_mut() {
+            group.clear();
+        }
+    }
+}
+
+impl<K: Ord, Param: ParallelParam> Default for ArchetypeMap<K, Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArchetypeMap;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_push_and_groups() {
+        let mut map = ArchetypeMap::new();
+        assert_eq!(map.push("a", (1, 2.0)), 0);
+        assert_eq!(map.push("b", (2, 3.0)), 0);
+        assert_eq!(map.push("a", (3, 4.0)), 1);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.group_count(), 2);
+        let (a, b) = map.group("a").unwrap().as_slices();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(b, &[2.0, 4.0]);
+        let keys: Vec<_> = map.groups().map(|(key, _)| *key).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+        let rows: Vec<_> = map.iter().map(|(key, (a, _))| (*key, *a)).collect();
+        assert_eq!(rows, vec![("a", 1), ("a", 3), ("b", 2)]);
+    }
+
+    #[test]
+    fn test_move_row() {
+        let mut map = ArchetypeMap::new();
+        map.push(0, (1, 1));
+        map.push(0, (2, 2));
+        map.push(0, (3, 3));
+        assert_eq!(map.move_row(&0, 0, 1), Some(0));
+        assert_eq!(map.move_row(&0, 0, 1), Some(1));
+        assert_eq!(map.move_row(&0, 5, 1), None);
+        assert_eq!(map.move_row(&2, 0, 1), None);
+        assert_eq!(map.move_row(&1, 1, 1), Some(1));
+        let (a, _) = map.group(&0).unwrap().as_slices();
+        assert_eq!(a, &[2]);
+        let (a, _) = map.group(&1).unwrap().as_slices();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(map.len(), 3);
+
+        let mut map = ArchetypeMap::new();
+        map.push(String::from("a"), (1, 1));
+        assert_eq!(map.move_row("a", 0, String::from("b")), Some(0));
+        assert_eq!(map.move_row("b", 0, String::from("b")), Some(0));
+        assert_eq!(map.group("b").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = ArchetypeMap::new();
+        map.push(0, (1, 1));
+        map.push(0, (2, 2));
+        assert_eq!(map.swap_remove(&0, 0), Some((1, 1)));
+        assert_eq!(map.swap_remove(&0, 1), None);
+        assert_eq!(map.swap_remove(&1, 0), None);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.group_count(), 1);
+        assert!(map.remove_group(&0).is_some());
+        assert_eq!(map.group_count(), 0);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{assert_in_bounds, assert_in_bounds_inclusive};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+const BITS: usize = u64::BITS as usize;
+
+#[inline(always)]
+fn low_mask(bits: usize) -> u64 {
+    (1u64 << bits) - 1
+}
+
+/// A growable sequence of `bool`s stored as a bitset, using one bit per value.
+///
+/// This is used as the flag column of a [`FlaggedParallelVec`] and as a row
+/// selection mask, where it uses an eighth of the memory of a `[bool]` and
+/// allows set rows to be found a word at a time.
+///
+/// [`FlaggedParallelVec`]: crate::FlaggedParallelVec
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PackedBools {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PackedBools {
+    /// Constructs a new, empty `PackedBools`.
+    ///
+    /// This will not allocate until values are pushed onto it.
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Constructs a new, empty `PackedBools` with space for at least
+    /// `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity.div_ceil(BITS)),
+            len: 0,
+        }
+    }
+
+    /// Constructs a `PackedBools` of `len` copies of `value`.
+    pub fn repeat(value: bool, len: usize) -> Self {
+        let fill = if value { u64::MAX } else { 0 };
+        let mut words = alloc::vec![fill; len.div_ceil(BITS)];
+        if let Some(last) = words.last_mut() {
+            if !len.is_multiple_of(BITS) {
+                *last &= low_mask(len % BITS);
+            }
+        }
+        Self { words, len }
+    }
+
+    /// Returns the number of values stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no values stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the underlying words, least significant bit first. Bits past
+    /// `len()` in the final word are always zero.
+    #[inline]
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Gets the value at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.words[index / BITS] & (1 << (index % BITS)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the value at `index`.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert_in_bounds(index, self.len);
+        let word = &mut self.words[index / BITS];
+        let bit = 1 << (index % BITS);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    /// Appends a value to the end.
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(BITS) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Removes the last value and returns it, or [`None`] if it is empty.
+    ///
+    /// [`None`]: Option::None
+    pub fn pop(&mut self) -> Option<bool> {
+        let value = self.get(self.len.checked_sub(1)?)?;
+        self.set(self.len - 1, false);
+        self.len -= 1;
+        if self.len.is_multiple_of(BITS) {
+            self.words.pop();
+        }
+        Some(value)
+    }
+
+    /// Inserts a value at `index`, shifting all of the values after it up
+    /// one index. This is a `O(N)` operation.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: bool) {
+        assert_in_bounds_inclusive(index, self.len);
+        if self.len.is_multiple_of(BITS) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        let start = index / BITS;
+        for word in (start + 1..self.words.len()).rev() {
+            self.words[word] = (self.words[word] << 1) | (self.words[word - 1] >> (BITS - 1));
+        }
+        let mask = low_mask(index % BITS);
+        let word = self.words[start];
+        self.words[start] = (word & mask) | ((word & !mask) << 1);
+        self.set(index, value);
+    }
+
+    /// Removes the value at `index`, shifting all of the values after it
+    /// down one index. This is a `O(N)` operation.
+    ///
+    /// Returns `None` if `index` is greater than or equal to `len()`.
+    pub fn remove(&mut self, index: usize) -> Option<bool> {
+        let value = self.get(index)?;
+        let start = index / BITS;
+        let mask = low_mask(index % BITS);
+        let word = self.words[start];
+        self.words[start] = (word & mask) | ((word >> 1) & !mask);
+        for word in start + 1..self.words.len() {
+            self.words[word - 1] |= (self.words[word] & 1) << (BITS - 1);
+            self.words[word] >>= 1;
+        }
+        self.len -= 1;
+        if self.len.is_multiple_of(BITS) {
+            self.words.pop();
+        }
+        Some(value)
+    }
+
+    /// Removes the value at `index` and returns it, replacing it with the
+    /// last value.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than or equal to
+    /// `len()`.
+    pub fn swap_remove(&mut self, index: usize) -> bool {
+        assert_in_bounds(index, self.len);
+        let last = self.pop().unwrap();
+        if index == self.len {
+            return last;
+        }
+        let value = self.get(index).unwrap();
+        self.set(index, last);
+        value
+    }
+
+    /// Shortens to the first `len` values. Has no effect if `len` is
+    /// greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        self.len = len;
+        self.words.truncate(len.div_ceil(BITS));
+        if !len.is_multiple_of(BITS) {
+            *self.words.last_mut().unwrap() &= low_mask(len % BITS);
+        }
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Returns the number of values that are `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over all of the values.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |idx| self.words[idx / BITS] & (1 << (idx % BITS)) != 0)
+    }
+
+    /// Returns an iterator over the indices of all of the values that are
+    /// `true`, in increasing order.
+    ///
+    /// This skips over unset values a word at a time.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(idx, &word)| {
+            let mut word = word;
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(idx * BITS + bit)
+            })
+        })
+    }
+}
+
+impl FromIterator<bool> for PackedBools {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut bits = PackedBools::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl Extend<bool> for PackedBools {
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl From<&[bool]> for PackedBools {
+    fn from(values: &[bool]) -> Self {
+        values.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedBools;
+    use std::vec::Vec;
+
+    fn pattern(len: usize) -> Vec<bool> {
+        (0..len).map(|idx| idx % 3 == 0 || idx % 7 == 2).collect()
+    }
+
+    #[test]
+    fn test_push_pop_get() {
+        let values = pattern(200);
+        let mut bits = PackedBools::from(values.as_slice());
+        assert_eq!(bits.len(), 200);
+        assert_eq!(bits.iter().collect::<Vec<_>>(), values);
+        assert_eq!(bits.count_ones(), values.iter().filter(|v| **v).count());
+        assert_eq!(bits.get(200), None);
+        for expected in values.iter().rev() {
+            assert_eq!(bits.pop(), Some(*expected));
+        }
+        assert_eq!(bits.pop(), None);
+        assert!(bits.as_words().is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove_across_words() {
+        let mut values = pattern(130);
+        let mut bits = PackedBools::from(values.as_slice());
+        for &(idx, value) in &[(0, true), (63, false), (64, true), (131, true), (5, true)] {
+            values.insert(idx, value);
+            bits.insert(idx, value);
+            assert_eq!(bits.iter().collect::<Vec<_>>(), values);
+        }
+        for &idx in &[0, 63, 64, 100, 130] {
+            assert_eq!(bits.remove(idx), Some(values.remove(idx)));
+            assert_eq!(bits.iter().collect::<Vec<_>>(), values);
+        }
+        assert_eq!(bits.swap_remove(3), values.swap_remove(3));
+        assert_eq!(bits.iter().collect::<Vec<_>>(), values);
+        assert_eq!(bits, PackedBools::from(values.as_slice()));
+    }
+
+    #[test]
+    fn test_iter_ones() {
+        let values = pattern(150);
+        let bits = PackedBools::from(values.as_slice());
+        let expected: Vec<_> = (0..150).filter(|idx| values[*idx]).collect();
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), expected);
+        assert_eq!(PackedBools::repeat(true, 70).iter_ones().count(), 70);
+        assert_eq!(PackedBools::repeat(true, 70).as_words()[1], 0b11_1111);
+    }
+}
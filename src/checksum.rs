@@ -0,0 +1,194 @@
+use core::hash::Hasher;
+
+const SEED: u64 = 0x243F_6A88_85A3_08D3;
+const MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A fast, deterministic [`Hasher`] used to compute content checksums.
+///
+/// Unlike the hashers in the standard library, the output depends only on the
+/// sequence of bytes written, which are always read as little-endian words, so
+/// the same bytes produce the same checksum on every platform and in every
+/// release, regardless of how they are split across writes.
+pub(crate) struct ChecksumHasher {
+    hash: u64,
+    bytes: u64,
+    tail: [u8; 8],
+    tail_len: usize,
+}
+
+impl ChecksumHasher {
+    pub(crate) fn new() -> Self {
+        Self {
+            hash: SEED,
+            bytes: 0,
+            tail: [0; 8],
+            tail_len: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn mix(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(MULTIPLIER);
+    }
+}
+
+impl Hasher for ChecksumHasher {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.bytes = self.bytes.wrapping_add(bytes.len() as u64);
+        if self.tail_len > 0 {
+            let fill = bytes.len().min(8 - self.tail_len);
+            self.tail[self.tail_len..self.tail_len + fill].copy_from_slice(&bytes[..fill]);
+            self.tail_len += fill;
+            bytes = &bytes[fill..];
+            if self.tail_len < 8 {
+                return;
+            }
+            self.mix(u64::from_le_bytes(self.tail));
+            self.tail_len = 0;
+        }
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.mix(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let remainder = chunks.remainder();
+        self.tail[..remainder.len()].copy_from_slice(remainder);
+        self.tail_len = remainder.len();
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    // usize and isize are always written as 64-bit values so that the
+    // checksum does not depend on the pointer width.
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i8(&mut self, value: i8) {
+        self.write_u8(value as u8);
+    }
+
+    fn write_i16(&mut self, value: i16) {
+        self.write_u16(value as u16);
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_u32(value as u32);
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i128(&mut self, value: i128) {
+        self.write_u128(value as u128);
+    }
+
+    fn write_isize(&mut self, value: isize) {
+        self.write_i64(value as i64);
+    }
+
+    fn finish(&self) -> u64 {
+        // Finalize with the MurmurHash3 avalanche so that every input bit
+        // affects every output bit.
+        let mut hash = self.hash;
+        if self.tail_len > 0 {
+            let mut word = [0u8; 8];
+            word[..self.tail_len].copy_from_slice(&self.tail[..self.tail_len]);
+            hash = (hash.rotate_left(5) ^ u64::from_le_bytes(word)).wrapping_mul(MULTIPLIER);
+        }
+        let mut hash = (hash.rotate_left(5) ^ self.bytes).wrapping_mul(MULTIPLIER);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+        hash ^ (hash >> 33)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_checksum_by_rows() {
+        let rows = || vec![(1u32, String::from("a")), (2, String::from("b"))];
+        let a = ParallelVec::from(rows());
+        let mut b = ParallelVec::from(rows());
+        assert_eq!(a.checksum_by_rows(), b.checksum_by_rows());
+        b.index_mut(1).1.push('c');
+        assert_ne!(a.checksum_by_rows(), b.checksum_by_rows());
+        assert_ne!(
+            a.checksum_by_rows(),
+            a.index(0..1).checksum_by_rows(),
+            "the length is part of the checksum"
+        );
+    }
+
+    #[test]
+    fn test_checksum_hasher_is_little_endian() {
+        use super::ChecksumHasher;
+        use core::hash::Hasher;
+
+        let mut a = ChecksumHasher::new();
+        a.write_u16(0x0102);
+        a.write_u32(0x0304_0506);
+        a.write_i64(-2);
+        a.write_u128(7);
+        a.write_isize(-3);
+        let mut b = ChecksumHasher::new();
+        b.write(&[0x02, 0x01]);
+        b.write(&[0x06, 0x05, 0x04, 0x03]);
+        b.write(&(-2i64).to_le_bytes());
+        b.write(&7u128.to_le_bytes());
+        b.write(&(-3i64).to_le_bytes());
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn test_checksum_hasher_ignores_write_boundaries() {
+        use super::ChecksumHasher;
+        use core::hash::Hasher;
+
+        let bytes: Vec<u8> = (0..29).collect();
+        let mut whole = ChecksumHasher::new();
+        whole.write(&bytes);
+        for split in [1, 3, 7, 8, 9] {
+            let mut parts = ChecksumHasher::new();
+            for chunk in bytes.chunks(split) {
+                parts.write(chunk);
+            }
+            assert_eq!(whole.finish(), parts.finish());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_checksum_is_stable() {
+        let src = ParallelVec::from(vec![(1u32, 2u64), (3, 4), (5, 6)]);
+        assert_eq!(src.checksum(), 0x9FEA_20DD_E920_5398);
+        assert_ne!(src.checksum(), src.index(0..2).checksum());
+        let mut other = src.clone();
+        *other.index_mut(2).1 += 1;
+        assert_ne!(src.checksum(), other.checksum());
+    }
+}
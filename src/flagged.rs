@@ -0,0 +1,206 @@
+use crate::{
+    assert_in_bounds, PackedBools, ParallelParam, ParallelSliceMut, ParallelVec,
+    ParallelVecConversionError,
+};
+use core::ops::Deref;
+
+/// A [`ParallelVec`] with an additional boolean flag per row, stored as a
+/// bitset.
+///
+/// The flags are kept in a [`PackedBools`] alongside the rows, using one bit
+/// per row instead of the byte a `bool` column would use, and are kept in
+/// lockstep with the rows across every mutating operation exposed by this
+/// type. Rows can be mutated in place via [`rows_mut`], but rows can only be
+/// added or removed through this type so that the flags stay aligned.
+///
+/// [`rows_mut`]: Self::rows_mut
+pub struct FlaggedParallelVec<Param: ParallelParam> {
+    vec: ParallelVec<Param>,
+    flags: PackedBools,
+}
+
+impl<Param: ParallelParam> FlaggedParallelVec<Param> {
+    /// Constructs a new, empty `FlaggedParallelVec`.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new() -> Self {
+        Self {
+            vec: ParallelVec::new(),
+            flags: PackedBools::new(),
+        }
+    }
+
+    /// Constructs a new, empty `FlaggedParallelVec` with the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: ParallelVec::with_capacity(capacity),
+            flags: PackedBools::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a `FlaggedParallelVec` from a set of rows and their flags.
+    ///
+    /// # Errors
+    /// Returns [`ParallelVecConversionError::UnevenLengths`] if there is not
+    /// exactly one flag per row.
+    pub fn from_parts(
+        vec: ParallelVec<Param>,
+        flags: PackedBools,
+    ) -> Result<Self, ParallelVecConversionError> {
+        if vec.len() != flags.len() {
+            return Err(ParallelVecConversionError::UnevenLengths);
+        }
+        Ok(Self { vec, flags })
+    }
+
+    /// Gets the flags of all of the rows.
+    #[inline]
+    pub fn flags(&self) -> &PackedBools {
+        &self.flags
+    }
+
+    /// Gets the flag of the row at `index`.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    #[inline]
+    pub fn flag(&self, index: usize) -> bool {
+        assert_in_bounds(index, self.flags.len());
+        self.flags.get(index).unwrap()
+    }
+
+    /// Sets the flag of the row at `index`.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    #[inline]
+    pub fn set_flag(&mut self, index: usize, flag: bool) {
+        self.flags.set(index, flag);
+    }
+
+    /// Gets a mutable slice over the rows.
+    #[inline]
+    pub fn rows_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        self.vec.index_mut(..)
+    }
+
+    /// Returns an iterator over the rows whose flag is set.
+    pub fn iter_flagged(&self) -> impl Iterator<Item = Param::Ref<'_>> {
+        // SAFE: There is exactly one flag per row, so every set flag is in bounds.
+        self.flags
+            .iter_ones()
+            .map(move |idx| unsafe { self.vec.get_unchecked(idx) })
+    }
+
+    /// Appends an element and its flag to the back of the collection.
+    pub fn push(&mut self, value: Param, flag: bool) {
+        self.vec.push(value);
+        self.flags.push(flag);
+    }
+
+    /// Removes the last element from the vector and returns it along with
+    /// its flag, or [`None`] if it is empty.
+    ///
+    /// [`None`]: Option::None
+    pub fn pop(&mut self) -> Option<(Param, bool)> {
+        Some((self.vec.pop()?, self.flags.pop().unwrap()))
+    }
+
+    /// Removes an element from the vector and returns it along with its flag.
+    ///
+    /// The removed element is replaced by the last element of the vector.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than or equal to
+    /// `len()`.
+    pub fn swap_remove(&mut self, index: usize) -> (Param, bool) {
+        let value = self.vec.swap_remove(index);
+        (value, self.flags.swap_remove(index))
+    }
+
+    /// Inserts a value and its flag at `index`. Moves all of the elements
+    /// above `index` up one index. This is a `O(N)` operation.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: Param, flag: bool) {
+        self.vec.insert(index, value);
+        self.flags.insert(index, flag);
+    }
+
+    /// Removes a value at `index` and returns it along with its flag. Moves
+    /// all of the elements above `index` down one index. This is a `O(N)`
+    /// operation.
+    ///
+    /// Returns `None` if `index` is is greater than or equal to `len()`.
+    pub fn remove(&mut self, index: usize) -> Option<(Param, bool)> {
+        Some((self.vec.remove(index)?, self.flags.remove(index).unwrap()))
+    }
+
+    /// Clears the vector and its flags, removing all values.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the vector.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+        self.flags.clear();
+    }
+
+    /// Consumes the `FlaggedParallelVec`, returning the rows and their flags.
+    pub fn into_parts(self) -> (ParallelVec<Param>, PackedBools) {
+        (self.vec, self.flags)
+    }
+}
+
+impl<Param: ParallelParam> Default for FlaggedParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam> Deref for FlaggedParallelVec<Param> {
+    type Target = ParallelVec<Param>;
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlaggedParallelVec;
+    use crate::{PackedBools, ParallelVec, ParallelVecConversionError};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_flags_follow_rows() {
+        let mut src = FlaggedParallelVec::new();
+        for idx in 0..10u32 {
+            src.push((idx, idx as u64 * 10), idx % 2 == 0);
+        }
+        assert_eq!(src.swap_remove(0), ((0, 0), true));
+        assert_eq!(src.index(0), (&9, &90));
+        assert!(!src.flag(0));
+        assert_eq!(src.remove(1), Some(((1, 10), false)));
+        src.insert(0, (100, 1000), true);
+        src.set_flag(1, true);
+        for (a, _) in src.rows_mut().iter_mut() {
+            *a += 1;
+        }
+        let flagged: Vec<_> = src.iter_flagged().map(|(a, _)| *a).collect();
+        assert_eq!(flagged, vec![101, 10, 3, 5, 7, 9]);
+        assert_eq!(src.pop(), Some(((9, 80), true)));
+        assert_eq!(src.len(), src.flags().len());
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let vec = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        let flags = PackedBools::from(&[true][..]);
+        assert_eq!(
+            FlaggedParallelVec::from_parts(vec.clone(), flags).err(),
+            Some(ParallelVecConversionError::UnevenLengths)
+        );
+        let flags = PackedBools::from(&[true, false][..]);
+        let src = FlaggedParallelVec::from_parts(vec, flags).unwrap();
+        assert_eq!(src.iter_flagged().count(), 1);
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{assert_in_bounds, out_of_bounds, ParallelParam, ParallelVec};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{borrow::Borrow, ops::Deref};
+
+/// A [`ParallelVec`] that maintains a secondary index from a key derived from
+/// each row to the indices of the rows that share that key.
+///
+/// The index is kept up to date across every mutating operation exposed by
+/// this type, including [`swap_remove`], which moves the last row into the
+/// removed slot. Mutable access to the underlying rows is intentionally not
+/// provided, as it would allow the keys to change without updating the index.
+/// Use [`set`] to replace a row instead.
+///
+/// [`swap_remove`]: Self::swap_remove
+/// [`set`]: Self::set
+pub struct IndexedParallelVec<Param: ParallelParam, K, F> {
+    vec: ParallelVec<Param>,
+    index: BTreeMap<K, Vec<usize>>,
+    key: F,
+}
+
+impl<Param, K, F> IndexedParallelVec<Param, K, F>
+where
+    Param: ParallelParam,
+    K: Ord,
+    F: for<'a> Fn(Param::Ref<'a>) -> K,
+{
+    /// Constructs a new, empty `IndexedParallelVec` that indexes its rows by
+    /// the key returned by `key`.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new(key: F) -> Self {
+        Self::from_vec(ParallelVec::new(), key)
+    }
+
+    /// Constructs a new, empty `IndexedParallelVec` with the specified capacity
+    /// that indexes its rows by the key returned by `key`.
+    pub fn with_capacity(capacity: usize, key: F) -> Self {
+        Self::from_vec(ParallelVec::with_capacity(capacity), key)
+    }
+
+    /// Builds an index over all of the rows in an existing [`ParallelVec`].
+    pub fn from_vec(vec: ParallelVec<Param>, key: F) -> Self {
+        let mut index: BTreeMap<K, Vec<usize>> = BTreeMap::new();
+        for (idx, row) in vec.iter().enumerate() {
+            index.entry(key(row)).or_default().push(idx);
+        }
+        Self { vec, index, key }
+    }
+
+    /// Returns the indices of all of the rows with the provided key.
+    ///
+    /// The order of the returned indices is unspecified. Returns an empty
+    /// slice if no row has the key.
+    pub fn find_by_key<Q>(&self, key: &Q) -> &[usize]
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.index.get(key).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns `true` if at least one row has the provided key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Returns an iterator over the distinct keys in the index, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.index.keys()
+    }
+
+    /// Appends an element to the back of the collection.
+    pub fn push(&mut self, value: Param) {
+        self.vec.push(value);
+        let idx = self.vec.len() - 1;
+        self.index_row(idx);
+    }
+
+    /// Removes the last element from the vector and returns it,
+    /// or [`None`] if it is empty.
+    ///
+    /// [`None`]: Option::None
+    pub fn pop(&mut self) -> Option<Param> {
+        if self.vec.is_empty() {
+            return None;
+        }
+        self.unindex_row(self.vec.len() - 1);
+        self.vec.pop()
+    }
+
+    /// Removes an element from the vector and returns it.
+    ///
+    /// The removed element is replaced by the last element of the vector,
+    /// and the index entry of the moved row is updated accordingly.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than or equal to
+    /// `len()`.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        assert_in_bounds(index, self.vec.len());
+        let last = self.vec.len() - 1;
+        self.unindex_row(index);
+        if index != last {
+            let key = (self.key)(self.vec.index(last));
+            Self::replace_row(&mut self.index, &key, last, index);
+        }
+        self.vec.swap_remove(index)
+    }
+
+    /// Inserts a value at `index`. Moves all of the elements above
+    /// `index` up one index. This is a `O(N)` operation.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: Param) {
+        if index > self.vec.len() {
+            out_of_bounds(index, self.vec.len());
+        }
+        self.shift_rows(index, |row| row + 1);
+        self.vec.insert(index, value);
+        self.index_row(index);
+    }
+
+    /// Removes a value at `index`. Moves all of the elements above
+    /// `index` down one index. This is a `O(N)` operation.
+    ///
+    /// Returns `None` if `index` is is greater than or equal to `len()`.
+    pub fn remove(&mut self, index: usize) -> Option<Param> {
+        if index >= self.vec.len() {
+            return None;
+        }
+        self.unindex_row(index);
+        self.shift_rows(index + 1, |row| row - 1);
+        self.vec.remove(index)
+    }
+
+    /// Replaces the value at `index`, updating the index if the key changes.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    pub fn set(&mut self, index: usize, value: Param) {
+        assert_in_bounds(index, self.vec.len());
+        self.unindex_row(index);
+        self.vec.set(index, value);
+        self.index_row(index);
+    }
+
+    /// Clears the vector and the index, removing all values.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the vector.
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.vec.clear();
+    }
+
+    /// Consumes the `IndexedParallelVec`, discarding the index and returning
+    /// the underlying [`ParallelVec`].
+    pub fn into_inner(self) -> ParallelVec<Param> {
+        self.vec
+    }
+
+    fn index_row(&mut self, row: usize) {
+        let key = (self.key)(self.vec.index(row));
+        self.index.entry(key).or_default().push(row);
+    }
+
+    fn unindex_row(&mut self, row: usize) {
+        let key = (self.key)(self.vec.index(row));
+        let rows = self
+            .index
+            .get_mut(&key)
+            .expect("IndexedParallelVec index is out of sync");
+        let pos = rows
+            .iter()
+            .position(|idx| *idx == row)
+            .expect("IndexedParallelVec index is out of sync");
+        rows.swap_remove(pos);
+        if rows.is_empty() {
+            self.index.remove(&key);
+        }
+    }
+
+    fn replace_row(index: &mut BTreeMap<K, Vec<usize>>, key: &K, from: usize, to: usize) {
+        let rows = index
+            .get_mut(key)
+            .expect("IndexedParallelVec index is out of sync");
+        for row in rows.iter_mut() {
+            if *row == from {
+                *row = to;
+                return;
+            }
+        }
+        panic!("IndexedParallelVec index is out of sync");
+    }
+
+    fn shift_rows(&mut self, start: usize, f: impl Fn(usize) -> usize) {
+        for rows in self.index.values_mut() {
+            for row in rows.iter_mut().filter(|row| **row >= start) {
+                *row = f(*row);
+            }
+        }
+    }
+}
+
+impl<Param: ParallelParam, K, F> Deref for IndexedParallelVec<Param, K, F> {
+    type Target = ParallelVec<Param>;
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedParallelVec;
+    use crate::ParallelVec;
+
+    fn check_index<F>(vec: &IndexedParallelVec<(u32, u64), u32, F>)
+    where
+        F: for<'a> Fn((&'a u32, &'a u64)) -> u32,
+    {
+        let mut total = 0;
+        for key in vec.keys() {
+            for &row in vec.find_by_key(key) {
+                assert_eq!(vec.index(row).0, key);
+                total += 1;
+            }
+        }
+        assert_eq!(total, vec.len());
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        let mut src = IndexedParallelVec::new(|(a, _): (&u32, &u64)| *a);
+        src.push((1, 10));
+        src.push((2, 20));
+        src.push((1, 30));
+        let mut rows = src.find_by_key(&1).to_vec();
+        rows.sort();
+        assert_eq!(rows, vec![0, 2]);
+        assert_eq!(src.find_by_key(&2), &[1]);
+        assert_eq!(src.find_by_key(&3), &[]);
+        assert!(src.contains_key(&2));
+        assert!(!src.contains_key(&3));
+        check_index(&src);
+    }
+
+    #[test]
+    fn test_swap_remove_updates_index() {
+        let vec = ParallelVec::from(vec![(1, 10), (2, 20), (3, 30), (4, 40)]);
+        let mut src = IndexedParallelVec::from_vec(vec, |(a, _): (&u32, &u64)| *a);
+        assert_eq!(src.swap_remove(1), (2, 20));
+        assert_eq!(src.find_by_key(&2), &[]);
+        assert_eq!(src.find_by_key(&4), &[1]);
+        check_index(&src);
+        assert_eq!(src.swap_remove(2), (3, 30));
+        assert_eq!(src.find_by_key(&3), &[]);
+        check_index(&src);
+    }
+
+    #[test]
+    fn test_remove_and_insert_update_index() {
+        let vec = ParallelVec::from(vec![(1, 10), (2, 20), (3, 30), (2, 40)]);
+        let mut src = IndexedParallelVec::from_vec(vec, |(a, _): (&u32, &u64)| *a);
+        assert_eq!(src.remove(0), Some((1, 10)));
+        assert_eq!(src.find_by_key(&3), &[1]);
+        check_index(&src);
+        src.insert(1, (5, 50));
+        assert_eq!(src.find_by_key(&5), &[1]);
+        assert_eq!(src.find_by_key(&3), &[2]);
+        check_index(&src);
+        assert_eq!(src.remove(10), None);
+    }
+
+    #[test]
+    fn test_set_and_pop_update_index() {
+        let vec = ParallelVec::from(vec![(1, 10), (2, 20), (3, 30)]);
+        let mut src = IndexedParallelVec::from_vec(vec, |(a, _): (&u32, &u64)| *a);
+        src.set(0, (3, 0));
+        assert!(!src.contains_key(&1));
+        let mut rows = src.find_by_key(&3).to_vec();
+        rows.sort();
+        assert_eq!(rows, vec![0, 2]);
+        assert_eq!(src.pop(), Some((3, 30)));
+        assert_eq!(src.find_by_key(&3), &[0]);
+        check_index(&src);
+        src.clear();
+        assert!(src.is_empty());
+        assert_eq!(src.keys().count(), 0);
+    }
+}
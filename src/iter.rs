@@ -1,7 +1,9 @@
-use crate::ParallelParam;
+use crate::{ParallelParam, ParallelSlice, ParallelVec, SidecarSlices};
+use alloc::vec::Vec;
 use core::{
-    iter::{DoubleEndedIterator, ExactSizeIterator},
+    iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator},
     marker::PhantomData,
+    ops::Range,
 };
 
 /// An iterator over immutable references to values in a [`ParallelSlice`].
@@ -33,10 +35,32 @@ impl<'a, Param: ParallelParam> Iterator for Iter<'a, Param> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.remaining, Some(self.remaining))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Param::Ref<'a>> {
+        let skip = n.min(self.remaining);
+        // SAFE: The pointer remains within, or one past the end of, the allocation.
+        self.ptr = unsafe { Param::add(self.ptr, skip) };
+        self.remaining -= skip;
+        self.next()
+    }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        G: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for idx in 0..self.remaining {
+            // SAFE: idx is within the remaining rows.
+            acc = f(acc, unsafe { Param::as_ref(Param::add(self.ptr, idx)) });
+        }
+        acc
+    }
 }
 
 impl<'a, Param: ParallelParam> ExactSizeIterator for Iter<'a, Param> {}
 
+impl<'a, Param: ParallelParam> FusedIterator for Iter<'a, Param> {}
+
 impl<'a, Param: ParallelParam> DoubleEndedIterator for Iter<'a, Param> {
     fn next_back(&mut self) -> Option<Param::Ref<'a>> {
         unsafe {
@@ -48,6 +72,11 @@ impl<'a, Param: ParallelParam> DoubleEndedIterator for Iter<'a, Param> {
             Some(Param::as_ref(ptr))
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Param::Ref<'a>> {
+        self.remaining -= n.min(self.remaining);
+        self.next_back()
+    }
 }
 
 /// An iterator over mutable reference to values in a [`ParallelSliceMut`].
@@ -75,10 +104,36 @@ impl<'a, Param: ParallelParam> Iterator for IterMut<'a, Param> {
             Some(output)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Param::RefMut<'a>> {
+        let skip = n.min(self.remaining);
+        // SAFE: The pointer remains within, or one past the end of, the allocation.
+        self.ptr = unsafe { Param::add(self.ptr, skip) };
+        self.remaining -= skip;
+        self.next()
+    }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        G: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for idx in 0..self.remaining {
+            // SAFE: idx is within the remaining rows.
+            acc = f(acc, unsafe { Param::as_mut(Param::add(self.ptr, idx)) });
+        }
+        acc
+    }
 }
 
 impl<'a, Param: ParallelParam> ExactSizeIterator for IterMut<'a, Param> {}
 
+impl<'a, Param: ParallelParam> FusedIterator for IterMut<'a, Param> {}
+
 impl<'a, Param: ParallelParam> DoubleEndedIterator for IterMut<'a, Param> {
     fn next_back(&mut self) -> Option<Param::RefMut<'a>> {
         unsafe {
@@ -90,20 +145,579 @@ impl<'a, Param: ParallelParam> DoubleEndedIterator for IterMut<'a, Param> {
             Some(Param::as_mut(ptr))
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Param::RefMut<'a>> {
+        self.remaining -= n.min(self.remaining);
+        self.next_back()
+    }
+}
+
+/// An iterator over immutable references to values in a [`ParallelSlice`]
+/// or [`ParallelSliceMut`] along with their indices.
+///
+/// See [`ParallelSlice::iter_enumerated`] and
+/// [`ParallelSliceMut::iter_enumerated`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSlice::iter_enumerated`]: crate::ParallelSlice::iter_enumerated
+/// [`ParallelSliceMut::iter_enumerated`]: crate::ParallelSliceMut::iter_enumerated
+pub struct IterEnumerated<'a, Param: ParallelParam> {
+    pub(crate) base: Param::Ptr,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+/// An iterator over mutable references to values in a [`ParallelSliceMut`]
+/// along with their indices.
+///
+/// See [`ParallelSliceMut::iter_mut_enumerated`].
+///
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSliceMut::iter_mut_enumerated`]: crate::ParallelSliceMut::iter_mut_enumerated
+pub struct IterMutEnumerated<'a, Param: ParallelParam> {
+    pub(crate) base: Param::Ptr,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) _marker: PhantomData<&'a mut Param>,
+}
+
+macro_rules! impl_enumerated_iter {
+    ($name: ident, $item: ident, $get: ident) => {
+        impl<'a, Param: ParallelParam> Iterator for $name<'a, Param> {
+            type Item = (usize, Param::$item<'a>);
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.idx >= self.end {
+                    return None;
+                }
+                let idx = self.idx;
+                self.idx += 1;
+                // SAFE: idx is within the remaining rows.
+                Some((idx, unsafe { Param::$get(Param::add(self.base, idx)) }))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.end - self.idx;
+                (remaining, Some(remaining))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.idx += n.min(self.end - self.idx);
+                self.next()
+            }
+
+            fn fold<B, G>(self, init: B, mut f: G) -> B
+            where
+                G: FnMut(B, Self::Item) -> B,
+            {
+                let mut acc = init;
+                for idx in self.idx..self.end {
+                    // SAFE: idx is within the remaining rows.
+                    acc = f(
+                        acc,
+                        (idx, unsafe { Param::$get(Param::add(self.base, idx)) }),
+                    );
+                }
+                acc
+            }
+        }
+
+        impl<'a, Param: ParallelParam> DoubleEndedIterator for $name<'a, Param> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.idx >= self.end {
+                    return None;
+                }
+                self.end -= 1;
+                // SAFE: end is within the remaining rows.
+                Some((self.end, unsafe {
+                    Param::$get(Param::add(self.base, self.end))
+                }))
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.end -= n.min(self.end - self.idx);
+                self.next_back()
+            }
+        }
+
+        impl<'a, Param: ParallelParam> ExactSizeIterator for $name<'a, Param> {}
+
+        impl<'a, Param: ParallelParam> FusedIterator for $name<'a, Param> {}
+    };
+}
+
+impl_enumerated_iter!(IterEnumerated, Ref, as_ref);
+impl_enumerated_iter!(IterMutEnumerated, RefMut, as_mut);
+
+/// An iterator over mutable references to values in a [`ParallelSliceMut`]
+/// along with the elements of one or more sidecar slices at the same index.
+///
+/// See [`ParallelSliceMut::zip_slices`].
+///
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSliceMut::zip_slices`]: crate::ParallelSliceMut::zip_slices
+pub struct ZipSlices<'a, Param: ParallelParam, S: SidecarSlices> {
+    pub(crate) base: Param::Ptr,
+    pub(crate) slices: S::Ptr,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) _marker: PhantomData<(&'a mut Param, S)>,
+}
+
+impl<'a, Param: ParallelParam, S: SidecarSlices> ZipSlices<'a, Param, S> {
+    /// # Safety
+    /// `idx` must be within the remaining rows, and may only be used once.
+    #[inline(always)]
+    unsafe fn get(&self, idx: usize) -> (Param::RefMut<'a>, S::Item) {
+        (
+            Param::as_mut(Param::add(self.base, idx)),
+            S::get(self.slices, idx),
+        )
+    }
+}
+
+impl<'a, Param: ParallelParam, S: SidecarSlices> Iterator for ZipSlices<'a, Param, S> {
+    type Item = (Param::RefMut<'a>, S::Item);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+        self.idx += 1;
+        // SAFE: The row is within the remaining rows and is never yielded again.
+        Some(unsafe { self.get(self.idx - 1) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.idx += n.min(self.end - self.idx);
+        self.next()
+    }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        G: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for idx in self.idx..self.end {
+            // SAFE: idx is within the remaining rows.
+            acc = f(acc, unsafe { self.get(idx) });
+        }
+        acc
+    }
+}
+
+impl<'a, Param: ParallelParam, S: SidecarSlices> DoubleEndedIterator for ZipSlices<'a, Param, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFE: The row is within the remaining rows and is never yielded again.
+        Some(unsafe { self.get(self.end) })
+    }
+}
+
+impl<'a, Param: ParallelParam, S: SidecarSlices> ExactSizeIterator for ZipSlices<'a, Param, S> {}
+
+impl<'a, Param: ParallelParam, S: SidecarSlices> FusedIterator for ZipSlices<'a, Param, S> {}
+
+/// An iterator over immutable references to the rows of two
+/// [`ParallelSliceMut`]s of the same length, in lockstep.
+///
+/// See [`ParallelSliceMut::zip_iter`].
+///
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSliceMut::zip_iter`]: crate::ParallelSliceMut::zip_iter
+pub struct Zip<'a, A: ParallelParam, B: ParallelParam> {
+    pub(crate) a: A::Ptr,
+    pub(crate) b: B::Ptr,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) _marker: PhantomData<(&'a A, &'a B)>,
+}
+
+/// An iterator over mutable references to the rows of two
+/// [`ParallelSliceMut`]s of the same length, in lockstep.
+///
+/// See [`ParallelSliceMut::zip_iter_mut`].
+///
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSliceMut::zip_iter_mut`]: crate::ParallelSliceMut::zip_iter_mut
+pub struct ZipMut<'a, A: ParallelParam, B: ParallelParam> {
+    pub(crate) a: A::Ptr,
+    pub(crate) b: B::Ptr,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) _marker: PhantomData<(&'a mut A, &'a mut B)>,
+}
+
+macro_rules! impl_zip_iter {
+    ($name: ident, $item: ident, $get: ident) => {
+        impl<'a, A: ParallelParam, B: ParallelParam> $name<'a, A, B> {
+            /// # Safety
+            /// `idx` must be within the remaining rows, and may only be used once.
+            #[inline(always)]
+            unsafe fn get(&self, idx: usize) -> (A::$item<'a>, B::$item<'a>) {
+                (A::$get(A::add(self.a, idx)), B::$get(B::add(self.b, idx)))
+            }
+        }
+
+        impl<'a, A: ParallelParam, B: ParallelParam> Iterator for $name<'a, A, B> {
+            type Item = (A::$item<'a>, B::$item<'a>);
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.idx >= self.end {
+                    return None;
+                }
+                self.idx += 1;
+                // SAFE: The row is within the remaining rows and is never yielded again.
+                Some(unsafe { self.get(self.idx - 1) })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.end - self.idx;
+                (remaining, Some(remaining))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.idx += n.min(self.end - self.idx);
+                self.next()
+            }
+
+            fn fold<C, G>(self, init: C, mut f: G) -> C
+            where
+                G: FnMut(C, Self::Item) -> C,
+            {
+                let mut acc = init;
+                for idx in self.idx..self.end {
+                    // SAFE: idx is within the remaining rows.
+                    acc = f(acc, unsafe { self.get(idx) });
+                }
+                acc
+            }
+        }
+
+        impl<'a, A: ParallelParam, B: ParallelParam> DoubleEndedIterator for $name<'a, A, B> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.idx >= self.end {
+                    return None;
+                }
+                self.end -= 1;
+                // SAFE: The row is within the remaining rows and is never yielded again.
+                Some(unsafe { self.get(self.end) })
+            }
+        }
+
+        impl<'a, A: ParallelParam, B: ParallelParam> ExactSizeIterator for $name<'a, A, B> {}
+
+        impl<'a, A: ParallelParam, B: ParallelParam> FusedIterator for $name<'a, A, B> {}
+    };
+}
+
+impl_zip_iter!(Zip, Ref, as_ref);
+impl_zip_iter!(ZipMut, RefMut, as_mut);
+
+/// An iterator over the raw pointers to each row of a [`ParallelSlice`] or
+/// [`ParallelSliceMut`].
+///
+/// This is intended for building custom `unsafe` algorithms on top of the
+/// crate. Creating and advancing the iterator is safe, but dereferencing the
+/// yielded pointers is subject to the same rules as the pointers returned by
+/// [`ParallelSliceMut::as_mut_ptrs`]: they are only valid while the source is
+/// not otherwise accessed or reallocated.
+///
+/// See [`ParallelSlice::iter_ptrs`] and [`ParallelSliceMut::iter_ptrs`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSlice::iter_ptrs`]: crate::ParallelSlice::iter_ptrs
+/// [`ParallelSliceMut::iter_ptrs`]: crate::ParallelSliceMut::iter_ptrs
+/// [`ParallelSliceMut::as_mut_ptrs`]: crate::ParallelSliceMut::as_mut_ptrs
+pub struct IterPtrs<'a, Param: ParallelParam> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) remaining: usize,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam> Iterator for IterPtrs<'a, Param> {
+    type Item = Param::Ptr;
+    fn next(&mut self) -> Option<Param::Ptr> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let output = self.ptr;
+        // SAFE: The pointer remains within, or one past the end of, the allocation.
+        self.ptr = unsafe { Param::add(self.ptr, 1) };
+        self.remaining -= 1;
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Param::Ptr> {
+        let skip = n.min(self.remaining);
+        // SAFE: The pointer remains within, or one past the end of, the allocation.
+        self.ptr = unsafe { Param::add(self.ptr, skip) };
+        self.remaining -= skip;
+        self.next()
+    }
+
+    fn fold<B, G>(self, init: B, mut f: G) -> B
+    where
+        G: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for idx in 0..self.remaining {
+            // SAFE: idx is within the remaining rows.
+            acc = f(acc, unsafe { Param::add(self.ptr, idx) });
+        }
+        acc
+    }
+}
+
+impl<'a, Param: ParallelParam> ExactSizeIterator for IterPtrs<'a, Param> {}
+
+impl<'a, Param: ParallelParam> FusedIterator for IterPtrs<'a, Param> {}
+
+impl<'a, Param: ParallelParam> DoubleEndedIterator for IterPtrs<'a, Param> {
+    fn next_back(&mut self) -> Option<Param::Ptr> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // SAFE: The offset is within the bounds of the allocation.
+        Some(unsafe { Param::add(self.ptr, self.remaining) })
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Param::Ptr> {
+        self.remaining -= n.min(self.remaining);
+        self.next_back()
+    }
+}
+
+/// An iterator over the runs of consecutive rows of a [`ParallelSlice`] or
+/// [`ParallelSliceMut`] for which a predicate holds between every adjacent
+/// pair of rows, yielded as [`ParallelSlice`]s.
+///
+/// See [`ParallelSlice::chunk_by`] and [`ParallelSliceMut::chunk_by`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSlice::chunk_by`]: crate::ParallelSlice::chunk_by
+/// [`ParallelSliceMut::chunk_by`]: crate::ParallelSliceMut::chunk_by
+pub struct ChunkBy<'a, Param: ParallelParam, P> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) remaining: usize,
+    pub(crate) pred: P,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param, P> Iterator for ChunkBy<'a, Param, P>
+where
+    Param: ParallelParam,
+    P: FnMut(Param::Ref<'a>, Param::Ref<'a>) -> bool,
+{
+    type Item = ParallelSlice<'a, Param>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFE: Every offset used is within the remaining rows.
+        unsafe {
+            let mut len = 1;
+            while len < self.remaining
+                && (self.pred)(
+                    Param::as_ref(Param::add(self.ptr, len - 1)),
+                    Param::as_ref(Param::add(self.ptr, len)),
+                )
+            {
+                len += 1;
+            }
+            let chunk = ParallelSlice::from_raw_parts(Param::as_storage(self.ptr), len);
+            self.ptr = Param::add(self.ptr, len);
+            self.remaining -= len;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.min(1), Some(self.remaining))
+    }
+}
+
+impl<'a, Param, P> DoubleEndedIterator for ChunkBy<'a, Param, P>
+where
+    Param: ParallelParam,
+    P: FnMut(Param::Ref<'a>, Param::Ref<'a>) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFE: Every offset used is within the remaining rows.
+        unsafe {
+            let mut start = self.remaining - 1;
+            while start > 0
+                && (self.pred)(
+                    Param::as_ref(Param::add(self.ptr, start - 1)),
+                    Param::as_ref(Param::add(self.ptr, start)),
+                )
+            {
+                start -= 1;
+            }
+            let ptr = Param::add(self.ptr, start);
+            let chunk =
+                ParallelSlice::from_raw_parts(Param::as_storage(ptr), self.remaining - start);
+            self.remaining = start;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, Param, P> FusedIterator for ChunkBy<'a, Param, P>
+where
+    Param: ParallelParam,
+    P: FnMut(Param::Ref<'a>, Param::Ref<'a>) -> bool,
+{
+}
+
+/// An iterator over the runs of consecutive rows of a [`ParallelSlice`] or
+/// [`ParallelSliceMut`] with equal keys, yielding each key along with the
+/// range of rows it covers.
+///
+/// See [`ParallelSlice::runs_by_key`] and [`ParallelSliceMut::runs_by_key`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSlice::runs_by_key`]: crate::ParallelSlice::runs_by_key
+/// [`ParallelSliceMut::runs_by_key`]: crate::ParallelSliceMut::runs_by_key
+pub struct RunsByKey<'a, Param: ParallelParam, K, F> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) len: usize,
+    pub(crate) idx: usize,
+    /// The key of the row at `idx`, if it has already been computed.
+    pub(crate) next_key: Option<K>,
+    /// The key of the row at `len - 1`, if it has already been computed.
+    pub(crate) back_key: Option<K>,
+    pub(crate) f: F,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param, K, F> RunsByKey<'a, Param, K, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'a>) -> K,
+{
+    /// Gets the key of the row at `idx`, reusing a cached key if there is one.
+    ///
+    /// # Safety
+    /// `idx` must be within `self.idx..self.len`.
+    unsafe fn key_at(&mut self, idx: usize) -> K {
+        if idx == self.idx {
+            if let Some(key) = self.next_key.take() {
+                return key;
+            }
+        }
+        if idx + 1 == self.len {
+            if let Some(key) = self.back_key.take() {
+                return key;
+            }
+        }
+        (self.f)(Param::as_ref(Param::add(self.ptr, idx)))
+    }
+}
+
+impl<'a, Param, K, F> Iterator for RunsByKey<'a, Param, K, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'a>) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Range<usize>);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let start = self.idx;
+        // SAFE: Every index used is within the remaining rows.
+        unsafe {
+            let key = self.key_at(start);
+            self.idx += 1;
+            while self.idx < self.len {
+                let next = self.key_at(self.idx);
+                if next != key {
+                    self.next_key = Some(next);
+                    break;
+                }
+                self.idx += 1;
+            }
+            Some((key, start..self.idx))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.idx;
+        (remaining.min(1), Some(remaining))
+    }
+}
+
+impl<'a, Param, K, F> DoubleEndedIterator for RunsByKey<'a, Param, K, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'a>) -> K,
+    K: PartialEq,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let end = self.len;
+        // SAFE: Every index used is within the remaining rows.
+        unsafe {
+            let key = self.key_at(end - 1);
+            self.len -= 1;
+            while self.len > self.idx {
+                let prev = self.key_at(self.len - 1);
+                if prev != key {
+                    self.back_key = Some(prev);
+                    break;
+                }
+                self.len -= 1;
+            }
+            Some((key, self.len..end))
+        }
+    }
+}
+
+impl<'a, Param, K, F> FusedIterator for RunsByKey<'a, Param, K, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'a>) -> K,
+    K: PartialEq,
+{
 }
 
 /// An iterator over values from a [`ParallelVec`].
 ///
 /// See [`ParallelVec::into_iter`].
 ///
+/// Rows that are not consumed are dropped along with the iterator.
+///
 /// [`ParallelVec`]: crate::ParallelVec
-/// [`ParallelVec::iter_mut`]: crate::ParallelVec::into_iter
+/// [`ParallelVec::into_iter`]: crate::ParallelVec::into_iter
 #[repr(C)]
 pub struct IntoIter<Param: ParallelParam> {
     pub(crate) len: usize,
     pub(crate) storage: Param::Storage,
     pub(crate) capacity: usize,
     pub(crate) idx: usize,
+    pub(crate) _marker: PhantomData<Param>,
 }
 
 impl<Param: ParallelParam> Iterator for IntoIter<Param> {
@@ -124,14 +738,28 @@ impl<Param: ParallelParam> Iterator for IntoIter<Param> {
         let remaining = self.len - self.idx;
         (remaining, Some(remaining))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Param> {
+        let end = self.idx + n.min(self.len - self.idx);
+        while self.idx < end {
+            let idx = self.idx;
+            // Advance first so the row is not dropped again if its drop panics.
+            self.idx += 1;
+            // SAFE: The row is initialized and is never accessed again.
+            unsafe { Param::drop(Param::ptr_at(self.storage, idx)) };
+        }
+        self.next()
+    }
 }
 
 impl<Param: ParallelParam> ExactSizeIterator for IntoIter<Param> {}
 
+impl<Param: ParallelParam> FusedIterator for IntoIter<Param> {}
+
 impl<Param: ParallelParam> DoubleEndedIterator for IntoIter<Param> {
     fn next_back(&mut self) -> Option<Param> {
         unsafe {
-            if self.len == 0 {
+            if self.idx >= self.len {
                 return None;
             }
             self.len -= 1;
@@ -152,3 +780,236 @@ impl<Param: ParallelParam> Drop for IntoIter<Param> {
         }
     }
 }
+
+/// A draining iterator over a range of values from a [`ParallelVec`].
+///
+/// See [`ParallelVec::drain`].
+///
+/// [`ParallelVec::drain`]: crate::ParallelVec::drain
+pub struct Drain<'a, Param: ParallelParam> {
+    pub(crate) vec: &'a mut ParallelVec<Param>,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) tail_start: usize,
+    pub(crate) tail_len: usize,
+}
+
+impl<'a, Param: ParallelParam> Iterator for Drain<'a, Param> {
+    type Item = Param;
+    fn next(&mut self) -> Option<Param> {
+        if self.idx >= self.end {
+            return None;
+        }
+        // SAFE: The rows in idx..end are initialized and not yet moved out.
+        let value = unsafe { Param::read(Param::ptr_at(self.vec.storage, self.idx)) };
+        self.idx += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, Param: ParallelParam> ExactSizeIterator for Drain<'a, Param> {}
+
+impl<'a, Param: ParallelParam> FusedIterator for Drain<'a, Param> {}
+
+impl<'a, Param: ParallelParam> DoubleEndedIterator for Drain<'a, Param> {
+    fn next_back(&mut self) -> Option<Param> {
+        if self.idx >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFE: The rows in idx..end are initialized and not yet moved out.
+        Some(unsafe { Param::read(Param::ptr_at(self.vec.storage, self.end)) })
+    }
+}
+
+impl<'a, Param: ParallelParam> Drop for Drain<'a, Param> {
+    fn drop(&mut self) {
+        // Moves the tail back into place even if dropping one of the
+        // remaining rows panics.
+        struct MoveTail<'r, 'a, Param: ParallelParam>(&'r mut Drain<'a, Param>);
+
+        impl<'r, 'a, Param: ParallelParam> Drop for MoveTail<'r, 'a, Param> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let start = drain.vec.len;
+                // SAFE: Both ranges are within the allocation, and the rows
+                // between the start and the tail have all been moved out or dropped.
+                unsafe {
+                    if drain.tail_start != start {
+                        let src = Param::ptr_at(drain.vec.storage, drain.tail_start);
+                        let dst = Param::ptr_at(drain.vec.storage, start);
+                        Param::copy_to(src, dst, drain.tail_len);
+                    }
+                }
+                drain.vec.len = start + drain.tail_len;
+            }
+        }
+
+        let guard = MoveTail(self);
+        while guard.0.idx < guard.0.end {
+            let idx = guard.0.idx;
+            guard.0.idx += 1;
+            // SAFE: The row is initialized and has not been moved out.
+            unsafe { Param::drop(Param::ptr_at(guard.0.vec.storage, idx)) };
+        }
+    }
+}
+
+/// An iterator that removes and yields the rows of a [`ParallelVec`] that
+/// match a predicate.
+///
+/// See [`ParallelVec::extract_if`].
+///
+/// [`ParallelVec::extract_if`]: crate::ParallelVec::extract_if
+pub struct ExtractIf<'a, Param: ParallelParam, F> {
+    pub(crate) vec: &'a mut ParallelVec<Param>,
+    pub(crate) idx: usize,
+    pub(crate) deleted: usize,
+    pub(crate) original_len: usize,
+    pub(crate) pred: F,
+}
+
+impl<'a, Param, F> Iterator for ExtractIf<'a, Param, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::RefMut<'_>) -> bool,
+{
+    type Item = Param;
+    fn next(&mut self) -> Option<Param> {
+        while self.idx < self.original_len {
+            // SAFE: idx is in bounds and the row has not been moved out yet.
+            // Rows before idx - deleted are compacted and never revisited.
+            unsafe {
+                let ptr = Param::ptr_at(self.vec.storage, self.idx);
+                let extract = (self.pred)(Param::as_mut(ptr));
+                self.idx += 1;
+                if extract {
+                    self.deleted += 1;
+                    return Some(Param::read(ptr));
+                } else if self.deleted > 0 {
+                    let dst = Param::ptr_at(self.vec.storage, self.idx - 1 - self.deleted);
+                    Param::copy_to_nonoverlapping(ptr, dst, 1);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.original_len - self.idx))
+    }
+}
+
+impl<'a, Param: ParallelParam, F> Drop for ExtractIf<'a, Param, F> {
+    fn drop(&mut self) {
+        // Unvisited rows are kept and shifted down over the extracted rows.
+        unsafe {
+            if self.deleted > 0 && self.idx < self.original_len {
+                let src = Param::ptr_at(self.vec.storage, self.idx);
+                let dst = Param::ptr_at(self.vec.storage, self.idx - self.deleted);
+                Param::copy_to(src, dst, self.original_len - self.idx);
+            }
+        }
+        self.vec.len = self.original_len - self.deleted;
+    }
+}
+
+/// A splicing iterator for a [`ParallelVec`].
+///
+/// See [`ParallelVec::splice`].
+///
+/// [`ParallelVec::splice`]: crate::ParallelVec::splice
+pub struct Splice<'a, Param: ParallelParam, I: Iterator<Item = Param>> {
+    pub(crate) drain: Drain<'a, Param>,
+    pub(crate) replace_with: I,
+}
+
+impl<'a, Param: ParallelParam, I: Iterator<Item = Param>> Iterator for Splice<'a, Param, I> {
+    type Item = Param;
+    fn next(&mut self) -> Option<Param> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, Param: ParallelParam, I: Iterator<Item = Param>> ExactSizeIterator
+    for Splice<'a, Param, I>
+{
+}
+
+impl<'a, Param: ParallelParam, I: Iterator<Item = Param>> DoubleEndedIterator
+    for Splice<'a, Param, I>
+{
+    fn next_back(&mut self) -> Option<Param> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, Param: ParallelParam, I: Iterator<Item = Param>> Drop for Splice<'a, Param, I> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+        // The drain moves the tail back into place after the replacement rows
+        // when it is dropped, so there is nothing to do if there is no tail.
+        if self.drain.tail_len == 0 {
+            self.drain.vec.extend(self.replace_with.by_ref());
+            return;
+        }
+        if !self.drain.fill(&mut self.replace_with) {
+            return;
+        }
+        // Use the lower bound as an estimate of the remaining rows, so that
+        // the tail is usually only moved once.
+        let (lower, _) = self.replace_with.size_hint();
+        if lower > 0 {
+            self.drain.move_tail(lower);
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+        }
+        let mut collected: Vec<Param> = self.replace_with.by_ref().collect();
+        if !collected.is_empty() {
+            self.drain.move_tail(collected.len());
+            let filled = self.drain.fill(&mut collected.drain(..));
+            debug_assert!(filled);
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam> Drain<'a, Param> {
+    /// Fills the gap between the vector's length and the tail with rows from
+    /// `iter`. Returns `true` if the gap was completely filled.
+    fn fill(&mut self, iter: &mut impl Iterator<Item = Param>) -> bool {
+        while self.vec.len < self.tail_start {
+            match iter.next() {
+                // SAFE: The gap is within the allocation and holds no rows.
+                Some(value) => unsafe {
+                    Param::write(Param::ptr_at(self.vec.storage, self.vec.len), value);
+                    self.vec.len += 1;
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Moves the tail back by `additional` rows, growing the vector if needed.
+    fn move_tail(&mut self, additional: usize) {
+        let used = self.tail_start + self.tail_len;
+        self.vec.reserve(used + additional - self.vec.len);
+        // SAFE: The reservation ensures both ranges are within the allocation.
+        unsafe {
+            let src = Param::ptr_at(self.vec.storage, self.tail_start);
+            let dst = Param::ptr_at(self.vec.storage, self.tail_start + additional);
+            Param::copy_to(src, dst, self.tail_len);
+        }
+        self.tail_start += additional;
+    }
+}
@@ -0,0 +1,127 @@
+use crate::ArrayColumn;
+use core::ops::{Index, IndexMut};
+
+/// A view of a single lane of a fixed-size array column.
+///
+/// Array columns store each row's array contiguously, so consecutive values of
+/// a lane are strided by the length of the array.
+///
+/// See [`ParallelSlice::column_lane`].
+///
+/// [`ParallelSlice::column_lane`]: crate::ParallelSlice::column_lane
+pub struct Lane<'a, A> {
+    pub(crate) rows: &'a [A],
+    pub(crate) lane: usize,
+}
+
+impl<'a, A: ArrayColumn> Lane<'a, A> {
+    /// Returns the number of values in the lane.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the lane has no values.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Gets the value of the lane at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a A::Lane> {
+        self.rows.get(index).map(|row| row.lane(self.lane))
+    }
+
+    /// Returns an iterator over the values of the lane.
+    pub fn iter(&self) -> impl Iterator<Item = &'a A::Lane> + use<'a, A> {
+        let lane = self.lane;
+        self.rows.iter().map(move |row| row.lane(lane))
+    }
+}
+
+impl<A: ArrayColumn> Index<usize> for Lane<'_, A> {
+    type Output = A::Lane;
+    fn index(&self, index: usize) -> &A::Lane {
+        self.rows[index].lane(self.lane)
+    }
+}
+
+/// A mutable view of a single lane of a fixed-size array column.
+///
+/// See [`ParallelSliceMut::column_lane_mut`].
+///
+/// [`ParallelSliceMut::column_lane_mut`]: crate::ParallelSliceMut::column_lane_mut
+pub struct LaneMut<'a, A> {
+    pub(crate) rows: &'a mut [A],
+    pub(crate) lane: usize,
+}
+
+impl<A: ArrayColumn> LaneMut<'_, A> {
+    /// Returns the number of values in the lane.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the lane has no values.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Gets the value of the lane at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&A::Lane> {
+        self.rows.get(index).map(|row| row.lane(self.lane))
+    }
+
+    /// Gets a mutable reference to the value of the lane at `index`, or `None`
+    /// if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut A::Lane> {
+        let lane = self.lane;
+        self.rows.get_mut(index).map(|row| row.lane_mut(lane))
+    }
+
+    /// Returns an iterator over the values of the lane.
+    pub fn iter(&self) -> impl Iterator<Item = &A::Lane> {
+        let lane = self.lane;
+        self.rows.iter().map(move |row| row.lane(lane))
+    }
+
+    /// Returns an iterator that allows modifying each value of the lane.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut A::Lane> {
+        let lane = self.lane;
+        self.rows.iter_mut().map(move |row| row.lane_mut(lane))
+    }
+}
+
+impl<A: ArrayColumn> Index<usize> for LaneMut<'_, A> {
+    type Output = A::Lane;
+    fn index(&self, index: usize) -> &A::Lane {
+        self.rows[index].lane(self.lane)
+    }
+}
+
+impl<A: ArrayColumn> IndexMut<usize> for LaneMut<'_, A> {
+    fn index_mut(&mut self, index: usize) -> &mut A::Lane {
+        self.rows[index].lane_mut(self.lane)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_column_lane() {
+        let mut src = ParallelVec::from(vec![([1.0f32, 2.0, 3.0], 'a'), ([4.0, 5.0, 6.0], 'b')]);
+        assert_eq!(src.column::<0>(), &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let y = src.column_lane::<0, 1>();
+        assert_eq!(y.len(), 2);
+        assert_eq!(y[1], 5.0);
+        assert_eq!(y.get(2), None);
+        assert_eq!(y.iter().copied().collect::<Vec<_>>(), vec![2.0, 5.0]);
+        let mut z = src.column_lane_mut::<0, 2>();
+        for value in z.iter_mut() {
+            *value *= 10.0;
+        }
+        z[0] += 1.0;
+        assert_eq!(src.column::<0>(), &[[1.0, 2.0, 31.0], [4.0, 5.0, 60.0]]);
+    }
+}
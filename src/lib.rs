@@ -52,6 +52,46 @@
 //! enables this crate to compile in `#![no_std]` environments. There must be a set global
 //! allocator and heap support for this crate to work.
 //!
+//! ## `unstable-param` Support
+//! [`ParallelParam`] is normally sealed and only implemented for tuples. Enabling the
+//! `unstable-param` feature unseals the trait, allowing downstream crates to implement it
+//! for their own types (i.e. a struct with named fields). Implementing the trait is `unsafe`
+//! and its associated items are not covered by semver guarantees while this feature is enabled.
+//!
+//! ## `bytemuck` Support
+//! Enabling the `bytemuck` feature alongside `std` allows the columns of a `ParallelVec`
+//! whose columns are all `bytemuck::Pod` to be viewed as byte buffers via
+//! `as_io_slices` and `as_io_slices_mut`. These can be passed directly to
+//! `Write::write_vectored` and `Read::read_vectored` without copying. With or without
+//! `std`, it also enables `checksum`, which hashes each such column as a whole, and adds
+//! `ParallelVec::zeroed` for params whose columns are all `bytemuck::Zeroable`.
+//!
+//! ## `mlock` Support
+//! Enabling the `mlock` feature adds `ParallelVec::lock_memory` and
+//! `ParallelVec::with_capacity_locked`, which lock a vector's backing memory into RAM
+//! and keep it locked as the vector grows. This is intended for real-time applications
+//! that cannot tolerate page faults. Partial pages that are shared with other allocations
+//! are never unlocked. This requires the standard library.
+//!
+//! ## `numa` Support
+//! Enabling the `numa` feature adds `ParallelVec::set_numa_policy`, which binds or interleaves
+//! a vector's backing memory across NUMA nodes and keeps the policy applied as the vector
+//! grows. This is currently only supported on Linux, and requires the standard library.
+//!
+//! ## `polars` Support
+//! Enabling the `polars` feature adds `ParallelVec::into_data_frame` and
+//! `ParallelVec::from_data_frame`, which convert between a `ParallelVec` and a polars
+//! `DataFrame`, with one `Series` per column, for params whose columns are all
+//! `PolarsColumn`s. This requires the standard library.
+//!
+//! ## `rand` Support
+//! Enabling the `rand` feature adds weighted random sampling of rows, i.e.
+//! `ParallelSlice::sample_weighted`, using an alias table built over the rows.
+//!
+//! ## `rayon` Support
+//! Enabling the `rayon` feature adds parallel implementations of some column operations,
+//! i.e. `ParallelSlice::par_scan_column`. This requires the standard library.
+//!
 //!  ## `serde` Support
 //! `ParallelVec` can be serialized if it's parameters can be serialized. This is disabled by
 //! default. Use the `serde` feature to enable support for serialization and deserialization.
@@ -62,21 +102,65 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+mod archetype;
+mod bits;
+mod checksum;
+mod flagged;
+mod indexed;
 /// A collection of iterators types for [`ParallelVec`].
 pub mod iter;
+mod lane;
+#[cfg(feature = "mlock")]
+mod lock;
+#[cfg(feature = "numa")]
+mod numa;
 /// Implementations for [`ParallelParam`].
 pub mod param;
+#[cfg(feature = "polars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+mod polars;
+mod pool;
+#[cfg(target_has_atomic = "ptr")]
+mod ring;
+mod rolling;
+#[cfg(feature = "rand")]
+mod sample;
+mod scan;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serde;
 mod slice;
+mod sort;
+mod stats;
 mod vec;
+/// Lazy, composable views over [`ParallelSlice`]s.
+pub mod view;
 
-#[cfg(feature = "serde")]
-pub use crate::serde::*;
-
-pub use param::ParallelParam;
+#[cfg(feature = "polars")]
+pub use crate::polars::{ParallelFrame, PolarsColumn};
+pub use archetype::ArchetypeMap;
+pub use bits::PackedBools;
+pub use flagged::FlaggedParallelVec;
+pub use indexed::IndexedParallelVec;
+pub use lane::{Lane, LaneMut};
+#[cfg(feature = "numa")]
+pub use numa::NumaPolicy;
+#[cfg(feature = "bytemuck")]
+pub use param::ParallelPod;
+#[cfg(feature = "bytemuck")]
+pub use param::ParallelZeroable;
+pub use param::{
+    ArrayColumn, FloatColumn, ParallelColumn, ParallelInit, ParallelJoin, ParallelParam,
+    ParallelStats, ParallelUninit, SidecarSlices,
+};
+pub use pool::ParallelVecPool;
+#[cfg(target_has_atomic = "ptr")]
+pub use ring::{ParallelRingChannel, RingReceiver, RingSender};
+pub use rolling::Rolling;
+pub use scan::Scan;
 pub use slice::{ParallelSlice, ParallelSliceMut};
+pub use sort::SortByKeys;
+pub use stats::{ColumnStats, StatsParallelVec};
 pub use vec::ParallelVec;
 
 /// Error when attempting to convert types to [`ParallelVec`].
@@ -86,6 +170,44 @@ pub enum ParallelVecConversionError {
     UnevenLengths,
 }
 
+/// Error when attempting to get mutable references to many rows at once.
+///
+/// See [`ParallelSliceMut::get_many_mut`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum GetManyMutError {
+    /// One of the provided indices was out of bounds.
+    IndexOutOfBounds,
+    /// The same index was provided more than once.
+    OverlappingIndices,
+}
+
+/// Error when attempting to reorder rows with an invalid permutation.
+///
+/// See [`ParallelSliceMut::apply_permutation`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum PermutationError {
+    /// The permutation was not the same length as the slice.
+    UnevenLengths,
+    /// One of the provided indices was out of bounds.
+    IndexOutOfBounds,
+    /// The same index was provided more than once.
+    DuplicateIndices,
+}
+
+/// Error returned by the fallible allocation functions of [`ParallelVec`], such
+/// as [`ParallelVec::try_reserve`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum TryReserveError {
+    /// The requested capacity overflowed `usize`, or the layout of one of the
+    /// columns exceeded the maximum allocation size.
+    CapacityOverflow,
+    /// The allocator failed to allocate one of the columns.
+    AllocError {
+        /// The layout of the column allocation that failed.
+        layout: core::alloc::Layout,
+    },
+}
+
 #[inline(always)]
 pub(crate) fn assert_in_bounds(idx: usize, len: usize) {
     assert!(idx < len, "Index out of bounds: {} (len: {})", idx, len);
@@ -100,3 +222,32 @@ pub(crate) fn assert_in_bounds_inclusive(idx: usize, len: usize) {
 pub(crate) fn out_of_bounds(idx: usize, len: usize) {
     panic!("Index out of bounds: {} (len: {})", idx, len);
 }
+
+/// Resolves `range` into a concrete range within `0..len`.
+///
+/// # Panics
+/// Panics if the range is decreasing or out of bounds.
+pub(crate) fn resolve_range(
+    range: impl core::ops::RangeBounds<usize>,
+    len: usize,
+) -> core::ops::Range<usize> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "Range start {} is greater than range end {}",
+        start,
+        end
+    );
+    assert_in_bounds_inclusive(end, len);
+    start..end
+}
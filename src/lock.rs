@@ -0,0 +1,94 @@
+use std::io;
+
+/// Unlocks the pages that lie entirely within `ptr..ptr + len`.
+///
+/// The OS does not count how many times a page has been locked, so unlocking
+/// a partial page at either end would also unlock the other allocations that
+/// share it. Those pages are left locked instead.
+#[cfg(any(unix, windows))]
+pub(crate) unsafe fn unlock(ptr: *mut u8, len: usize) {
+    let page = page_size();
+    let start = (ptr as usize).next_multiple_of(page);
+    let end = (ptr as usize + len) / page * page;
+    if start < end {
+        unlock_pages(start as *mut u8, end - start);
+    }
+}
+
+#[cfg(unix)]
+pub(crate) unsafe fn lock(ptr: *mut u8, len: usize) -> io::Result<()> {
+    if libc::mlock(ptr.cast(), len) == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(unix)]
+unsafe fn unlock_pages(ptr: *mut u8, len: usize) {
+    libc::munlock(ptr.cast(), len);
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+#[cfg(windows)]
+#[repr(C)]
+#[allow(dead_code)]
+struct SystemInfo {
+    processor_architecture: u16,
+    reserved: u16,
+    page_size: u32,
+    minimum_application_address: *mut core::ffi::c_void,
+    maximum_application_address: *mut core::ffi::c_void,
+    active_processor_mask: usize,
+    number_of_processors: u32,
+    processor_type: u32,
+    allocation_granularity: u32,
+    processor_level: u16,
+    processor_revision: u16,
+}
+
+#[cfg(windows)]
+#[link(name = "kernel32")]
+extern "system" {
+    fn VirtualLock(address: *mut core::ffi::c_void, size: usize) -> i32;
+    fn VirtualUnlock(address: *mut core::ffi::c_void, size: usize) -> i32;
+    fn GetSystemInfo(info: *mut SystemInfo);
+}
+
+#[cfg(windows)]
+pub(crate) unsafe fn lock(ptr: *mut u8, len: usize) -> io::Result<()> {
+    if VirtualLock(ptr.cast(), len) != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+unsafe fn unlock_pages(ptr: *mut u8, len: usize) {
+    VirtualUnlock(ptr.cast(), len);
+}
+
+#[cfg(windows)]
+fn page_size() -> usize {
+    let mut info = core::mem::MaybeUninit::<SystemInfo>::uninit();
+    unsafe {
+        GetSystemInfo(info.as_mut_ptr());
+        info.assume_init().page_size as usize
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) unsafe fn lock(_: *mut u8, _: usize) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "page locking is not supported on this platform",
+    ))
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) unsafe fn unlock(_: *mut u8, _: usize) {}
@@ -0,0 +1,87 @@
+use std::io;
+
+/// A NUMA memory placement policy for the backing allocation of a
+/// [`ParallelVec`](crate::ParallelVec).
+///
+/// Node masks are bitmasks where bit `n` selects NUMA node `n`, so only the
+/// first 64 nodes can be selected.
+///
+/// See [`ParallelVec::set_numa_policy`](crate::ParallelVec::set_numa_policy).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumaPolicy {
+    /// Only place pages on the nodes in the mask.
+    Bind(u64),
+    /// Interleave pages round-robin across the nodes in the mask.
+    Interleave(u64),
+    /// Prefer placing pages on the given node, falling back to other nodes
+    /// if it is out of memory.
+    Preferred(u32),
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) unsafe fn apply(policy: Option<NumaPolicy>, ptr: *mut u8, len: usize) -> io::Result<()> {
+    const MPOL_DEFAULT: i32 = 0;
+    const MPOL_PREFERRED: i32 = 1;
+    const MPOL_BIND: i32 = 2;
+    const MPOL_INTERLEAVE: i32 = 3;
+    const MPOL_MF_MOVE: u32 = 1 << 1;
+
+    let (mode, mask) = match policy {
+        None => (MPOL_DEFAULT, 0u64),
+        Some(NumaPolicy::Bind(mask)) => (MPOL_BIND, mask),
+        Some(NumaPolicy::Interleave(mask)) => (MPOL_INTERLEAVE, mask),
+        Some(NumaPolicy::Preferred(node)) if node < 64 => (MPOL_PREFERRED, 1 << node),
+        Some(NumaPolicy::Preferred(_)) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "only the first 64 NUMA nodes can be selected",
+            ))
+        }
+    };
+
+    // mbind only operates on whole pages. Partial pages at either end are
+    // shared with other allocations and are left with their current policy.
+    let page = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+    let start = (ptr as usize).next_multiple_of(page);
+    let end = (ptr as usize + len) / page * page;
+    if end <= start {
+        return Ok(());
+    }
+    // The kernel reads one less than the provided number of bits, as an
+    // array of c_ulong words.
+    const WORD_BITS: usize = libc::c_ulong::BITS as usize;
+    let max_node: libc::c_ulong = 65;
+    let mut words: [libc::c_ulong; 64 / WORD_BITS] = [0; 64 / WORD_BITS];
+    for (idx, word) in words.iter_mut().enumerate() {
+        *word = (mask >> (idx * WORD_BITS)) as libc::c_ulong;
+    }
+    let result = libc::syscall(
+        libc::SYS_mbind,
+        start as *mut libc::c_void,
+        end - start,
+        mode,
+        if mode == MPOL_DEFAULT {
+            core::ptr::null()
+        } else {
+            words.as_ptr()
+        },
+        if mode == MPOL_DEFAULT { 0 } else { max_node },
+        MPOL_MF_MOVE,
+    );
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) unsafe fn apply(policy: Option<NumaPolicy>, _: *mut u8, _: usize) -> io::Result<()> {
+    match policy {
+        None => Ok(()),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "NUMA policies are not supported on this platform",
+        )),
+    }
+}
@@ -1,22 +1,75 @@
-use super::{ParallelVec, ParallelVecConversionError};
+use super::{
+    ColumnStats, ParallelSlice, ParallelSliceMut, ParallelVec, ParallelVecConversionError,
+    TryReserveError,
+};
+#[cfg(feature = "bytemuck")]
+use alloc::alloc::alloc_zeroed;
 use alloc::{
     alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout},
     vec::Vec,
 };
-use core::ptr::NonNull;
+#[cfg(feature = "bytemuck")]
+use core::hash::Hasher;
+use core::{cmp::Ordering, mem::MaybeUninit, ptr::NonNull};
+#[cfg(all(feature = "std", feature = "bytemuck"))]
+use std::io::{IoSlice, IoSliceMut};
 
 /// This trait contains the basic operations for creating variadic
 /// parallel vector implementations.
 ///
 /// This trait is sealed and cannot be implemented outside of
-/// `parallel_vec`.
+/// `parallel_vec` unless the `unstable-param` feature is enabled.
 ///
 /// This trait has blanket implementations of all tuples of up
 /// to size 12 of all types that are `'static`.
 ///
 /// # Safety
-/// None of the associated functions can panic.
-pub unsafe trait ParallelParam: Sized + private::Sealed {
+/// [`ParallelVec`] and the slice types rely on implementations for memory
+/// safety, so implementations outside of this crate must uphold the documented
+/// contract of every associated function. In particular:
+///
+///  - Every associated type that holds a set of pointers, references, slices,
+///    or iterators must hold exactly one per column, in the same column order.
+///  - [`alloc`], [`try_alloc`], and [`realloc`] must return storage valid for
+///    reads and writes of `capacity` values of every column, and [`dealloc`]
+///    must free exactly that storage. [`dangling`] must return non-null, well
+///    aligned pointers. Zero-sized columns must not allocate.
+///  - [`add`] and [`ptr_at`] must offset every column by the same number of
+///    values, and [`as_storage`] must be the inverse of [`as_ptr`].
+///  - [`read`], [`write`], [`copy_to`], [`copy_to_nonoverlapping`], [`swap`],
+///    [`swap_nonoverlapping`], and [`drop`] must behave like the functions of
+///    the same name in [`core::ptr`] applied to every column, and [`from_rows`]
+///    and [`into_rows`] must move every value exactly once.
+///  - [`get_vec_len`] and [`get_slices_len`] must only return `Some` if every
+///    column has the returned length.
+///  - Apart from the documented panics of [`alloc`] and [`realloc`], none of
+///    the associated functions may panic. The collections call them while
+///    rows are only partially moved, and do not guard against unwinding there.
+///
+/// The `unstable-param` feature is exempt from semver guarantees: the required
+/// associated items may change in any release.
+///
+/// [`try_alloc`]: Self::try_alloc
+/// [`dealloc`]: Self::dealloc
+/// [`dangling`]: Self::dangling
+/// [`add`]: Self::add
+/// [`ptr_at`]: Self::ptr_at
+/// [`read`]: Self::read
+/// [`write`]: Self::write
+/// [`copy_to`]: Self::copy_to
+/// [`copy_to_nonoverlapping`]: Self::copy_to_nonoverlapping
+/// [`swap`]: Self::swap
+/// [`swap_nonoverlapping`]: Self::swap_nonoverlapping
+/// [`drop`]: Self::drop
+/// [`from_rows`]: Self::from_rows
+/// [`into_rows`]: Self::into_rows
+/// [`get_vec_len`]: Self::get_vec_len
+/// [`get_slices_len`]: Self::get_slices_len
+/// [`alloc`]: Self::alloc
+/// [`realloc`]: Self::realloc
+/// [`as_storage`]: Self::as_storage
+/// [`as_ptr`]: Self::as_ptr
+pub unsafe trait ParallelParam: Sized + private::SealedParam {
     /// A set of [`NonNull`] pointers of the parameter.
     /// This is the main backing storage pointers for [`ParallelVec`].
     type Storage: Copy + Eq;
@@ -34,10 +87,17 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     type Slices<'a>;
     /// A set of mutable slice references of the parameter.
     type SlicesMut<'a>;
+    /// A set of mutable slice references to possibly uninitialized values of
+    /// the parameter.
+    type UninitSlicesMut<'a>;
     /// A set of iterators of immutable references of the parameter.
     type Iters<'a>;
     /// A set of iterators of mutable references of the parameter.
     type ItersMut<'a>;
+    /// A set of immutable fixed-size array references of the parameter.
+    type Arrays<'a, const N: usize>;
+    /// A set of mutable fixed-size array references of the parameter.
+    type ArraysMut<'a, const N: usize>;
 
     /// Creates a set of dangling pointers for the given types.
     fn dangling() -> Self::Storage;
@@ -48,10 +108,22 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
 
     /// Allocates a buffer for a given capacity.
     ///
+    /// # Panics
+    /// Panics if the layout of any column would exceed the maximum allocation
+    /// size. Aborts via [`handle_alloc_error`] if the allocator fails.
+    ///
     /// # Safety
     /// Capacity should be non-zero.
     unsafe fn alloc(capacity: usize) -> Self::Storage;
 
+    /// Allocates a buffer for a given capacity, returning an error instead of
+    /// panicking or aborting if any column's layout overflows or the allocator
+    /// fails. Nothing remains allocated if an error is returned.
+    ///
+    /// # Safety
+    /// Capacity should be non-zero.
+    unsafe fn try_alloc(capacity: usize) -> Result<Self::Storage, TryReserveError>;
+
     /// Realloc a buffer allocated from [`alloc`].
     ///
     /// # Safety
@@ -74,6 +146,20 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// [`alloc`]: Self::alloc
     unsafe fn dealloc(storage: Self::Storage, capacity: usize);
 
+    /// Calls `f` with the base pointer and size in bytes of every column's
+    /// allocation. Columns that do not allocate are skipped.
+    ///
+    /// # Safety
+    /// `storage` must have been allocated from [`alloc`] alongside
+    /// the provided `capacity`.
+    ///
+    /// [`alloc`]: Self::alloc
+    unsafe fn for_each_allocation(
+        storage: Self::Storage,
+        capacity: usize,
+        f: impl FnMut(*mut u8, usize),
+    );
+
     /// Gets the pointer at a given index.
     ///
     /// # Safety
@@ -91,12 +177,31 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// length.
     fn get_vec_len(vecs: &Self::Vecs) -> Option<usize>;
 
+    /// Gets the length of a set of slices.
+    ///
+    /// Returns `None` if not all of the slices share the same length.
+    fn get_slices_len(slices: &Self::Slices<'_>) -> Option<usize>;
+
+    /// Gets the underlying pointers for a set of slices.
+    ///
+    /// The returned pointers must never be written to.
+    fn get_slices_ptrs(slices: Self::Slices<'_>) -> Self::Ptr;
+
     /// Gets the underlying pointers for the associated `Vec`s.
     ///
     /// # Safety
     /// The provided `Vec`s must be correctly allocated.
     unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr;
 
+    /// Moves `len` elements from the columns pointed to by `src` into a newly
+    /// allocated set of `Vec`s.
+    ///
+    /// # Safety
+    /// `src` must be a valid, non-null pointer for the associated types, and
+    /// valid for reads of `len` elements. The values at `src` are moved out and
+    /// must not be used or dropped afterwards.
+    unsafe fn into_vecs(src: Self::Ptr, len: usize) -> Self::Vecs;
+
     /// Adds `offset` to all of the pointers in `base`.
     ///
     /// # Safety
@@ -123,6 +228,34 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     ///  - `src..src + size` must not overlap with the memory range of `dst..dst + size`.
     unsafe fn copy_to_nonoverlapping(src: Self::Ptr, dst: Self::Ptr, size: usize);
 
+    /// Moves `len` rows out of the contiguous array of values at `src` into
+    /// the columns pointed to by `dst`.
+    ///
+    /// The rows are transposed in blocks small enough to stay in cache while
+    /// each column is written out sequentially.
+    ///
+    /// # Safety
+    ///  - `src` must be valid for reads of `len` values of `Self`.
+    ///  - `dst` must be a valid, non-null pointer for the associated types, and
+    ///    valid for writes of `len` elements.
+    ///  - The values at `src` are moved out and must not be used or dropped
+    ///    afterwards.
+    unsafe fn from_rows(src: *const Self, dst: Self::Ptr, len: usize);
+
+    /// Moves `len` rows out of the columns pointed to by `src` into the
+    /// contiguous array of values at `dst`.
+    ///
+    /// The rows are transposed in blocks small enough to stay in cache while
+    /// each column is read sequentially.
+    ///
+    /// # Safety
+    ///  - `src` must be a valid, non-null pointer for the associated types, and
+    ///    valid for reads of `len` elements.
+    ///  - `dst` must be valid for writes of `len` values of `Self`.
+    ///  - The values at `src` are moved out and must not be used or dropped
+    ///    afterwards.
+    unsafe fn into_rows(src: Self::Ptr, dst: *mut Self, len: usize);
+
     /// Creates a set of immutable slices from `ptr` and a provided length.
     ///
     /// # Safety
@@ -137,6 +270,28 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// for the allocation that `ptr` points to.
     unsafe fn as_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'a>;
 
+    /// Creates a set of mutable slices of possibly uninitialized values from
+    /// `ptr` and a provided length.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid, non-null pointer. `len` must be approriately set
+    /// for the allocation that `ptr` points to.
+    unsafe fn as_uninit_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::UninitSlicesMut<'a>;
+
+    /// Creates a set of immutable fixed-size array references from `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid, non-null pointer. The allocation that `ptr` points
+    /// to must have at least `N` initialized elements.
+    unsafe fn as_arrays<'a, const N: usize>(ptr: Self::Ptr) -> Self::Arrays<'a, N>;
+
+    /// Creates a set of mutable fixed-size array references from `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid, non-null pointer. The allocation that `ptr` points
+    /// to must have at least `N` initialized elements.
+    unsafe fn as_arrays_mut<'a, const N: usize>(ptr: Self::Ptr) -> Self::ArraysMut<'a, N>;
+
     /// Creates a set of iterators from slices.
     #[allow(clippy::needless_lifetimes)]
     fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a>;
@@ -154,6 +309,9 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// `ptr` must be a valid, non-null pointer.
     unsafe fn as_ref<'a>(ptr: Self::Ptr) -> Self::Ref<'a>;
 
+    /// Converts a reference to a row into a set of references to its values.
+    fn row_ref(value: &Self) -> Self::Ref<'_>;
+
     /// Converts `ptr` into the storage type.
     ///
     /// # Safety
@@ -184,6 +342,15 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// Both `a` and `b` must be valid for all of it's consitutent member pointers.
     unsafe fn swap(a: Self::Ptr, other: Self::Ptr);
 
+    /// Swaps `count` values starting at `a` with the `count` values starting
+    /// at `b`.
+    ///
+    /// # Safety
+    /// Both `a` and `b` must be valid for reads and writes of `count` values
+    /// for all of it's consitutent member pointers, and the two regions must
+    /// not overlap.
+    unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, count: usize);
+
     /// Drops the values pointed to by the pointers.
     ///
     /// # Safety
@@ -192,15 +359,426 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     unsafe fn drop(ptr: Self::Ptr);
 }
 
+/// Access to an individual column of a [`ParallelParam`], selected by its
+/// index `N` within the tuple.
+///
+/// This trait has implementations for every index of all of the tuples that
+/// implement [`ParallelParam`].
+///
+/// # Safety
+/// [`column_ptr`] must return the pointer for the `N`th column from the provided
+/// set of pointers.
+///
+/// [`column_ptr`]: Self::column_ptr
+pub unsafe trait ParallelColumn<const N: usize>: ParallelParam {
+    /// The type of the values stored in the column.
+    type Column;
+
+    /// Gets the pointer for the column from a set of pointers.
+    fn column_ptr(ptr: Self::Ptr) -> *mut Self::Column;
+}
+
+/// Byte level access to the columns of a [`ParallelParam`] whose values are all
+/// plain old data, for use with vectored I/O and checksums.
+///
+/// This trait has implementations for all of the tuples that implement
+/// [`ParallelParam`] where every column is [`bytemuck::Pod`].
+///
+/// # Safety
+/// [`io_slices`] and [`io_slices_mut`] must return exactly one buffer per column,
+/// in column order, each covering the entire provided slice and nothing more.
+///
+/// [`io_slices`]: Self::io_slices
+/// [`io_slices_mut`]: Self::io_slices_mut
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub unsafe trait ParallelPod: ParallelParam {
+    /// A fixed size array of [`IoSlice`]s, one per column.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    type IoSlices<'a>: AsRef<[IoSlice<'a>]>;
+    /// A fixed size array of [`IoSliceMut`]s, one per column.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    type IoSlicesMut<'a>: AsMut<[IoSliceMut<'a>]>;
+
+    /// Reinterprets each of the slices as an [`IoSlice`] over its bytes.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn io_slices(slices: Self::Slices<'_>) -> Self::IoSlices<'_>;
+
+    /// Reinterprets each of the slices as an [`IoSliceMut`] over its bytes.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn io_slices_mut(slices: Self::SlicesMut<'_>) -> Self::IoSlicesMut<'_>;
+
+    /// Writes each column to `hasher`, in column order, as its length in bytes
+    /// followed by the little-endian bytes of each of its values.
+    ///
+    /// Each value is byte swapped as a whole on big-endian targets, so the
+    /// bytes written are the same on every platform for columns of primitive
+    /// numbers. On big-endian targets, the bytes of a column may be split
+    /// across multiple calls to [`Hasher::write`].
+    fn hash_columns_le<H: Hasher>(slices: Self::Slices<'_>, hasher: &mut H);
+}
+
+/// Writes a column of plain old data to `hasher` as little-endian values.
+#[cfg(feature = "bytemuck")]
+fn hash_column_le<T: bytemuck::Pod, H: Hasher>(column: &[T], hasher: &mut H) {
+    hasher.write_u64(core::mem::size_of_val(column) as u64);
+    if core::mem::size_of::<T>() == 0 {
+        return;
+    }
+    if cfg!(target_endian = "little") || core::mem::size_of::<T>() == 1 {
+        hasher.write(bytemuck::cast_slice(column));
+    } else {
+        for value in column {
+            let mut value = *value;
+            bytemuck::bytes_of_mut(&mut value).reverse();
+            hasher.write(bytemuck::bytes_of(&value));
+        }
+    }
+}
+
+/// A [`ParallelParam`] whose columns are all valid when every byte is zero,
+/// which allows them to be allocated already initialized.
+///
+/// This trait has implementations for all of the tuples that implement
+/// [`ParallelParam`] where every column is [`bytemuck::Zeroable`].
+///
+/// # Safety
+/// Every column must be valid when all of its bytes are zero, and
+/// [`alloc_zeroed`] must return storage compatible with [`ParallelParam::alloc`].
+///
+/// [`alloc_zeroed`]: Self::alloc_zeroed
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub unsafe trait ParallelZeroable: ParallelParam {
+    /// Allocates a buffer for a given capacity with every byte set to zero.
+    ///
+    /// # Panics
+    /// Panics if the layout of any column would exceed the maximum allocation
+    /// size. Aborts via [`handle_alloc_error`] if the allocator fails.
+    ///
+    /// # Safety
+    /// Capacity should be non-zero.
+    unsafe fn alloc_zeroed(capacity: usize) -> Self::Storage;
+}
+
+/// A fixed-size array column whose individual elements, or lanes, can be
+/// accessed independently.
+///
+/// This is implemented for all arrays `[T; N]`.
+pub trait ArrayColumn {
+    /// The type of each lane.
+    type Lane;
+    /// The number of lanes in the array.
+    const LANES: usize;
+
+    /// Gets a reference to the `lane`th element of the array.
+    fn lane(&self, lane: usize) -> &Self::Lane;
+
+    /// Gets a mutable reference to the `lane`th element of the array.
+    fn lane_mut(&mut self, lane: usize) -> &mut Self::Lane;
+}
+
+impl<T, const N: usize> ArrayColumn for [T; N] {
+    type Lane = T;
+    const LANES: usize = N;
+
+    #[inline(always)]
+    fn lane(&self, lane: usize) -> &T {
+        &self[lane]
+    }
+
+    #[inline(always)]
+    fn lane_mut(&mut self, lane: usize) -> &mut T {
+        &mut self[lane]
+    }
+}
+
+/// A [`ParallelParam`] whose columns are all [`MaybeUninit`], which can be
+/// converted into the equivalent initialized [`ParallelParam`] in place.
+///
+/// This trait has implementations for all tuples of [`MaybeUninit`] values
+/// whose initialized tuple implements [`ParallelParam`].
+///
+/// # Safety
+/// Every column of [`Init`] must have the same layout as the corresponding
+/// column of `Self`, and [`assume_init_storage`] must return the same pointers,
+/// so that storage allocated for one may be used and deallocated as the other.
+///
+/// [`Init`]: Self::Init
+/// [`assume_init_storage`]: Self::assume_init_storage
+pub unsafe trait ParallelUninit: ParallelParam {
+    /// The initialized version of the row.
+    type Init: ParallelParam;
+
+    /// Reinterprets the storage as storage for the initialized columns.
+    fn assume_init_storage(storage: Self::Storage) -> <Self::Init as ParallelParam>::Storage;
+}
+
+/// A [`ParallelParam`] with an equivalent [`ParallelUninit`] row, whose columns
+/// are the same columns wrapped in [`MaybeUninit`].
+///
+/// This trait has implementations for all of the tuples that implement
+/// [`ParallelParam`].
+pub trait ParallelInit: ParallelParam {
+    /// The uninitialized version of the row.
+    type Uninit: ParallelUninit<Init = Self>;
+}
+
+/// Per-column statistics over the rows of a [`ParallelParam`], used by
+/// [`StatsParallelVec`].
+///
+/// This trait has implementations for all of the tuples that implement
+/// [`ParallelParam`] where every column is [`PartialOrd`] and [`Clone`].
+///
+/// [`StatsParallelVec`]: crate::StatsParallelVec
+pub trait ParallelStats: ParallelParam {
+    /// A tuple of [`ColumnStats`], one per column.
+    type Stats: Clone + Default;
+
+    /// Widens the bounds in `stats` to include every value in `row`.
+    fn observe(stats: &mut Self::Stats, row: Self::Ref<'_>);
+
+    /// Marks the bounds in `stats` as no longer exact for every column where
+    /// the value in `row` lies on the boundary.
+    fn forget(stats: &mut Self::Stats, row: Self::Ref<'_>);
+}
+
+/// A floating point column type that can be totally ordered.
+///
+/// This is implemented for [`f32`] and [`f64`].
+pub trait FloatColumn: Copy + private::Sealed {
+    /// Returns the ordering between `self` and `other` according to the IEEE 754
+    /// `totalOrder` predicate. See [`f64::total_cmp`].
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns `true` if the value is NaN.
+    fn is_nan(self) -> bool;
+}
+
+macro_rules! impl_float_column {
+    ($($t:ty),*) => {
+        $(
+            impl FloatColumn for $t {
+                #[inline(always)]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+
+                #[inline(always)]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_column!(f32, f64);
+
+/// One or more slices of sidecar data that can be iterated in lockstep with
+/// the rows of a [`ParallelSliceMut`].
+///
+/// This is implemented for `&[T]`, `&mut [T]`, and tuples of up to four of
+/// them. See [`ParallelSliceMut::zip_slices`].
+///
+/// # Safety
+/// [`get`] must only access the element at the provided index of each slice,
+/// so that distinct indices never alias.
+///
+/// [`get`]: Self::get
+pub unsafe trait SidecarSlices: Sized + private::Sealed {
+    /// The references yielded for each row.
+    type Item;
+    /// A set of pointers to the start of each slice.
+    type Ptr: Copy;
+
+    /// Gets the length of the slices.
+    ///
+    /// Returns `None` if not all of the slices share the same length.
+    fn get_len(&self) -> Option<usize>;
+
+    /// Converts the slices into pointers to their first elements.
+    fn into_ptr(self) -> Self::Ptr;
+
+    /// Gets the references to the elements at `idx`.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by [`into_ptr`], `idx` must be within the
+    /// length of the slices, and each index may only be used once.
+    ///
+    /// [`into_ptr`]: Self::into_ptr
+    unsafe fn get(ptr: Self::Ptr, idx: usize) -> Self::Item;
+}
+
+unsafe impl<'s, T> SidecarSlices for &'s [T] {
+    type Item = &'s T;
+    type Ptr = *const T;
+
+    #[inline(always)]
+    fn get_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[inline(always)]
+    fn into_ptr(self) -> Self::Ptr {
+        self.as_ptr()
+    }
+
+    #[inline(always)]
+    unsafe fn get(ptr: Self::Ptr, idx: usize) -> Self::Item {
+        &*ptr.add(idx)
+    }
+}
+
+unsafe impl<'s, T> SidecarSlices for &'s mut [T] {
+    type Item = &'s mut T;
+    type Ptr = *mut T;
+
+    #[inline(always)]
+    fn get_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[inline(always)]
+    fn into_ptr(self) -> Self::Ptr {
+        self.as_mut_ptr()
+    }
+
+    #[inline(always)]
+    unsafe fn get(ptr: Self::Ptr, idx: usize) -> Self::Item {
+        &mut *ptr.add(idx)
+    }
+}
+
+macro_rules! impl_sidecar_slices {
+    ($t1: ident, $i1: tt $(, $ts: ident, $is: tt)*) => {
+        unsafe impl<$t1: SidecarSlices $(, $ts: SidecarSlices)*> SidecarSlices for ($t1, $($ts),*) {
+            type Item = ($t1::Item, $($ts::Item),*);
+            type Ptr = ($t1::Ptr, $($ts::Ptr),*);
+
+            fn get_len(&self) -> Option<usize> {
+                let len = self.$i1.get_len()?;
+                $(
+                    if self.$is.get_len()? != len {
+                        return None;
+                    }
+                )*
+                Some(len)
+            }
+
+            #[inline(always)]
+            fn into_ptr(self) -> Self::Ptr {
+                (self.$i1.into_ptr(), $(self.$is.into_ptr()),*)
+            }
+
+            #[inline(always)]
+            unsafe fn get(ptr: Self::Ptr, idx: usize) -> Self::Item {
+                ($t1::get(ptr.$i1, idx), $($ts::get(ptr.$is, idx)),*)
+            }
+        }
+    };
+}
+
+impl_sidecar_slices!(S1, 0, S2, 1);
+impl_sidecar_slices!(S1, 0, S2, 1, S3, 2);
+impl_sidecar_slices!(S1, 0, S2, 1, S3, 2, S4, 3);
+
+/// Concatenation of the columns of two [`ParallelParam`]s into a single, wider
+/// [`ParallelParam`].
+///
+/// This trait has implementations for every pair of tuples that implement
+/// [`ParallelParam`] whose combined tuple also implements [`ParallelParam`].
+/// See [`ParallelVec::join`] and [`ParallelVec::split_columns`].
+///
+/// # Safety
+/// [`join_ptrs`] must return the pointers of `Self` followed by the pointers
+/// of `Other`, in column order, and [`split_ptrs`] must be its exact inverse.
+///
+/// [`join_ptrs`]: Self::join_ptrs
+/// [`split_ptrs`]: Self::split_ptrs
+/// [`ParallelVec::join`]: crate::ParallelVec::join
+/// [`ParallelVec::split_columns`]: crate::ParallelVec::split_columns
+pub unsafe trait ParallelJoin<Other: ParallelParam>: ParallelParam {
+    /// The combined row, with the columns of `Self` followed by the columns of `Other`.
+    type Output: ParallelParam;
+
+    /// Combines two sets of pointers into the pointers of the combined row.
+    fn join_ptrs(ptr: Self::Ptr, other: Other::Ptr) -> <Self::Output as ParallelParam>::Ptr;
+
+    /// Splits the pointers of the combined row back into the two sets of pointers.
+    fn split_ptrs(ptr: <Self::Output as ParallelParam>::Ptr) -> (Self::Ptr, Other::Ptr);
+}
+
+macro_rules! impl_parallel_join {
+    (($($as:ident),*), ($($bs:ident),*)) => {
+        #[allow(non_snake_case)]
+        unsafe impl<$($as: 'static,)* $($bs: 'static),*> ParallelJoin<($($bs,)*)> for ($($as,)*) {
+            type Output = ($($as,)* $($bs,)*);
+
+            #[inline(always)]
+            fn join_ptrs(ptr: Self::Ptr, other: <($($bs,)*) as ParallelParam>::Ptr) -> <Self::Output as ParallelParam>::Ptr {
+                let ($($as,)*) = ptr;
+                let ($($bs,)*) = other;
+                ($($as,)* $($bs,)*)
+            }
+
+            #[inline(always)]
+            fn split_ptrs(ptr: <Self::Output as ParallelParam>::Ptr) -> (Self::Ptr, <($($bs,)*) as ParallelParam>::Ptr) {
+                let ($($as,)* $($bs,)*) = ptr;
+                (($($as,)*), ($($bs,)*))
+            }
+        }
+    };
+}
+
+/// Implements [`ParallelJoin`] for every way of splitting the provided
+/// columns into two tuples of at least two columns each.
+macro_rules! impl_parallel_joins {
+    (($($as:ident),*); ($b1:ident, $b2:ident $(, $bs:ident)*)) => {
+        impl_parallel_join!(($($as),*), ($b1, $b2 $(, $bs)*));
+        impl_parallel_joins!(($($as,)* $b1); ($b2 $(, $bs)*));
+    };
+    (($($as:ident),*); ($b1:ident)) => {};
+}
+
+impl_parallel_joins!((T1, T2); (T3, T4));
+impl_parallel_joins!((T1, T2); (T3, T4, T5));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7, T8));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7, T8, T9));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7, T8, T9, T10));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7, T8, T9, T10, T11));
+impl_parallel_joins!((T1, T2); (T3, T4, T5, T6, T7, T8, T9, T10, T11, T12));
+
 mod private {
     pub trait Sealed {}
 
+    /// The seal for [`ParallelParam`](super::ParallelParam), which is lifted
+    /// by the `unstable-param` feature. The other sealed traits are unaffected.
+    pub trait SealedParam {}
+
+    #[cfg(feature = "unstable-param")]
+    impl<T> SealedParam for T {}
+
+    impl<T> Sealed for &[T] {}
+    impl<T> Sealed for &mut [T] {}
+
     macro_rules! impl_seal {
         ($($ts:ident),*) => {
             impl<$($ts,)*> Sealed for ($($ts,)*) {}
+            #[cfg(not(feature = "unstable-param"))]
+            impl<$($ts,)*> SealedParam for ($($ts,)*) {}
         }
     }
 
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+
     impl_seal!(T1, T2);
     impl_seal!(T1, T2, T3);
     impl_seal!(T1, T2, T3, T4);
@@ -220,19 +798,179 @@ macro_rules! skip_first {
     };
 }
 
+/// The number of bytes of rows transposed at a time by [`ParallelParam::from_rows`]
+/// and [`ParallelParam::into_rows`]. This is sized to comfortably fit within L1 cache.
+const TRANSPOSE_BLOCK_BYTES: usize = 16 * 1024;
+
+#[inline(always)]
+const fn transpose_block_len<T>() -> usize {
+    let size = core::mem::size_of::<T>();
+    if size == 0 || size >= TRANSPOSE_BLOCK_BYTES {
+        1
+    } else {
+        TRANSPOSE_BLOCK_BYTES / size
+    }
+}
+
+#[cfg(all(feature = "std", feature = "bytemuck"))]
+macro_rules! one {
+    ($t: ident) => {
+        1
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_parallel_pod {
+    ($($ts:ident),*) => {
+        unsafe impl<$($ts: bytemuck::Pod),*> ParallelPod for ($($ts,)*) {
+            #[cfg(feature = "std")]
+            type IoSlices<'a> = [IoSlice<'a>; 0 $(+ one!($ts))*];
+            #[cfg(feature = "std")]
+            type IoSlicesMut<'a> = [IoSliceMut<'a>; 0 $(+ one!($ts))*];
+
+            #[cfg(feature = "std")]
+            fn io_slices(slices: Self::Slices<'_>) -> Self::IoSlices<'_> {
+                let ($($ts,)*) = slices;
+                // SAFE: Pod values have no padding bytes, so every byte of the
+                // slice is initialized. The byte slice covers exactly the same memory.
+                unsafe {
+                    [$(IoSlice::new(core::slice::from_raw_parts(
+                        $ts.as_ptr().cast::<u8>(),
+                        core::mem::size_of_val($ts),
+                    ))),*]
+                }
+            }
+
+            #[cfg(feature = "std")]
+            fn io_slices_mut(slices: Self::SlicesMut<'_>) -> Self::IoSlicesMut<'_> {
+                let ($($ts,)*) = slices;
+                // SAFE: Pod values are valid for any bit pattern, so arbitrary bytes
+                // may be written to them. The byte slice covers exactly the same memory.
+                unsafe {
+                    [$(IoSliceMut::new(core::slice::from_raw_parts_mut(
+                        $ts.as_mut_ptr().cast::<u8>(),
+                        core::mem::size_of_val($ts),
+                    ))),*]
+                }
+            }
+
+            fn hash_columns_le<H: Hasher>(slices: Self::Slices<'_>, hasher: &mut H) {
+                let ($($ts,)*) = slices;
+                $(hash_column_le($ts, hasher);)*
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+macro_rules! impl_parallel_pod {
+    ($($ts:ident),*) => {};
+}
+
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_parallel_zeroable {
+    ($($ts:ident),*) => {
+        unsafe impl<$($ts: bytemuck::Zeroable + 'static),*> ParallelZeroable for ($($ts,)*) {
+            unsafe fn alloc_zeroed(capacity: usize) -> Self::Storage {
+                debug_assert!(capacity != 0);
+                ($(
+                    if core::mem::size_of::<$ts>() != 0 {
+                        let layout = Layout::array::<$ts>(capacity).expect("capacity overflow");
+                        let ptr = alloc_zeroed(layout).cast::<$ts>();
+                        NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                    } else {
+                        NonNull::dangling()
+                    },
+                )*)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+macro_rules! impl_parallel_zeroable {
+    ($($ts:ident),*) => {};
+}
+
+macro_rules! impl_parallel_columns {
+    ($params: tt; $($t:ident $i:tt),*) => {
+        $(impl_parallel_column!($params, $t, $i);)*
+    };
+}
+
+macro_rules! impl_parallel_column {
+    (($($ts:ident),*), $t:ident, $i:tt) => {
+        unsafe impl<$($ts: 'static),*> ParallelColumn<$i> for ($($ts,)*) {
+            type Column = $t;
+
+            #[inline(always)]
+            fn column_ptr(ptr: Self::Ptr) -> *mut $t {
+                ptr.$i
+            }
+        }
+    };
+}
+
+/// Tracks the columns allocated by [`ParallelParam::try_alloc`], freeing them
+/// if a later column fails to allocate.
+struct AllocGuard {
+    allocations: [(*mut u8, Layout); 12],
+    len: usize,
+}
+
+impl AllocGuard {
+    fn new() -> Self {
+        Self {
+            allocations: [(core::ptr::null_mut(), Layout::new::<()>()); 12],
+            len: 0,
+        }
+    }
+
+    unsafe fn try_alloc_column<T>(
+        &mut self,
+        capacity: usize,
+    ) -> Result<NonNull<T>, TryReserveError> {
+        if core::mem::size_of::<T>() == 0 {
+            return Ok(NonNull::dangling());
+        }
+        let layout = Layout::array::<T>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        let ptr = NonNull::new(alloc(layout)).ok_or(TryReserveError::AllocError { layout })?;
+        self.allocations[self.len] = (ptr.as_ptr(), layout);
+        self.len += 1;
+        Ok(ptr.cast())
+    }
+
+    /// Keeps every allocation made so far.
+    fn commit(mut self) {
+        self.len = 0;
+    }
+}
+
+impl Drop for AllocGuard {
+    fn drop(&mut self) {
+        for &(ptr, layout) in &self.allocations[..self.len] {
+            // SAFE: Every tracked allocation was made with its layout.
+            unsafe { dealloc(ptr, layout) };
+        }
+    }
+}
+
 macro_rules! impl_parallel_vec_param {
-    ($t1: ident, $v1: ident, $($ts:ident, $vs:ident),*) => {
+    ($t1: ident, $v1: ident, $i1: tt $(, $ts:ident, $vs:ident, $is: tt)*) => {
         unsafe impl<$t1: 'static $(, $ts: 'static)*> ParallelParam for ($t1 $(, $ts)*) {
             type Storage = (NonNull<$t1> $(, NonNull<$ts>)*);
             type Ref<'a> = (&'a $t1, $(&'a $ts,)*);
             type RefMut<'a> = (&'a mut $t1, $(&'a mut $ts,)*);
             type Slices<'a> = (&'a [$t1] $(, &'a [$ts])*);
             type SlicesMut<'a> = (&'a mut [$t1] $(, &'a mut [$ts])*);
+            type UninitSlicesMut<'a> = (&'a mut [MaybeUninit<$t1>] $(, &'a mut [MaybeUninit<$ts>])*);
             type Vecs = (Vec<$t1> $(, Vec<$ts>)*);
             type Ptr = (*mut $t1 $(, *mut $ts)*);
             type Offsets = (usize $(, skip_first!($ts, usize))*);
             type Iters<'a> = (core::slice::Iter<'a, $t1> $(, core::slice::Iter<'a, $ts>)*);
             type ItersMut<'a>= (core::slice::IterMut<'a, $t1> $(, core::slice::IterMut<'a, $ts>)*);
+            type Arrays<'a, const N: usize> = (&'a [$t1; N] $(, &'a [$ts; N])*);
+            type ArraysMut<'a, const N: usize> = (&'a mut [$t1; N] $(, &'a mut [$ts; N])*);
 
             #[inline(always)]
             fn dangling() -> Self::Storage {
@@ -248,7 +986,7 @@ macro_rules! impl_parallel_vec_param {
             unsafe fn alloc(capacity: usize) -> Self::Storage {
                 debug_assert!(capacity != 0);
                 let $t1 = if core::mem::size_of::<$t1>() != 0 {
-                    let layout = Layout::array::<$t1>(capacity).unwrap();
+                    let layout = Layout::array::<$t1>(capacity).expect("capacity overflow");
                     let ptr = alloc(layout).cast::<$t1>();
                     NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
                 } else {
@@ -256,7 +994,7 @@ macro_rules! impl_parallel_vec_param {
                 };
                 $(
                     let $ts = if core::mem::size_of::<$ts>() != 0 {
-                        let layout = Layout::array::<$ts>(capacity).unwrap();
+                        let layout = Layout::array::<$ts>(capacity).expect("capacity overflow");
                         let ptr = alloc(layout).cast::<$ts>();
                         NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
                     } else {
@@ -266,6 +1004,15 @@ macro_rules! impl_parallel_vec_param {
                 ($t1 $(, $ts)*)
             }
 
+            unsafe fn try_alloc(capacity: usize) -> Result<Self::Storage, TryReserveError> {
+                debug_assert!(capacity != 0);
+                let mut guard = AllocGuard::new();
+                let $t1 = guard.try_alloc_column::<$t1>(capacity)?;
+                $(let $ts = guard.try_alloc_column::<$ts>(capacity)?;)*
+                guard.commit();
+                Ok(($t1 $(, $ts)*))
+            }
+
             unsafe fn realloc(storage: Self::Storage, current_capacity: usize, new_capacity: usize) -> Self::Storage {
                 if new_capacity == 0 {
                     Self::dealloc(storage, current_capacity);
@@ -277,18 +1024,18 @@ macro_rules! impl_parallel_vec_param {
                 let ($t1 $(, $ts)*) = storage;
                 let $t1 = if core::mem::size_of::<$t1>() != 0 {
                     let layout = Layout::array::<$t1>(current_capacity).unwrap();
-                    let new_size = core::mem::size_of::<$t1>().checked_mul(new_capacity).unwrap();
-                    let ptr = realloc($t1.as_ptr().cast::<u8>(), layout, new_size).cast::<$t1>();
-                    NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                    let new_layout = Layout::array::<$t1>(new_capacity).expect("capacity overflow");
+                    let ptr = realloc($t1.as_ptr().cast::<u8>(), layout, new_layout.size()).cast::<$t1>();
+                    NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(new_layout))
                 } else {
                     $t1
                 };
                 $(
                     let $ts = if core::mem::size_of::<$ts>() != 0 {
                         let layout = Layout::array::<$ts>(current_capacity).unwrap();
-                        let new_size = core::mem::size_of::<$ts>().checked_mul(new_capacity).unwrap();
-                        let ptr = realloc($ts.as_ptr().cast::<u8>(), layout, new_size).cast::<$ts>();
-                        NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                        let new_layout = Layout::array::<$ts>(new_capacity).expect("capacity overflow");
+                        let ptr = realloc($ts.as_ptr().cast::<u8>(), layout, new_layout.size()).cast::<$ts>();
+                        NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(new_layout))
                     } else {
                         $ts
                     };
@@ -311,6 +1058,25 @@ macro_rules! impl_parallel_vec_param {
                 )*
             }
 
+            unsafe fn for_each_allocation(
+                storage: Self::Storage,
+                capacity: usize,
+                mut f: impl FnMut(*mut u8, usize),
+            ) {
+                if capacity == 0 {
+                    return;
+                }
+                let ($t1 $(, $ts)*) = storage;
+                if core::mem::size_of::<$t1>() != 0 {
+                    f($t1.as_ptr().cast::<u8>(), core::mem::size_of::<$t1>() * capacity);
+                }
+                $(
+                    if core::mem::size_of::<$ts>() != 0 {
+                        f($ts.as_ptr().cast::<u8>(), core::mem::size_of::<$ts>() * capacity);
+                    }
+                )*
+            }
+
             #[inline(always)]
             unsafe fn add(base: Self::Ptr, offset: usize) -> Self::Ptr {
                 let ($t1, $($ts),*) = base;
@@ -335,6 +1101,42 @@ macro_rules! impl_parallel_vec_param {
                 )*
             }
 
+            unsafe fn from_rows(src: *const Self, dst: Self::Ptr, len: usize) {
+                let ($t1, $($ts),*) = dst;
+                let block = transpose_block_len::<Self>();
+                let mut start = 0;
+                while start < len {
+                    let end = core::cmp::min(start + block, len);
+                    for idx in start..end {
+                        $t1.add(idx).write(core::ptr::addr_of!((*src.add(idx)).$i1).read());
+                    }
+                    $(
+                        for idx in start..end {
+                            $ts.add(idx).write(core::ptr::addr_of!((*src.add(idx)).$is).read());
+                        }
+                    )*
+                    start = end;
+                }
+            }
+
+            unsafe fn into_rows(src: Self::Ptr, dst: *mut Self, len: usize) {
+                let ($t1, $($ts),*) = src;
+                let block = transpose_block_len::<Self>();
+                let mut start = 0;
+                while start < len {
+                    let end = core::cmp::min(start + block, len);
+                    for idx in start..end {
+                        core::ptr::addr_of_mut!((*dst.add(idx)).$i1).write($t1.add(idx).read());
+                    }
+                    $(
+                        for idx in start..end {
+                            core::ptr::addr_of_mut!((*dst.add(idx)).$is).write($ts.add(idx).read());
+                        }
+                    )*
+                    start = end;
+                }
+            }
+
             #[inline(always)]
             unsafe fn as_slices<'a>(ptr: Self::Ptr, len: usize) -> Self::Slices<'a> {
                 let ($t1, $($ts),*) = ptr;
@@ -357,6 +1159,29 @@ macro_rules! impl_parallel_vec_param {
                 )
             }
 
+            #[inline(always)]
+            unsafe fn as_uninit_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::UninitSlicesMut<'a> {
+                let ($t1, $($ts),*) = ptr;
+                (
+                    core::slice::from_raw_parts_mut($t1.cast::<MaybeUninit<$t1>>(), len)
+                    $(
+                        , core::slice::from_raw_parts_mut($ts.cast::<MaybeUninit<$ts>>(), len)
+                    )*
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn as_arrays<'a, const N: usize>(ptr: Self::Ptr) -> Self::Arrays<'a, N> {
+                let ($t1, $($ts),*) = ptr;
+                (&*$t1.cast::<[$t1; N]>() $(, &*$ts.cast::<[$ts; N]>())*)
+            }
+
+            #[inline(always)]
+            unsafe fn as_arrays_mut<'a, const N: usize>(ptr: Self::Ptr) -> Self::ArraysMut<'a, N> {
+                let ($t1, $($ts),*) = ptr;
+                (&mut *$t1.cast::<[$t1; N]>() $(, &mut *$ts.cast::<[$ts; N]>())*)
+            }
+
             #[inline(always)]
             fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a> {
                 let ($t1, $($ts),*) = slices;
@@ -397,6 +1222,12 @@ macro_rules! impl_parallel_vec_param {
                 (&mut *$t1 $(, &mut *$ts)*)
             }
 
+            #[inline(always)]
+            fn row_ref(value: &Self) -> Self::Ref<'_> {
+                let ($t1, $($ts),*) = value;
+                ($t1, $($ts),*)
+            }
+
             #[inline(always)]
             unsafe fn read(ptr: Self::Ptr) -> Self {
                 let ($t1, $($ts),*) = ptr;
@@ -419,6 +1250,14 @@ macro_rules! impl_parallel_vec_param {
                 $(core::ptr::swap($ts, $vs);)*
             }
 
+            #[inline(always)]
+            unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, count: usize) {
+                let ($v1, $($vs),*) = a;
+                let ($t1, $($ts),*) = b;
+                core::ptr::swap_nonoverlapping($t1, $v1, count);
+                $(core::ptr::swap_nonoverlapping($ts, $vs, count);)*
+            }
+
             #[inline(always)]
             unsafe fn drop(ptr: Self::Ptr) {
                 let ($t1, $($ts),*) = ptr;
@@ -437,6 +1276,36 @@ macro_rules! impl_parallel_vec_param {
                 Some(len)
             }
 
+            fn get_slices_len(slices: &Self::Slices<'_>) -> Option<usize> {
+                let ($t1, $($ts),*) = slices;
+                let len = $t1.len();
+                $(
+                    if $ts.len() != len {
+                        return None;
+                    }
+                )*
+                Some(len)
+            }
+
+            #[inline(always)]
+            fn get_slices_ptrs(slices: Self::Slices<'_>) -> Self::Ptr {
+                let ($t1, $($ts),*) = slices;
+                ($t1.as_ptr().cast_mut() $(, $ts.as_ptr().cast_mut())*)
+            }
+
+            unsafe fn into_vecs(src: Self::Ptr, len: usize) -> Self::Vecs {
+                let ($t1, $($ts),*) = src;
+                let mut $v1 = Vec::with_capacity(len);
+                $t1.copy_to_nonoverlapping($v1.as_mut_ptr(), len);
+                $v1.set_len(len);
+                $(
+                    let mut $vs = Vec::with_capacity(len);
+                    $ts.copy_to_nonoverlapping($vs.as_mut_ptr(), len);
+                    $vs.set_len(len);
+                )*
+                ($v1 $(, $vs)*)
+            }
+
             unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr {
                 let ($t1, $($ts),*) = vecs;
                 ($t1.as_mut_ptr() $(, $ts.as_mut_ptr())*)
@@ -448,13 +1317,17 @@ macro_rules! impl_parallel_vec_param {
             fn try_from(mut vecs: (Vec<$t1> $(, Vec<$ts>)*)) -> Result<Self, Self::Error> {
                 let len = <($t1 $(, $ts)*) as ParallelParam>::get_vec_len(&vecs);
                 if let Some(len) = len {
-                    let parallel_vec = Self::with_capacity(len);
-                    // SAFE: This is a move. Nothing should be dropped here.
+                    let mut parallel_vec = Self::with_capacity(len);
+                    // SAFE: This is a move. The source Vecs' lengths are zeroed so
+                    // that they only free their allocations when dropped.
                     unsafe {
                         let src = <($t1 $(, $ts)*) as ParallelParam>::get_vec_ptrs(&mut vecs);
                         let dst = <($t1 $(, $ts)*) as ParallelParam>::as_ptr(parallel_vec.storage);
                         <($t1 $(, $ts)*) as ParallelParam>::copy_to_nonoverlapping(src, dst, len);
-                        core::mem::forget(vecs);
+                        let ($v1 $(, $vs)*) = &mut vecs;
+                        $v1.set_len(0);
+                        $($vs.set_len(0);)*
+                        parallel_vec.len = len;
                     }
                     Ok(parallel_vec)
                 } else {
@@ -462,24 +1335,103 @@ macro_rules! impl_parallel_vec_param {
                 }
             }
         }
+
+        impl<$t1: 'static $(, $ts: 'static)*, const N: usize> TryFrom<ParallelVec<($t1 $(, $ts)*)>> for ([$t1; N] $(, [$ts; N])*) {
+            type Error = ParallelVec<($t1 $(, $ts)*)>;
+            fn try_from(mut vec: ParallelVec<($t1 $(, $ts)*)>) -> Result<Self, Self::Error> {
+                if vec.len != N {
+                    return Err(vec);
+                }
+                // SAFE: This is a move. The length is zeroed before the rows are
+                // moved out so that only the allocation is freed on drop.
+                unsafe {
+                    vec.len = 0;
+                    let ($t1 $(, $ts)*) = <($t1 $(, $ts)*) as ParallelParam>::as_ptr(vec.storage);
+                    Ok(($t1.cast::<[$t1; N]>().read() $(, $ts.cast::<[$ts; N]>().read())*))
+                }
+            }
+        }
+
+        impl<'b, $t1: PartialEq + 'static $(, $ts: PartialEq + 'static)*> PartialEq<(&'b [$t1] $(, &'b [$ts])*)> for ParallelVec<($t1 $(, $ts)*)> {
+            fn eq(&self, other: &(&'b [$t1] $(, &'b [$ts])*)) -> bool {
+                self.as_slices() == *other
+            }
+        }
+
+        impl<'a, 'b, $t1: PartialEq + 'static $(, $ts: PartialEq + 'static)*> PartialEq<(&'b [$t1] $(, &'b [$ts])*)> for ParallelSlice<'a, ($t1 $(, $ts)*)> {
+            fn eq(&self, other: &(&'b [$t1] $(, &'b [$ts])*)) -> bool {
+                self.as_slices() == *other
+            }
+        }
+
+        impl<'a, 'b, $t1: PartialEq + 'static $(, $ts: PartialEq + 'static)*> PartialEq<(&'b [$t1] $(, &'b [$ts])*)> for ParallelSliceMut<'a, ($t1 $(, $ts)*)> {
+            fn eq(&self, other: &(&'b [$t1] $(, &'b [$ts])*)) -> bool {
+                self.as_slices() == *other
+            }
+        }
+
+        unsafe impl<$t1: 'static $(, $ts: 'static)*> ParallelUninit for (MaybeUninit<$t1> $(, MaybeUninit<$ts>)*) {
+            type Init = ($t1 $(, $ts)*);
+
+            #[inline(always)]
+            fn assume_init_storage(storage: Self::Storage) -> <Self::Init as ParallelParam>::Storage {
+                let ($t1, $($ts),*) = storage;
+                ($t1.cast::<$t1>() $(, $ts.cast::<$ts>())*)
+            }
+        }
+
+        impl<$t1: 'static $(, $ts: 'static)*> ParallelInit for ($t1 $(, $ts)*) {
+            type Uninit = (MaybeUninit<$t1> $(, MaybeUninit<$ts>)*);
+        }
+
+        impl<$t1: PartialOrd + Clone + 'static $(, $ts: PartialOrd + Clone + 'static)*> ParallelStats for ($t1 $(, $ts)*) {
+            type Stats = (ColumnStats<$t1> $(, ColumnStats<$ts>)*);
+
+            fn observe(stats: &mut Self::Stats, row: Self::Ref<'_>) {
+                let ($t1, $($ts),*) = row;
+                let ($v1, $($vs),*) = stats;
+                $v1.observe($t1);
+                $($vs.observe($ts);)*
+            }
+
+            fn forget(stats: &mut Self::Stats, row: Self::Ref<'_>) {
+                let ($t1, $($ts),*) = row;
+                let ($v1, $($vs),*) = stats;
+                $v1.forget($t1);
+                $($vs.forget($ts);)*
+            }
+        }
+
+        impl_parallel_columns!(($t1 $(, $ts)*); $t1 $i1 $(, $ts $is)*);
+        impl_parallel_pod!($t1 $(, $ts)*);
+        impl_parallel_zeroable!($t1 $(, $ts)*);
     }
 }
 
-impl_parallel_vec_param!(T1, V1, T2, V2);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9);
+impl_parallel_vec_param!(T1, V1, 0, T2, V2, 1);
+impl_parallel_vec_param!(T1, V1, 0, T2, V2, 1, T3, V3, 2);
+impl_parallel_vec_param!(T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3);
+impl_parallel_vec_param!(T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4);
+impl_parallel_vec_param!(T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5);
+impl_parallel_vec_param!(
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6
+);
+impl_parallel_vec_param!(
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6, T8, V8, 7
+);
+impl_parallel_vec_param!(
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6, T8, V8, 7, T9, V9,
+    8
+);
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6, T8, V8, 7, T9, V9,
+    8, T10, V10, 9
 );
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6, T8, V8, 7, T9, V9,
+    8, T10, V10, 9, T11, V11, 10
 );
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
-    T12, V12
+    T1, V1, 0, T2, V2, 1, T3, V3, 2, T4, V4, 3, T5, V5, 4, T6, V6, 5, T7, V7, 6, T8, V8, 7, T9, V9,
+    8, T10, V10, 9, T11, V11, 10, T12, V12, 11
 );
@@ -0,0 +1,248 @@
+use crate::{ParallelParam, ParallelVec};
+use alloc::string::String;
+use alloc::vec::Vec;
+use polars::prelude::{
+    Column, DataFrame, IntoColumn, NamedFrom, PlSmallStr, PolarsError, PolarsResult, Series,
+};
+
+/// A column type that can be converted to and from a polars [`Series`].
+///
+/// This is implemented for [`bool`], [`i32`], [`i64`], [`u32`], [`u64`],
+/// [`f32`], [`f64`], and [`String`].
+pub trait PolarsColumn: Sized {
+    /// Moves the values into a new [`Series`] with the given name.
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series;
+
+    /// Copies the values out of a [`Series`].
+    ///
+    /// # Errors
+    /// Returns an error if the data type of the series does not match, or if
+    /// it contains any nulls.
+    fn from_series(series: &Series) -> PolarsResult<Vec<Self>>;
+}
+
+fn no_nulls(series: &Series) -> PolarsResult<()> {
+    if series.null_count() == 0 {
+        Ok(())
+    } else {
+        Err(PolarsError::ComputeError(
+            format!("column \"{}\" contains nulls", series.name()).into(),
+        ))
+    }
+}
+
+macro_rules! impl_polars_column {
+    ($($t:ty => $ca:ident),*) => {
+        $(
+            impl PolarsColumn for $t {
+                fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+                    Series::new(name, values)
+                }
+
+                fn from_series(series: &Series) -> PolarsResult<Vec<Self>> {
+                    let values = series.$ca()?;
+                    no_nulls(series)?;
+                    Ok(values.iter().flatten().collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_polars_column!(
+    bool => bool,
+    i32 => i32,
+    i64 => i64,
+    u32 => u32,
+    u64 => u64,
+    f32 => f32,
+    f64 => f64
+);
+
+impl PolarsColumn for String {
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn from_series(series: &Series) -> PolarsResult<Vec<Self>> {
+        let values = series.str()?;
+        no_nulls(series)?;
+        Ok(values.iter().flatten().map(String::from).collect())
+    }
+}
+
+/// A [`ParallelParam`] whose columns can all be converted to and from the
+/// columns of a polars [`DataFrame`].
+///
+/// This trait has implementations for all of the tuples that implement
+/// [`ParallelParam`] where every column is a [`PolarsColumn`].
+pub trait ParallelFrame: ParallelParam {
+    /// The number of columns.
+    const WIDTH: usize;
+
+    /// Moves each column into a [`Column`] with the corresponding name.
+    ///
+    /// `names` must contain exactly [`WIDTH`](Self::WIDTH) names.
+    fn into_columns(vecs: Self::Vecs, names: Vec<PlSmallStr>) -> Vec<Column>;
+
+    /// Copies each of the columns into a new [`ParallelVec`], in order.
+    ///
+    /// `columns` must contain exactly [`WIDTH`](Self::WIDTH) columns of the
+    /// same length.
+    fn from_columns(columns: &[Column]) -> PolarsResult<ParallelVec<Self>>;
+}
+
+macro_rules! one {
+    ($t: ident) => {
+        1
+    };
+}
+
+macro_rules! impl_parallel_frame {
+    ($($ts: ident, $is: tt),*) => {
+        #[allow(non_snake_case)]
+        impl<$($ts: PolarsColumn + 'static),*> ParallelFrame for ($($ts,)*) {
+            const WIDTH: usize = 0 $(+ one!($ts))*;
+
+            fn into_columns(vecs: Self::Vecs, names: Vec<PlSmallStr>) -> Vec<Column> {
+                let mut names = names.into_iter();
+                let ($($ts,)*) = vecs;
+                vec![$($ts::into_series(names.next().unwrap(), $ts).into_column()),*]
+            }
+
+            fn from_columns(columns: &[Column]) -> PolarsResult<ParallelVec<Self>> {
+                let vecs = ($($ts::from_series(columns[$is].as_materialized_series())?,)*);
+                ParallelVec::try_from(vecs).map_err(|_| {
+                    PolarsError::ShapeMismatch("columns must all have the same length".into())
+                })
+            }
+        }
+    };
+}
+
+impl_parallel_frame!(T1, 0, T2, 1);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6, T8, 7);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6, T8, 7, T9, 8);
+impl_parallel_frame!(T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6, T8, 7, T9, 8, T10, 9);
+impl_parallel_frame!(
+    T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6, T8, 7, T9, 8, T10, 9, T11, 10
+);
+impl_parallel_frame!(
+    T1, 0, T2, 1, T3, 2, T4, 3, T5, 4, T6, 5, T7, 6, T8, 7, T9, 8, T10, 9, T11, 10, T12, 11
+);
+
+impl<Param: ParallelFrame> ParallelVec<Param> {
+    /// Converts the vector into a polars [`DataFrame`], moving each column into
+    /// a [`Series`] with the corresponding name.
+    ///
+    /// # Errors
+    /// Returns an error if the number of names does not match the number of
+    /// columns, or if any of the names are duplicated.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![(1u32, 2.0f64), (3, 4.0)]);
+    /// let df = src.into_data_frame(["id", "score"]).unwrap();
+    /// assert_eq!(df.shape(), (2, 2));
+    /// let round_trip = ParallelVec::<(u32, f64)>::from_data_frame(&df).unwrap();
+    /// assert_eq!(Vec::from(round_trip), vec![(1, 2.0), (3, 4.0)]);
+    /// ```
+    pub fn into_data_frame<I>(self, names: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator,
+        I::Item: Into<PlSmallStr>,
+    {
+        let names: Vec<PlSmallStr> = names.into_iter().map(Into::into).collect();
+        if names.len() != Param::WIDTH {
+            return Err(PolarsError::ShapeMismatch(
+                format!(
+                    "expected {} column names, got {}",
+                    Param::WIDTH,
+                    names.len()
+                )
+                .into(),
+            ));
+        }
+        let height = self.len();
+        DataFrame::new(height, Param::into_columns(self.into_vecs(), names))
+    }
+
+    /// Copies the columns of a polars [`DataFrame`] into a new vector, matching
+    /// the columns by position.
+    ///
+    /// To convert a subset of the columns or reorder them, [`DataFrame::select`]
+    /// them first.
+    ///
+    /// # Errors
+    /// Returns an error if the number of columns does not match, if the data
+    /// type of any column does not match, or if any column contains nulls.
+    pub fn from_data_frame(df: &DataFrame) -> PolarsResult<Self> {
+        if df.width() != Param::WIDTH {
+            return Err(PolarsError::ShapeMismatch(
+                format!("expected {} columns, got {}", Param::WIDTH, df.width()).into(),
+            ));
+        }
+        Param::from_columns(df.columns())
+    }
+}
+
+impl<Param: ParallelFrame> TryFrom<&DataFrame> for ParallelVec<Param> {
+    type Error = PolarsError;
+
+    fn try_from(df: &DataFrame) -> PolarsResult<Self> {
+        Self::from_data_frame(df)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use polars::prelude::{DataFrame, IntoColumn, NamedFrom, Series};
+    use std::string::String;
+
+    #[test]
+    fn test_data_frame_round_trip() {
+        let rows = vec![
+            (1i64, String::from("a"), true, 0.5f32),
+            (2, String::from("b"), false, 1.5),
+        ];
+        let src = ParallelVec::from(rows.clone());
+        let df = ParallelVec::from(rows)
+            .into_data_frame(["a", "b", "c", "d"])
+            .unwrap();
+        assert_eq!(df.shape(), (2, 4));
+        assert_eq!(df.column("b").unwrap().str().unwrap().get(1), Some("b"));
+        let round_trip: ParallelVec<(i64, String, bool, f32)> = (&df).try_into().unwrap();
+        assert_eq!(round_trip, src);
+
+        let empty = ParallelVec::<(u32, u64)>::new()
+            .into_data_frame(["a", "b"])
+            .unwrap();
+        assert_eq!(empty.shape(), (0, 2));
+        assert!(ParallelVec::<(u32, u64)>::from_data_frame(&empty)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_data_frame_errors() {
+        let src = ParallelVec::from(vec![(1u32, 2u64)]);
+        assert!(src.clone().into_data_frame(["a"]).is_err());
+        assert!(src.clone().into_data_frame(["a", "a"]).is_err());
+
+        let df = src.into_data_frame(["a", "b"]).unwrap();
+        assert!(ParallelVec::<(u32, u64, u32)>::from_data_frame(&df).is_err());
+        assert!(ParallelVec::<(u32, f64)>::from_data_frame(&df).is_err());
+
+        let nulls = Series::new("a".into(), [Some(1u32), None]).into_column();
+        let values = Series::new("b".into(), [1u64, 2]).into_column();
+        let df = DataFrame::new(2, vec![nulls, values]).unwrap();
+        assert!(ParallelVec::<(u32, u64)>::from_data_frame(&df).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{ParallelParam, ParallelVec};
+use alloc::vec::Vec;
+
+/// A pool of empty [`ParallelVec`]s that retain their allocations for reuse.
+///
+/// Vectors are checked out with [`take`] or [`take_with_capacity`] and returned
+/// with [`recycle`], which clears them while keeping their capacity. This avoids
+/// repeatedly allocating and freeing short-lived tables.
+///
+/// A pool may optionally be bounded by the total number of bytes it retains.
+/// The size of a retained vector is estimated as `capacity * size_of::<Param>()`,
+/// which is never smaller than the memory actually allocated for its columns.
+///
+/// [`take`]: Self::take
+/// [`take_with_capacity`]: Self::take_with_capacity
+/// [`recycle`]: Self::recycle
+pub struct ParallelVecPool<Param: ParallelParam> {
+    free: Vec<ParallelVec<Param>>,
+    retained_bytes: usize,
+    max_bytes: Option<usize>,
+}
+
+impl<Param: ParallelParam> ParallelVecPool<Param> {
+    /// Constructs a new, empty and unbounded pool.
+    pub fn new() -> Self {
+        Self {
+            free: Vec::new(),
+            retained_bytes: 0,
+            max_bytes: None,
+        }
+    }
+
+    /// Constructs a new, empty pool that will retain at most `max_bytes` worth
+    /// of allocations.
+    ///
+    /// Vectors recycled while the pool is full are dropped instead.
+    pub fn with_max_bytes(max_bytes: usize) -> Self {
+        Self {
+            max_bytes: Some(max_bytes),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the number of vectors currently held by the pool.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns `true` if the pool holds no vectors.
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+
+    /// Returns the estimated number of bytes retained by the pool.
+    pub fn retained_bytes(&self) -> usize {
+        self.retained_bytes
+    }
+
+    /// Checks out an empty vector from the pool, or a new unallocated one
+    /// if the pool is empty.
+    pub fn take(&mut self) -> ParallelVec<Param> {
+        match self.free.pop() {
+            Some(vec) => {
+                self.retained_bytes -= Self::bytes_of(&vec);
+                vec
+            }
+            None => ParallelVec::new(),
+        }
+    }
+
+    /// Checks out an empty vector that can hold at least `capacity` elements
+    /// without reallocating.
+    ///
+    /// Prefers the smallest pooled vector that is large enough. If none are,
+    /// the largest pooled vector is grown instead.
+    pub fn take_with_capacity(&mut self, capacity: usize) -> ParallelVec<Param> {
+        let best = self
+            .free
+            .iter()
+            .enumerate()
+            .filter(|(_, vec)| vec.capacity() >= capacity)
+            .min_by_key(|(_, vec)| vec.capacity())
+            .or_else(|| {
+                self.free
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, vec)| vec.capacity())
+            })
+            .map(|(idx, _)| idx);
+        let mut vec = match best {
+            Some(idx) => {
+                let vec = self.free.swap_remove(idx);
+                self.retained_bytes -= Self::bytes_of(&vec);
+                vec
+            }
+            None => ParallelVec::new(),
+        };
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// Returns a vector to the pool, dropping all of its elements but
+    /// retaining its allocation.
+    ///
+    /// If the pool is bounded and retaining the vector would exceed the bound,
+    /// or the vector has no allocation, it is dropped instead.
+    pub fn recycle(&mut self, mut vec: ParallelVec<Param>) {
+        let bytes = Self::bytes_of(&vec);
+        if vec.capacity() == 0 {
+            return;
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            if self.retained_bytes.saturating_add(bytes) > max_bytes {
+                return;
+            }
+        }
+        vec.clear();
+        self.retained_bytes += bytes;
+        self.free.push(vec);
+    }
+
+    /// Drops all of the vectors held by the pool, freeing their allocations.
+    pub fn clear(&mut self) {
+        self.free.clear();
+        self.retained_bytes = 0;
+    }
+
+    #[inline]
+    fn bytes_of(vec: &ParallelVec<Param>) -> usize {
+        vec.capacity().saturating_mul(core::mem::size_of::<Param>())
+    }
+}
+
+impl<Param: ParallelParam> Default for ParallelVecPool<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelVecPool;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_take_and_recycle() {
+        let mut pool: ParallelVecPool<(u32, u64)> = ParallelVecPool::new();
+        let mut vec = pool.take();
+        assert_eq!(vec.capacity(), 0);
+        vec.extend(vec![(1, 2), (3, 4), (5, 6)]);
+        let capacity = vec.capacity();
+        pool.recycle(vec);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.retained_bytes(), capacity * 16);
+        let vec = pool.take();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), capacity);
+        assert!(pool.is_empty());
+        assert_eq!(pool.retained_bytes(), 0);
+    }
+
+    #[test]
+    fn test_take_with_capacity() {
+        let mut pool: ParallelVecPool<(u32, u64)> = ParallelVecPool::new();
+        pool.recycle(crate::ParallelVec::with_capacity(10));
+        pool.recycle(crate::ParallelVec::with_capacity(100));
+        pool.recycle(crate::ParallelVec::with_capacity(1000));
+        let vec = pool.take_with_capacity(50);
+        assert_eq!(vec.capacity(), 100);
+        let vec = pool.take_with_capacity(5000);
+        assert!(vec.capacity() >= 5000);
+        assert_eq!(pool.len(), 1);
+        pool.clear();
+        assert!(pool.is_empty());
+        assert_eq!(pool.retained_bytes(), 0);
+    }
+
+    #[test]
+    fn test_max_bytes() {
+        let mut pool: ParallelVecPool<(u32, u32)> = ParallelVecPool::with_max_bytes(800);
+        pool.recycle(crate::ParallelVec::with_capacity(64));
+        pool.recycle(crate::ParallelVec::with_capacity(64));
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.retained_bytes(), 512);
+        pool.recycle(crate::ParallelVec::with_capacity(0));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn test_recycle_drops_elements() {
+        let rc = Rc::new(0);
+        let mut pool = ParallelVecPool::new();
+        let mut vec = pool.take();
+        vec.push((rc.clone(), rc.clone()));
+        assert_eq!(Rc::strong_count(&rc), 3);
+        pool.recycle(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{ParallelParam, ParallelSlice, ParallelVec};
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// A bounded, single-producer single-consumer channel of rows, backed by a
+/// ring buffer with the same column-per-field layout as [`ParallelVec`].
+///
+/// Rows are moved in and out column by column, so batched operations like
+/// [`RingSender::push_slice`] and [`RingReceiver::pop_chunk`] never interleave
+/// the rows into tuples.
+///
+/// None of the operations block. Use [`ParallelRingChannel::new`] to create a
+/// connected sender and receiver pair.
+pub struct ParallelRingChannel<Param: ParallelParam> {
+    storage: Param::Storage,
+    capacity: usize,
+    /// The total number of rows ever popped. Only written by the receiver.
+    head: AtomicUsize,
+    /// The total number of rows ever pushed. Only written by the sender.
+    tail: AtomicUsize,
+    disconnected: AtomicBool,
+}
+
+// SAFE: The sender and receiver never access the same rows at the same time.
+// Rows are only ever moved between threads, so only `Send` is required.
+unsafe impl<Param: ParallelParam + Send> Send for ParallelRingChannel<Param> {}
+unsafe impl<Param: ParallelParam + Send> Sync for ParallelRingChannel<Param> {}
+
+impl<Param: ParallelParam> ParallelRingChannel<Param> {
+    /// Creates a new channel that can hold up to `capacity` rows, returning the
+    /// sending and receiving halves.
+    ///
+    /// # Panics
+    /// This function will panic if `capacity` is 0.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(capacity: usize) -> (RingSender<Param>, RingReceiver<Param>) {
+        assert!(capacity != 0, "capacity must be non-zero");
+        let channel = Arc::new(Self {
+            storage: unsafe { Param::alloc(capacity) },
+            capacity,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            disconnected: AtomicBool::new(false),
+        });
+        (
+            RingSender {
+                channel: channel.clone(),
+            },
+            RingReceiver { channel },
+        )
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+
+    /// Calls `f` with each of the contiguous runs of ring indices covering `len`
+    /// rows starting from the logical position `start`.
+    #[inline]
+    fn segments(&self, start: usize, len: usize, mut f: impl FnMut(usize, usize, usize)) {
+        let offset = start % self.capacity;
+        let first = len.min(self.capacity - offset);
+        f(offset, 0, first);
+        if first < len {
+            f(0, first, len - first);
+        }
+    }
+}
+
+impl<Param: ParallelParam> Drop for ParallelRingChannel<Param> {
+    fn drop(&mut self) {
+        let head = *self.head.get_mut();
+        let len = self.tail.get_mut().wrapping_sub(head);
+        unsafe {
+            self.segments(head, len, |ring, _, len| {
+                let base = Param::ptr_at(self.storage, ring);
+                for idx in 0..len {
+                    Param::drop(Param::add(base, idx));
+                }
+            });
+            Param::dealloc(self.storage, self.capacity);
+        }
+    }
+}
+
+/// The sending half of a [`ParallelRingChannel`].
+pub struct RingSender<Param: ParallelParam> {
+    channel: Arc<ParallelRingChannel<Param>>,
+}
+
+impl<Param: ParallelParam> RingSender<Param> {
+    /// Returns the maximum number of rows the channel can hold.
+    pub fn capacity(&self) -> usize {
+        self.channel.capacity
+    }
+
+    /// Returns the number of rows currently in the channel.
+    pub fn len(&self) -> usize {
+        self.channel.len()
+    }
+
+    /// Returns `true` if the channel has no rows in it.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the [`RingReceiver`] has been dropped.
+    pub fn is_disconnected(&self) -> bool {
+        self.channel.disconnected.load(Ordering::Acquire)
+    }
+
+    /// Pushes a row into the channel, or returns it back if the channel is full.
+    pub fn try_push(&mut self, value: Param) -> Result<(), Param> {
+        let channel = &*self.channel;
+        let tail = channel.tail.load(Ordering::Relaxed);
+        let head = channel.head.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) == channel.capacity {
+            return Err(value);
+        }
+        unsafe {
+            Param::write(
+                Param::ptr_at(channel.storage, tail % channel.capacity),
+                value,
+            );
+        }
+        channel.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Copies as many rows from the front of `rows` into the channel as will fit.
+    ///
+    /// Returns the number of rows pushed.
+    pub fn push_slice(&mut self, rows: ParallelSlice<'_, Param>) -> usize
+    where
+        Param: Copy,
+    {
+        let channel = &*self.channel;
+        let tail = channel.tail.load(Ordering::Relaxed);
+        let head = channel.head.load(Ordering::Acquire);
+        let free = channel.capacity - tail.wrapping_sub(head);
+        let count = rows.len().min(free);
+        let src = rows.as_ptr();
+        channel.segments(tail, count, |ring, offset, len| unsafe {
+            Param::copy_to_nonoverlapping(
+                Param::add(src, offset),
+                Param::ptr_at(channel.storage, ring),
+                len,
+            );
+        });
+        channel
+            .tail
+            .store(tail.wrapping_add(count), Ordering::Release);
+        count
+    }
+}
+
+impl<Param: ParallelParam> Drop for RingSender<Param> {
+    fn drop(&mut self) {
+        self.channel.disconnected.store(true, Ordering::Release);
+    }
+}
+
+/// The receiving half of a [`ParallelRingChannel`].
+pub struct RingReceiver<Param: ParallelParam> {
+    channel: Arc<ParallelRingChannel<Param>>,
+}
+
+impl<Param: ParallelParam> RingReceiver<Param> {
+    /// Returns the maximum number of rows the channel can hold.
+    pub fn capacity(&self) -> usize {
+        self.channel.capacity
+    }
+
+    /// Returns the number of rows currently in the channel.
+    pub fn len(&self) -> usize {
+        self.channel.len()
+    }
+
+    /// Returns `true` if the channel has no rows in it.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the [`RingSender`] has been dropped.
+    ///
+    /// Rows pushed before the sender was dropped can still be received.
+    pub fn is_disconnected(&self) -> bool {
+        self.channel.disconnected.load(Ordering::Acquire)
+    }
+
+    /// Pops the oldest row from the channel, or `None` if it is empty.
+    pub fn try_pop(&mut self) -> Option<Param> {
+        let channel = &*self.channel;
+        let head = channel.head.load(Ordering::Relaxed);
+        let tail = channel.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let value = unsafe { Param::read(Param::ptr_at(channel.storage, head % channel.capacity)) };
+        channel.head.store(head.wrapping_add(1), Ordering::Release);
+        Some(value)
+    }
+
+    /// Moves up to `max` of the oldest rows from the channel onto the end of `dst`.
+    ///
+    /// Returns the number of rows moved.
+    pub fn pop_chunk(&mut self, dst: &mut ParallelVec<Param>, max: usize) -> usize {
+        let channel = &*self.channel;
+        let head = channel.head.load(Ordering::Relaxed);
+        let tail = channel.tail.load(Ordering::Acquire);
+        let count = tail.wrapping_sub(head).min(max);
+        dst.reserve(count);
+        let base = dst.len;
+        channel.segments(head, count, |ring, offset, len| unsafe {
+            Param::copy_to_nonoverlapping(
+                Param::ptr_at(channel.storage, ring),
+                Param::ptr_at(dst.storage, base + offset),
+                len,
+            );
+        });
+        dst.len += count;
+        channel
+            .head
+            .store(head.wrapping_add(count), Ordering::Release);
+        count
+    }
+}
+
+impl<Param: ParallelParam> Drop for RingReceiver<Param> {
+    fn drop(&mut self) {
+        self.channel.disconnected.store(true, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelRingChannel;
+    use crate::ParallelVec;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_push_pop() {
+        let (mut tx, mut rx) = ParallelRingChannel::new(2);
+        assert_eq!(tx.capacity(), 2);
+        assert_eq!(tx.try_push((1, 'a')), Ok(()));
+        assert_eq!(tx.try_push((2, 'b')), Ok(()));
+        assert_eq!(tx.try_push((3, 'c')), Err((3, 'c')));
+        assert_eq!(rx.len(), 2);
+        assert_eq!(rx.try_pop(), Some((1, 'a')));
+        assert_eq!(tx.try_push((3, 'c')), Ok(()));
+        assert_eq!(rx.try_pop(), Some((2, 'b')));
+        assert_eq!(rx.try_pop(), Some((3, 'c')));
+        assert_eq!(rx.try_pop(), None);
+        assert!(!rx.is_disconnected());
+        drop(tx);
+        assert!(rx.is_disconnected());
+    }
+
+    #[test]
+    fn test_batches_wrap_around() {
+        let (mut tx, mut rx) = ParallelRingChannel::new(5);
+        let src = ParallelVec::from(vec![(0, 0.0), (1, 1.0), (2, 2.0), (3, 3.0)]);
+        assert_eq!(tx.push_slice(src.index(..)), 4);
+        let mut dst = ParallelVec::new();
+        assert_eq!(rx.pop_chunk(&mut dst, 3), 3);
+        // Wraps around the end of the ring.
+        assert_eq!(tx.push_slice(src.index(..)), 4);
+        assert_eq!(tx.push_slice(src.index(..)), 0);
+        assert_eq!(rx.pop_chunk(&mut dst, 100), 5);
+        assert_eq!(
+            dst,
+            vec![
+                (0, 0.0),
+                (1, 1.0),
+                (2, 2.0),
+                (3, 3.0),
+                (0, 0.0),
+                (1, 1.0),
+                (2, 2.0),
+                (3, 3.0)
+            ]
+        );
+        assert!(rx.is_empty());
+    }
+
+    #[test]
+    fn test_drops_remaining_rows() {
+        let rc = Rc::new(0);
+        let (mut tx, mut rx) = ParallelRingChannel::new(3);
+        for _ in 0..3 {
+            assert!(tx.try_push((rc.clone(), 0)).is_ok());
+        }
+        drop(rx.try_pop());
+        assert!(tx.try_push((rc.clone(), 0)).is_ok());
+        assert_eq!(Rc::strong_count(&rc), 4);
+        drop(tx);
+        drop(rx);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_threads() {
+        let (mut tx, mut rx) = ParallelRingChannel::<(u64, u32)>::new(16);
+        let producer = std::thread::spawn(move || {
+            let mut next = 0;
+            while next < 10000 {
+                if tx.try_push((next, next as u32)).is_ok() {
+                    next += 1;
+                } else {
+                    std::thread::yield_now();
+                }
+            }
+        });
+        let mut received = ParallelVec::new();
+        while received.len() < 10000 {
+            if rx.pop_chunk(&mut received, 7) == 0 {
+                std::thread::yield_now();
+            }
+        }
+        producer.join().unwrap();
+        let (a, b) = received.as_slices();
+        assert!(a
+            .iter()
+            .enumerate()
+            .all(|(idx, value)| *value == idx as u64));
+        assert!(b
+            .iter()
+            .enumerate()
+            .all(|(idx, value)| *value == idx as u32));
+    }
+}
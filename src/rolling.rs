@@ -0,0 +1,193 @@
+use crate::{ParallelColumn, ParallelParam};
+use alloc::vec::Vec;
+use core::{marker::PhantomData, ops::Add};
+
+/// Rolling window aggregations over the columns of a [`ParallelSlice`].
+///
+/// Each aggregation produces one value per full window of `window` consecutive
+/// rows, so the output has `len - window + 1` values, or none if there are fewer
+/// than `window` rows.
+///
+/// See [`ParallelSlice::rolling`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSlice::rolling`]: crate::ParallelSlice::rolling
+pub struct Rolling<'a, Param: ParallelParam> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) len: usize,
+    pub(crate) window: usize,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam> Rolling<'a, Param> {
+    /// Computes the sum of every window over the `N`th column.
+    ///
+    /// This is a `O(N)` operation regardless of the window size. Values are
+    /// never subtracted back out of a running sum, so with floating point
+    /// columns, a large value only affects the windows that contain it.
+    pub fn sum_column<const N: usize>(&self) -> Vec<<Param as ParallelColumn<N>>::Column>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column:
+            Copy + Add<Output = <Param as ParallelColumn<N>>::Column>,
+    {
+        window_sums(self.column::<N>(), self.window, |value| *value)
+    }
+
+    /// Computes the arithmetic mean of every window over the `N`th column.
+    ///
+    /// This is a `O(N)` operation regardless of the window size. See
+    /// [`sum_column`] for how the sums are computed.
+    ///
+    /// [`sum_column`]: Self::sum_column
+    pub fn mean_column<const N: usize>(&self) -> Vec<f64>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy + Into<f64>,
+    {
+        let window = self.window as f64;
+        let mut means = window_sums(self.column::<N>(), self.window, |value| (*value).into());
+        for mean in means.iter_mut() {
+            *mean /= window;
+        }
+        means
+    }
+
+    /// Applies `f` to every window over the `N`th column.
+    ///
+    /// Unlike [`sum_column`] and [`mean_column`], this is a `O(N * window)`
+    /// operation if `f` reads the entire window.
+    ///
+    /// [`sum_column`]: Self::sum_column
+    /// [`mean_column`]: Self::mean_column
+    pub fn aggregate_column<const N: usize, R, F>(&self, f: F) -> Vec<R>
+    where
+        Param: ParallelColumn<N>,
+        F: FnMut(&[<Param as ParallelColumn<N>>::Column]) -> R,
+    {
+        self.column::<N>().windows(self.window).map(f).collect()
+    }
+
+    fn column<const N: usize>(&self) -> &'a [<Param as ParallelColumn<N>>::Column]
+    where
+        Param: ParallelColumn<N>,
+    {
+        unsafe { core::slice::from_raw_parts(Param::column_ptr(self.ptr), self.len) }
+    }
+}
+
+/// Sums every window of `window` consecutive values.
+///
+/// The values are split into blocks of `window` values, so every window is the
+/// suffix of one block followed by the prefix of the next. The suffix sums of a
+/// block are computed right to left, and the prefix sums of the next block are
+/// accumulated left to right alongside them, so every value is only ever added.
+fn window_sums<T, U, F>(values: &[T], window: usize, map: F) -> Vec<U>
+where
+    U: Copy + Add<Output = U>,
+    F: Fn(&T) -> U,
+{
+    if values.len() < window {
+        return Vec::new();
+    }
+    let count = values.len() - window + 1;
+    let mut sums = Vec::with_capacity(count);
+    let mut suffixes = Vec::with_capacity(window);
+    for start in (0..count).step_by(window) {
+        let block = &values[start..start + window];
+        suffixes.clear();
+        let mut suffix = map(&block[window - 1]);
+        suffixes.push(suffix);
+        for value in block[..window - 1].iter().rev() {
+            suffix = map(value) + suffix;
+            suffixes.push(suffix);
+        }
+        // suffixes[window - 1 - offset] is the sum of block[offset..].
+        sums.push(suffixes[window - 1]);
+        let next = &values[start + window..];
+        let mut prefix = None;
+        for offset in 1..window.min(count - start) {
+            let value = map(&next[offset - 1]);
+            let sum = prefix.map_or(value, |prefix| prefix + value);
+            prefix = Some(sum);
+            sums.push(suffixes[window - 1 - offset] + sum);
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_rolling_sum() {
+        let src = ParallelVec::from(vec![(1, 0.5), (2, 1.5), (3, 2.5), (4, 3.5), (5, 4.5)]);
+        assert_eq!(src.rolling(2).sum_column::<0>(), vec![3, 5, 7, 9]);
+        assert_eq!(src.rolling(3).sum_column::<1>(), vec![4.5, 7.5, 10.5]);
+        assert_eq!(src.rolling(5).sum_column::<0>(), vec![15]);
+        assert_eq!(src.rolling(6).sum_column::<0>(), Vec::<i32>::new());
+        assert_eq!(src.rolling(1).sum_column::<0>(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_rolling_sum_windows() {
+        let values: Vec<(u64, u8)> = (0..20).map(|value| (value * value, 0)).collect();
+        let src = ParallelVec::from(values.clone());
+        for window in 1..=20 {
+            let expected: Vec<u64> = values
+                .windows(window)
+                .map(|rows| rows.iter().map(|(value, _)| value).sum())
+                .collect();
+            assert_eq!(src.rolling(window).sum_column::<0>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_rolling_mean() {
+        let src = ParallelVec::from(vec![(1u32, 0.5f32), (2, 1.5), (3, 2.5), (4, 3.5)]);
+        assert_eq!(src.rolling(2).mean_column::<0>(), vec![1.5, 2.5, 3.5]);
+        assert_eq!(src.rolling(4).mean_column::<1>(), vec![2.0]);
+        assert!(src.rolling(5).mean_column::<1>().is_empty());
+    }
+
+    #[test]
+    fn test_rolling_outlier() {
+        let src = ParallelVec::from(vec![
+            (1e20, 'a'),
+            (1.0, 'b'),
+            (1.0, 'c'),
+            (1.0, 'd'),
+            (1.0, 'e'),
+        ]);
+        assert_eq!(src.rolling(2).sum_column::<0>(), vec![1e20, 2.0, 2.0, 2.0]);
+        assert_eq!(src.rolling(2).mean_column::<0>(), vec![5e19, 1.0, 1.0, 1.0]);
+        assert_eq!(src.rolling(3).sum_column::<0>(), vec![1e20, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_rolling_aggregate() {
+        let src = ParallelVec::from(vec![(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (5, 'e')]);
+        let max = src
+            .rolling(3)
+            .aggregate_column::<0, _, _>(|window| *window.iter().max().unwrap());
+        assert_eq!(max, vec![4, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rolling_zero_window_panics() {
+        let src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        src.rolling(0);
+    }
+
+    #[test]
+    fn test_column() {
+        let mut src = ParallelVec::from(vec![(1, 2.0), (3, 4.0)]);
+        assert_eq!(src.column::<0>(), &[1, 3]);
+        assert_eq!(src.column::<1>(), &[2.0, 4.0]);
+        src.column_mut::<1>()[1] = 5.0;
+        assert_eq!(src.index(1), (&3, &5.0));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::iter::Iter;
+use crate::ParallelParam;
+use alloc::vec::Vec;
+use rand::{distributions::WeightedError, Rng};
+
+/// A Walker/Vose alias table for `O(1)` sampling from a discrete weighted
+/// distribution after `O(N)` construction.
+pub(crate) struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    pub(crate) fn new(weights: impl Iterator<Item = f64>) -> Result<Self, WeightedError> {
+        let mut prob: Vec<f64> = Vec::with_capacity(weights.size_hint().0);
+        let mut total = 0.0;
+        for weight in weights {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(WeightedError::InvalidWeight);
+            }
+            total += weight;
+            prob.push(weight);
+        }
+        let len = prob.len();
+        if len == 0 {
+            return Err(WeightedError::NoItem);
+        }
+        if len > u32::MAX as usize {
+            return Err(WeightedError::TooMany);
+        }
+        if total <= 0.0 {
+            return Err(WeightedError::AllWeightsZero);
+        }
+        if !total.is_finite() {
+            return Err(WeightedError::InvalidWeight);
+        }
+
+        let scale = len as f64 / total;
+        let mut alias: Vec<usize> = (0..len).collect();
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for (idx, p) in prob.iter_mut().enumerate() {
+            *p *= scale;
+            if *p < 1.0 {
+                small.push(idx);
+            } else {
+                large.push(idx);
+            }
+        }
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            alias[less] = more;
+            prob[more] -= 1.0 - prob[less];
+            if prob[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Whatever remains is only off from 1.0 due to rounding error.
+        for idx in small.into_iter().chain(large) {
+            prob[idx] = 1.0;
+        }
+        Ok(Self { prob, alias })
+    }
+
+    #[inline]
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let idx = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f64>() < self.prob[idx] {
+            idx
+        } else {
+            self.alias[idx]
+        }
+    }
+}
+
+pub(crate) fn sample_weighted<Param, R, F>(
+    rows: Iter<'_, Param>,
+    rng: &mut R,
+    k: usize,
+    weight: F,
+) -> Result<Vec<usize>, WeightedError>
+where
+    Param: ParallelParam,
+    R: Rng + ?Sized,
+    F: FnMut(Param::Ref<'_>) -> f64,
+{
+    let table = AliasTable::new(rows.map(weight))?;
+    Ok((0..k).map(|_| table.sample(rng)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use rand::{distributions::WeightedError, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_choose_weighted_by_column() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let src = ParallelVec::from(vec![('a', 0u32), ('b', 1), ('c', 3)]);
+        let indices = src
+            .choose_weighted_by_column::<1, _>(&mut rng, 10000)
+            .unwrap();
+        assert_eq!(indices.len(), 10000);
+        let mut counts = [0; 3];
+        for idx in indices {
+            counts[idx] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        assert!((2400..2600).contains(&counts[1]));
+        assert!((7400..7600).contains(&counts[2]));
+    }
+
+    #[test]
+    fn test_sample_weighted_rows() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let src = ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+        let sampled = src
+            .sample_weighted_rows(&mut rng, 100, |(a, _)| (*a % 2) as f64)
+            .unwrap();
+        assert_eq!(sampled.len(), 100);
+        assert!(sampled.iter().all(|(a, _)| *a == 1 || *a == 3));
+        let (a, b) = sampled.as_slices();
+        assert!(a.iter().zip(b).all(|(a, b)| (*a == 1) == (*b == 'a')));
+    }
+
+    #[test]
+    fn test_sample_weighted_errors() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let empty: ParallelVec<(f64, u8)> = ParallelVec::new();
+        assert_eq!(
+            empty.sample_weighted(&mut rng, 1, |(w, _)| *w),
+            Err(WeightedError::NoItem)
+        );
+        let src = ParallelVec::from(vec![(1.0, 0u8), (-1.0, 1)]);
+        assert_eq!(
+            src.sample_weighted(&mut rng, 1, |(w, _)| *w),
+            Err(WeightedError::InvalidWeight)
+        );
+        let src = ParallelVec::from(vec![(1.0, 0u8), (f64::NAN, 1)]);
+        assert_eq!(
+            src.sample_weighted(&mut rng, 1, |(w, _)| *w),
+            Err(WeightedError::InvalidWeight)
+        );
+        let src = ParallelVec::from(vec![(0.0, 0u8), (0.0, 1)]);
+        assert_eq!(
+            src.choose_weighted_by_column::<0, _>(&mut rng, 1),
+            Err(WeightedError::AllWeightsZero)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+
+/// The kind of prefix scan to compute with [`ParallelSlice::scan_column`].
+///
+/// [`ParallelSlice::scan_column`]: crate::ParallelSlice::scan_column
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scan<T> {
+    /// Every output includes its own input: `[a, a+b, a+b+c, ...]`.
+    Inclusive,
+    /// Every output excludes its own input, starting from the provided
+    /// identity value: `[id, a, a+b, ...]`.
+    Exclusive(T),
+}
+
+pub(crate) fn scan<T, F>(column: &[T], scan: Scan<T>, op: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut output = Vec::with_capacity(column.len());
+    scan_into(column, scan, None, &op, &mut output);
+    output
+}
+
+/// Scans `column` into `output`, optionally continuing from the combined
+/// value of every prior input.
+#[inline]
+fn scan_into<T, F>(column: &[T], scan: Scan<T>, carry: Option<T>, op: &F, output: &mut Vec<T>)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    match scan {
+        Scan::Inclusive => {
+            let mut acc = carry;
+            output.extend(column.iter().map(|value| {
+                let next = match acc {
+                    Some(acc) => op(acc, *value),
+                    None => *value,
+                };
+                acc = Some(next);
+                next
+            }));
+        }
+        Scan::Exclusive(identity) => {
+            let mut acc = carry.unwrap_or(identity);
+            output.extend(column.iter().map(|value| {
+                let current = acc;
+                acc = op(acc, *value);
+                current
+            }));
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub(crate) fn par_scan<T, F>(column: &[T], scan: Scan<T>, op: F) -> Vec<T>
+where
+    T: Copy + Send + Sync,
+    F: Fn(T, T) -> T + Sync,
+{
+    use rayon::prelude::*;
+
+    const MIN_CHUNK_LEN: usize = 4096;
+    let chunk_len = (column.len() / (rayon::current_num_threads() * 4)).max(MIN_CHUNK_LEN);
+    if column.len() <= chunk_len {
+        return self::scan(column, scan, op);
+    }
+
+    // Reduce every chunk in parallel, then scan the chunk totals to find
+    // the value carried into every chunk.
+    let totals: Vec<T> = column
+        .par_chunks(chunk_len)
+        .map(|chunk| {
+            chunk[1..]
+                .iter()
+                .fold(chunk[0], |acc, value| op(acc, *value))
+        })
+        .collect();
+    let mut carries = Vec::with_capacity(totals.len());
+    let mut acc: Option<T> = None;
+    for total in totals {
+        carries.push(acc);
+        acc = Some(match acc {
+            Some(acc) => op(acc, total),
+            None => total,
+        });
+    }
+
+    let mut chunks: Vec<Vec<T>> = column
+        .par_chunks(chunk_len)
+        .zip(carries)
+        .map(|(chunk, carry)| {
+            let mut output = Vec::with_capacity(chunk.len());
+            scan_into(chunk, scan, carry, &op, &mut output);
+            output
+        })
+        .collect();
+    let mut output = Vec::with_capacity(column.len());
+    for chunk in chunks.iter_mut() {
+        output.append(chunk);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scan;
+    use crate::ParallelVec;
+
+    #[test]
+    fn test_scan_column() {
+        let src = ParallelVec::from(vec![('a', 3u32), ('b', 1), ('c', 4), ('d', 1)]);
+        assert_eq!(
+            src.scan_column::<1, _>(Scan::Inclusive, |a, b| a + b),
+            vec![3, 4, 8, 9]
+        );
+        assert_eq!(
+            src.scan_column::<1, _>(Scan::Exclusive(0), |a, b| a + b),
+            vec![0, 3, 4, 8]
+        );
+        assert_eq!(
+            src.scan_column::<1, _>(Scan::Inclusive, |a, b| a.max(b)),
+            vec![3, 3, 4, 4]
+        );
+        let empty: ParallelVec<(u8, u32)> = ParallelVec::new();
+        assert!(empty
+            .scan_column::<1, _>(Scan::Exclusive(0), |a, b| a + b)
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_scan_column() {
+        let src: ParallelVec<_> = (0..100_000u64).map(|x| (x % 7, x as u8)).collect();
+        for scan in [Scan::Inclusive, Scan::Exclusive(0)] {
+            assert_eq!(
+                src.par_scan_column::<0, _>(scan, |a, b| a + b),
+                src.scan_column::<0, _>(scan, |a, b| a + b)
+            );
+        }
+    }
+}
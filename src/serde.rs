@@ -1,7 +1,9 @@
 use crate::{ParallelParam, ParallelVec};
-use alloc::vec::Vec;
+use core::{fmt::Formatter, marker::PhantomData};
 use serde::{
-    de::DeserializeOwned, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer,
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
 impl<'a, Param> Serialize for ParallelVec<Param>
@@ -21,24 +23,54 @@ where
     }
 }
 
+/// The maximum number of bytes to preallocate from an untrusted size hint.
+/// Matches the limit used by `serde` for its own collections.
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+struct ParallelVecVisitor<Param>(PhantomData<Param>);
+
+impl<'de, Param> Visitor<'de> for ParallelVecVisitor<Param>
+where
+    Param: ParallelParam + Deserialize<'de>,
+{
+    type Value = ParallelVec<Param>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Size hints come from the input, so don't trust them to be
+        // reasonable, but avoid regrowing in the common case.
+        let size = core::cmp::max(core::mem::size_of::<Param>(), 1);
+        let capacity = core::cmp::min(seq.size_hint().unwrap_or(0), MAX_PREALLOC_BYTES / size);
+        let mut values = ParallelVec::with_capacity(capacity);
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
 impl<'de, Param> Deserialize<'de> for ParallelVec<Param>
 where
-    Param: ParallelParam + DeserializeOwned,
+    Param: ParallelParam + Deserialize<'de>,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Ok(Self::from(<Vec<Param> as Deserialize<'de>>::deserialize(
-            deserializer,
-        )?))
+        deserializer.deserialize_seq(ParallelVecVisitor(PhantomData))
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::ParallelVec;
-    use serde_test::{assert_tokens, Token};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
 
     #[test]
     fn test_serde_empty() {
@@ -46,6 +78,52 @@ mod test {
         assert_tokens(&vec, &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
     }
 
+    #[test]
+    fn test_de_without_len() {
+        let vec: ParallelVec<(i32, u64)> = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        assert_de_tokens(
+            &vec,
+            &[
+                Token::Seq { len: None },
+                Token::Tuple { len: 2 },
+                Token::I32(1),
+                Token::U64(2),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::I32(3),
+                Token::U64(4),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_huge_len_hint() {
+        let vec: ParallelVec<(i32, u64)> = ParallelVec::from(vec![(1, 2)]);
+        assert_de_tokens(
+            &vec,
+            &[
+                Token::Seq {
+                    len: Some(usize::MAX),
+                },
+                Token::Tuple { len: 2 },
+                Token::I32(1),
+                Token::U64(2),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_invalid() {
+        assert_de_tokens_error::<ParallelVec<(i32, u64)>>(
+            &[Token::Bool(true)],
+            "invalid type: boolean `true`, expected a sequence",
+        );
+    }
+
     #[test]
     fn test_serde_2() {
         let vec: ParallelVec<(i32, u64)> = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
@@ -1,13 +1,30 @@
-use crate::iter::{Iter, IterMut};
-use crate::ParallelParam;
-use crate::{assert_in_bounds, assert_in_bounds_inclusive};
+use crate::checksum::ChecksumHasher;
+use crate::iter::{
+    ChunkBy, Iter, IterEnumerated, IterMut, IterMutEnumerated, IterPtrs, RunsByKey, Zip, ZipMut,
+    ZipSlices,
+};
+#[cfg(feature = "rand")]
+use crate::sample;
+use crate::scan::Scan;
+use crate::sort::{self, SortByKeys};
+use crate::view::ParallelView;
+#[cfg(feature = "bytemuck")]
+use crate::ParallelPod;
+use crate::ParallelVec;
+use crate::{assert_in_bounds, resolve_range, GetManyMutError, PackedBools, PermutationError};
+use crate::{ArrayColumn, FloatColumn, ParallelColumn, ParallelParam, Rolling, SidecarSlices};
+use crate::{Lane, LaneMut};
 use alloc::vec::Vec;
 use core::{
     cmp::Ordering,
+    convert::Infallible,
     hash::{Hash, Hasher},
     marker::PhantomData,
-    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo},
+    mem::MaybeUninit,
+    ops::{Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo},
 };
+#[cfg(feature = "rand")]
+use rand::{distributions::WeightedError, Rng};
 
 /// A immutable dynamically-sized view into a contiguous heterogeneous sequence.
 /// Contiguous here means that elements are laid out so that every element is
@@ -58,6 +75,23 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn as_ptr(&self) -> Param::Ptr {
+        Param::as_ptr(self.storage)
+    }
+
+    /// Returns raw pointers to the slice’s buffers, one per column.
+    ///
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage.
+    ///
+    /// The caller must also ensure that the memory the pointers point to is never written to through these pointers.
+    /// Use [`ParallelSliceMut::as_mut_ptrs`] if the memory needs to be mutated.
+    #[inline]
+    pub fn as_ptrs(&self) -> Param::Ptr {
+        Param::as_ptr(self.storage)
+    }
+
     /// Returns the number of elements in the vector, also referred to as its ‘length’.
     pub fn len(&self) -> usize {
         self.len
@@ -73,7 +107,7 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get<'b: 'a, I: ParallelSliceIndex<Self>>(&'b self, index: I) -> Option<I::Output> {
+    pub fn get<I: ParallelSliceIndex<Self>>(&self, index: I) -> Option<I::Output<'_>> {
         index.get(self)
     }
 
@@ -95,10 +129,16 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
 
     /// Gets a immutable reference to the elements at `index`.
     ///
+    /// This is the equivalent of [`Index`], which cannot be implemented as a row
+    /// is a tuple of references rather than a single reference. `index` may be a
+    /// single index or a range of indices.
+    ///
     /// # Panics
     /// This function will panic if `index` is >= `self.len`.
+    ///
+    /// [`Index`]: core::ops::Index
     #[inline]
-    pub fn index<I>(&self, index: I) -> I::Output
+    pub fn index<I>(&self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -124,6 +164,29 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
     }
 
+    /// Returns an iterator over the raw pointers to every row of the slice.
+    ///
+    /// The pointers are only valid for reads. See [`IterPtrs`] for details.
+    pub fn iter_ptrs(&self) -> IterPtrs<'_, Param> {
+        self.iter_ptrs_range(..)
+    }
+
+    /// Returns an iterator over the raw pointers to the rows of the slice
+    /// within `range`.
+    ///
+    /// The pointers are only valid for reads. See [`IterPtrs`] for details.
+    ///
+    /// # Panics
+    /// This function will panic if the range is out of bounds.
+    pub fn iter_ptrs_range<R: RangeBounds<usize>>(&self, range: R) -> IterPtrs<'_, Param> {
+        let range = resolve_range(range, self.len);
+        IterPtrs {
+            ptr: unsafe { Param::ptr_at(self.storage, range.start) },
+            remaining: range.len(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Returns an iterator over the [`ParallelSlice`].
     pub fn iter(&self) -> Iter<'a, Param> {
         Iter {
@@ -133,6 +196,508 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         }
     }
 
+    /// Returns an iterator over the rows of the slice along with their indices.
+    ///
+    /// This is equivalent to `iter().enumerate()`, but derives the index of each row from its
+    /// offset into the slice instead of tracking a separate counter.
+    pub fn iter_enumerated(&self) -> IterEnumerated<'a, Param> {
+        IterEnumerated {
+            base: Param::as_ptr(self.storage),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Calls `f` on every row of the slice, in order.
+    ///
+    /// This is equivalent to `iter().for_each(f)`, but indexes every column
+    /// from a single loop counter, which is more amenable to vectorization than
+    /// zipping multiple column iterators.
+    #[inline]
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>),
+    {
+        let base = Param::as_ptr(self.storage);
+        for idx in 0..self.len {
+            unsafe { f(Param::as_ref(Param::add(base, idx))) }
+        }
+    }
+
+    /// Gets the slice of the `N`th column.
+    #[inline]
+    pub fn column<const N: usize>(&self) -> &[<Param as ParallelColumn<N>>::Column]
+    where
+        Param: ParallelColumn<N>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(Param::as_ptr(self.storage));
+            core::slice::from_raw_parts(ptr, self.len)
+        }
+    }
+
+    /// Finds the smallest value of the floating point `N`th column, alongside
+    /// its index. NaNs are ignored.
+    ///
+    /// Returns `None` if the slice is empty or the column contains only NaNs. If
+    /// there are multiple minimums, the first is returned.
+    pub fn min_float_column<const N: usize>(
+        &self,
+    ) -> Option<(usize, <Param as ParallelColumn<N>>::Column)>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: FloatColumn,
+    {
+        float_extreme(self.column::<N>(), Ordering::Less)
+    }
+
+    /// Finds the largest value of the floating point `N`th column, alongside
+    /// its index. NaNs are ignored.
+    ///
+    /// Returns `None` if the slice is empty or the column contains only NaNs. If
+    /// there are multiple maximums, the first is returned.
+    pub fn max_float_column<const N: usize>(
+        &self,
+    ) -> Option<(usize, <Param as ParallelColumn<N>>::Column)>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: FloatColumn,
+    {
+        float_extreme(self.column::<N>(), Ordering::Greater)
+    }
+
+    /// Binary searches the slice with a comparator function.
+    ///
+    /// The comparator should return an order code that indicates whether its
+    /// argument is `Less`, `Equal` or `Greater` than the desired target. The
+    /// slice must be sorted consistently with the comparator.
+    ///
+    /// If a matching row is found, [`Result::Ok`] is returned with its index.
+    /// If there are multiple matches, any one of them may be returned. If no
+    /// match is found, [`Result::Err`] is returned with the index where a
+    /// matching row could be inserted while maintaining the sorted order.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        binary_search_by(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Binary searches the slice with a key extraction function.
+    ///
+    /// The slice must be sorted by the key, i.e. with
+    /// [`sort_by_key`](ParallelSliceMut::sort_by_key) using the same function. See
+    /// [`binary_search_by`](Self::binary_search_by) for the returned value.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|row| f(row).cmp(b))
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate, i.e. the index of the first row for which `pred` returns
+    /// `false`.
+    ///
+    /// The slice must be partitioned by the predicate, i.e. all rows for which
+    /// it returns `true` must come before all rows for which it returns `false`.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(Param::Ref<'_>) -> bool,
+    {
+        partition_point::<Param, _>(Param::as_ptr(self.storage), self.len, pred)
+    }
+
+    /// Returns the range of rows whose key, as extracted by `f`, is equal to
+    /// `key`. The range is empty, and positioned where such rows could be
+    /// inserted, if there are none.
+    ///
+    /// The slice must be sorted by the key.
+    pub fn equal_range<K, F>(&self, key: &K, mut f: F) -> Range<usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let ptr = Param::as_ptr(self.storage);
+        let start = partition_point::<Param, _>(ptr, self.len, |row| f(row) < *key);
+        // SAFE: start is at most len, so the remaining rows are within the slice.
+        let rest = unsafe { Param::add(ptr, start) };
+        let end = start + partition_point::<Param, _>(rest, self.len - start, |row| f(row) <= *key);
+        start..end
+    }
+
+    /// Checks if the rows of the slice are sorted using the given comparator
+    /// function.
+    ///
+    /// `compare` is called on every pair of consecutive rows, and should
+    /// return `true` if they are in order. This is a cheap `O(N)` check of the
+    /// invariant required by [`binary_search_by`](Self::binary_search_by) and
+    /// the other search functions.
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        is_sorted_by::<Param, _>(Param::as_ptr(self.storage), self.len, compare)
+    }
+
+    /// Maps every row of the slice into a new [`ParallelVec`], in order.
+    ///
+    /// The output is allocated exactly once with the length of the slice, and each result is
+    /// written directly into its columns, avoiding an intermediate `Vec` of rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![(1u32, 2.0f32), (3, 4.0)]);
+    /// let mapped = src.map(|(a, b)| (*a as f32 * *b, *a == 3));
+    /// assert_eq!(mapped.as_slices(), (&[2.0, 12.0][..], &[false, true][..]));
+    /// ```
+    pub fn map<New, F>(&self, f: F) -> ParallelVec<New>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> New,
+    {
+        map_rows::<Param, _, _>(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Maps the rows of the slice into a new [`ParallelVec`], in order, keeping only the
+    /// results that are `Some`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![(1u32, 2.0f32), (2, 4.0), (3, 6.0)]);
+    /// let odd = src.filter_map(|(a, b)| (*a % 2 == 1).then(|| (*b, *a)));
+    /// assert_eq!(odd.as_slices(), (&[2.0, 6.0][..], &[1, 3][..]));
+    /// ```
+    pub fn filter_map<New, F>(&self, f: F) -> ParallelVec<New>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> Option<New>,
+    {
+        self.iter().filter_map(f).collect()
+    }
+
+    /// Maps every row of the slice into a new [`ParallelVec`], in order, stopping at the
+    /// first error.
+    ///
+    /// Like [`map`](Self::map), the output is allocated exactly once. If `f` returns an
+    /// error, the rows mapped so far are dropped and the error is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![("1", 'a'), ("2", 'b')]);
+    /// let parsed = src.try_map(|(num, c)| num.parse::<u32>().map(|num| (num, *c)));
+    /// assert_eq!(parsed.unwrap().as_slices(), (&[1, 2][..], &['a', 'b'][..]));
+    /// ```
+    pub fn try_map<New, E, F>(&self, f: F) -> Result<ParallelVec<New>, E>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> Result<New, E>,
+    {
+        try_map_rows::<Param, _, _, _>(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Clones the rows at `indices`, in order, into a new [`ParallelVec`].
+    ///
+    /// This is the "take" operation of columnar processing. Indices may be repeated and may
+    /// appear in any order. Every index is checked before anything is allocated, and the
+    /// result is allocated exactly once and filled in order.
+    ///
+    /// # Panics
+    /// This function will panic if any of the indices are out of bounds.
+    pub fn gather(&self, indices: &[usize]) -> ParallelVec<Param>
+    where
+        Param: Clone,
+    {
+        gather(Param::as_ptr(self.storage), self.len, indices)
+    }
+
+    /// Returns an iterator over the runs of consecutive rows for which `pred` returns `true`
+    /// between every adjacent pair, yielding each run as a [`ParallelSlice`].
+    ///
+    /// `pred` is called on each pair of adjacent rows, and a new run starts whenever it returns
+    /// `false`. When the slice is sorted by some key, an equality predicate on that key yields
+    /// one run per distinct key. This mirrors [`slice::chunk_by`].
+    ///
+    /// [`slice::chunk_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunk_by
+    pub fn chunk_by<P>(&self, pred: P) -> ChunkBy<'a, Param, P>
+    where
+        P: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        ChunkBy {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            pred,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the runs of consecutive rows with equal keys, yielding each key
+    /// along with the range of rows it covers.
+    ///
+    /// When the slice is sorted by the key, this yields every distinct key exactly once, and
+    /// the ranges can be used as per-group offsets. The key of each row is computed exactly
+    /// once.
+    pub fn runs_by_key<K, F>(&self, f: F) -> RunsByKey<'a, Param, K, F>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: PartialEq,
+    {
+        RunsByKey {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            idx: 0,
+            next_key: None,
+            back_key: None,
+            f,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Computes the prefix scan of the `N`th column with the associative
+    /// operation `op`, i.e. prefix sums when `op` is addition.
+    ///
+    /// The output has one value per row. See [`Scan`] for the difference
+    /// between inclusive and exclusive scans.
+    pub fn scan_column<const N: usize, F>(
+        &self,
+        scan: Scan<<Param as ParallelColumn<N>>::Column>,
+        op: F,
+    ) -> Vec<<Param as ParallelColumn<N>>::Column>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy,
+        F: Fn(
+            <Param as ParallelColumn<N>>::Column,
+            <Param as ParallelColumn<N>>::Column,
+        ) -> <Param as ParallelColumn<N>>::Column,
+    {
+        crate::scan::scan(self.column::<N>(), scan, op)
+    }
+
+    /// Computes the prefix scan of the `N`th column in parallel.
+    ///
+    /// `op` must be associative, as the column is split into chunks that are
+    /// combined in a different order than a sequential scan. Otherwise, this
+    /// produces the same results as [`scan_column`].
+    ///
+    /// [`scan_column`]: Self::scan_column
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn par_scan_column<const N: usize, F>(
+        &self,
+        scan: Scan<<Param as ParallelColumn<N>>::Column>,
+        op: F,
+    ) -> Vec<<Param as ParallelColumn<N>>::Column>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy + Send + Sync,
+        F: Fn(
+                <Param as ParallelColumn<N>>::Column,
+                <Param as ParallelColumn<N>>::Column,
+            ) -> <Param as ParallelColumn<N>>::Column
+            + Sync,
+    {
+        crate::scan::par_scan(self.column::<N>(), scan, op)
+    }
+
+    /// Gets a view of the `LANE`th element of every array in the `COL`th column.
+    ///
+    /// Array columns are stored one array after another, so the view is strided
+    /// by the length of the array rather than being a contiguous slice.
+    ///
+    /// Fails to compile if `LANE` is out of bounds for the array type.
+    #[inline]
+    pub fn column_lane<const COL: usize, const LANE: usize>(
+        &self,
+    ) -> Lane<'_, <Param as ParallelColumn<COL>>::Column>
+    where
+        Param: ParallelColumn<COL>,
+        <Param as ParallelColumn<COL>>::Column: ArrayColumn,
+    {
+        const {
+            assert!(LANE < <<Param as ParallelColumn<COL>>::Column as ArrayColumn>::LANES);
+        }
+        Lane {
+            rows: self.column::<COL>(),
+            lane: LANE,
+        }
+    }
+
+    /// Returns a [`Rolling`] window helper over the rows of the [`ParallelSlice`].
+    ///
+    /// # Panics
+    /// This function will panic if `window` is 0.
+    pub fn rolling(&self, window: usize) -> Rolling<'_, Param> {
+        assert!(window != 0, "window size must be non-zero");
+        Rolling {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            window,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Randomly samples `k` indices with replacement, where the probability of
+    /// selecting a row is proportional to the weight returned by `weight`.
+    ///
+    /// This builds an alias table in `O(N)` time, after which every sample
+    /// takes `O(1)` time.
+    ///
+    /// # Errors
+    /// Returns an error if the slice is empty, any weight is negative or not
+    /// finite, or all of the weights are zero.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sample_weighted<R, F>(
+        &self,
+        rng: &mut R,
+        k: usize,
+        weight: F,
+    ) -> Result<Vec<usize>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(Param::Ref<'_>) -> f64,
+    {
+        let rows: Iter<'_, Param> = Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        };
+        sample::sample_weighted(rows, rng, k, weight)
+    }
+
+    /// Randomly samples `k` rows with replacement, where the probability of
+    /// selecting a row is proportional to the weight returned by `weight`,
+    /// and gathers clones of them into a new [`ParallelVec`].
+    ///
+    /// See [`sample_weighted`] for details.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    /// [`sample_weighted`]: Self::sample_weighted
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sample_weighted_rows<R, F>(
+        &self,
+        rng: &mut R,
+        k: usize,
+        weight: F,
+    ) -> Result<ParallelVec<Param>, WeightedError>
+    where
+        Param: Clone,
+        R: Rng + ?Sized,
+        F: FnMut(Param::Ref<'_>) -> f64,
+    {
+        let indices = self.sample_weighted(rng, k, weight)?;
+        Ok(self.gather(&indices))
+    }
+
+    /// Randomly samples `k` indices with replacement, using the values of the
+    /// `N`th column as weights.
+    ///
+    /// See [`sample_weighted`] for details.
+    ///
+    /// [`sample_weighted`]: Self::sample_weighted
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn choose_weighted_by_column<const N: usize, R>(
+        &self,
+        rng: &mut R,
+        k: usize,
+    ) -> Result<Vec<usize>, WeightedError>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy + Into<f64>,
+        R: Rng + ?Sized,
+    {
+        let weights = self.column::<N>().iter().map(|weight| (*weight).into());
+        let table = sample::AliasTable::new(weights)?;
+        Ok((0..k).map(|_| table.sample(rng)).collect())
+    }
+
+    /// Gets the individual columns as fixed-size arrays, or `None` if the
+    /// length of the slice is not exactly `N`.
+    #[inline]
+    pub fn as_arrays<const N: usize>(&self) -> Option<Param::Arrays<'_, N>> {
+        if self.len != N {
+            return None;
+        }
+        unsafe { Some(Param::as_arrays(Param::as_ptr(self.storage))) }
+    }
+
+    /// Gets the bytes of every column as [`IoSlice`]s, in column order.
+    ///
+    /// The result can be passed to [`Write::write_vectored`] to write the
+    /// entire slice without first copying it into a single buffer.
+    ///
+    /// [`IoSlice`]: std::io::IoSlice
+    /// [`Write::write_vectored`]: std::io::Write::write_vectored
+    #[cfg(all(feature = "std", feature = "bytemuck"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "bytemuck"))))]
+    #[inline]
+    pub fn as_io_slices(&self) -> Param::IoSlices<'_>
+    where
+        Param: ParallelPod,
+    {
+        Param::io_slices(self.as_slices())
+    }
+
+    /// Computes a deterministic checksum over the length and the values of
+    /// every column.
+    ///
+    /// Each column is hashed as a whole, which is much faster than hashing the
+    /// slice row by row. Values are hashed as little-endian bytes, so for
+    /// columns of primitive numbers the checksum is stable across releases and
+    /// platforms, making it suitable for verifying that replicated copies of
+    /// the data are identical. For columns of other plain old data, such as
+    /// structs or arrays, it is only stable across platforms with the same
+    /// endianness. See [`ParallelPod::hash_columns_le`].
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn checksum(&self) -> u64
+    where
+        Param: ParallelPod,
+    {
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_u64(self.len as u64);
+        Param::hash_columns_le(self.as_slices(), &mut hasher);
+        hasher.finish()
+    }
+
+    /// Computes a deterministic checksum over the length and the [`Hash`]
+    /// of every row.
+    ///
+    /// This supports columns that are not plain old data, but is slower than
+    /// [`checksum`]. The checksum is stable across releases and platforms as
+    /// long as the [`Hash`] implementations of the columns are.
+    ///
+    /// [`checksum`]: Self::checksum
+    pub fn checksum_by_rows(&self) -> u64
+    where
+        for<'r> Param::Ref<'r>: Hash,
+    {
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_u64(self.len as u64);
+        for row in self.iter() {
+            row.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns a lazy [`ParallelView`] over the rows of the [`ParallelSlice`].
+    pub fn view(&self) -> ParallelView<'_, Param, fn(&Param::Ref<'_>) -> bool> {
+        ParallelView {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            predicate: |_| true,
+            _marker: PhantomData,
+        }
+    }
+
     /// Returns an iterator over the [`ParallelSlice`].
     pub fn iters(&self) -> Param::Iters<'_> {
         unsafe {
@@ -143,11 +708,10 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
     }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSlice<'s, Param>
+impl<'s, Param> Hash for ParallelSlice<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -160,6 +724,26 @@ where
     }
 }
 
+impl<'s, 'b, Param> PartialEq<&'b [Param]> for ParallelSlice<'s, Param>
+where
+    Param: ParallelParam + 's,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &&'b [Param]) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
+impl<'s, Param> PartialEq<Vec<Param>> for ParallelSlice<'s, Param>
+where
+    Param: ParallelParam + 's,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &Vec<Param>) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
 /// A mutable dynamically-sized view into a contiguous heterogeneous sequence.
 /// Contiguous here means that elements are laid out so that every element is
 /// the same distance from its neighbors.
@@ -224,7 +808,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get<'b: 'a, I>(&'b self, index: I) -> Option<I::Output>
+    pub fn get<I>(&self, index: I) -> Option<I::Output<'_>>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -236,7 +820,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get_mut<I>(&mut self, index: I) -> Option<I::Output>
+    pub fn get_mut<I>(&mut self, index: I) -> Option<I::Output<'_>>
     where
         I: ParallelSliceIndexMut<Self>,
     {
@@ -250,9 +834,13 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     }
 
     /// Returns the mutable pointer first element of the slice, or `None` if it is empty.
-    #[inline(always)]
-    pub fn first_mut(&mut self) -> Option<Param::RefMut<'a>> {
-        self.get_mut(0)
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<Param::RefMut<'_>> {
+        if self.len == 0 {
+            None
+        } else {
+            unsafe { Some(self.get_unchecked_mut(0)) }
+        }
     }
 
     /// Returns the last element of the slice, or `None` if it is empty.
@@ -277,10 +865,16 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 
     /// Gets a immutable reference to the elements at `index`.
     ///
+    /// This is the equivalent of [`Index`], which cannot be implemented as a row
+    /// is a tuple of references rather than a single reference. `index` may be a
+    /// single index or a range of indices. The result borrows the slice.
+    ///
     /// # Panics
     /// This function will panic if `index >= self.len`.
+    ///
+    /// [`Index`]: core::ops::Index
     #[inline]
-    pub fn index<I>(&self, index: I) -> I::Output
+    pub fn index<I>(&self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -289,10 +883,17 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 
     /// Gets a mutable reference to the elements at `index`.
     ///
+    /// This is the equivalent of [`IndexMut`], which cannot be implemented as a
+    /// row is a tuple of references rather than a single reference. `index` may
+    /// be a single index or a range of indices. The result mutably borrows the
+    /// slice.
+    ///
     /// # Panics
     /// This function will panic if `index >= self.len`.
+    ///
+    /// [`IndexMut`]: core::ops::IndexMut
     #[inline]
-    pub fn index_mut<I>(&mut self, index: I) -> I::Output
+    pub fn index_mut<I>(&mut self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndexMut<Self>,
     {
@@ -311,6 +912,46 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Moves the value at `index` out of the slice, replacing it with its
+    /// default value.
+    ///
+    /// Unlike removing the value, this does not move any other values.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len`.
+    #[inline]
+    pub fn take(&mut self, index: usize) -> Param
+    where
+        Param: Default,
+    {
+        assert_in_bounds(index, self.len);
+        let default = Param::default();
+        unsafe {
+            let ptr = Param::add(self.as_mut_ptrs(), index);
+            let value = Param::read(ptr);
+            Param::write(ptr, default);
+            value
+        }
+    }
+
+    /// Moves the value of the `N`th column at `index` out of the slice,
+    /// replacing it with its default value.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len`.
+    #[inline]
+    pub fn take_column<const N: usize>(
+        &mut self,
+        index: usize,
+    ) -> <Param as ParallelColumn<N>>::Column
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Default,
+    {
+        assert_in_bounds(index, self.len);
+        core::mem::take(&mut self.column_mut::<N>()[index])
+    }
+
     /// Sets a value at an valid index in the slice without
     /// checking bounds.
     ///
@@ -349,19 +990,64 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         Param::as_mut(Param::ptr_at(self.storage, index))
     }
 
-    /// Sorts the slice with a comparator function.
+    /// Returns mutable references to many rows at once.
     ///
-    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
-    /// buffer.
+    /// # Errors
+    /// Returns [`GetManyMutError::IndexOutOfBounds`] if any of the indices are out
+    /// of bounds, or [`GetManyMutError::OverlappingIndices`] if the same index is
+    /// provided more than once.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[Param::RefMut<'_>; N], GetManyMutError> {
+        for (i, &idx) in indices.iter().enumerate() {
+            if idx >= self.len {
+                return Err(GetManyMutError::IndexOutOfBounds);
+            }
+            if indices[..i].contains(&idx) {
+                return Err(GetManyMutError::OverlappingIndices);
+            }
+        }
+        // SAFE: Every index has been checked to be in bounds and distinct.
+        unsafe { Ok(self.get_many_unchecked_mut(indices)) }
+    }
+
+    /// Returns mutable references to many rows at once, without doing any
+    /// checks.
     ///
-    /// This defers to the `core` implemenation of [`slice::sort_by`], so any properties it
-    /// has will also hold for this function.
+    /// For a safe alternative see [`get_many_mut`].
     ///
-    /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
-    pub fn sort_by<F>(&mut self, f: F)
-    where
-        F: Fn(Param::Ref<'a>, Param::Ref<'a>) -> Ordering,
-    {
+    /// # Safety
+    /// Calling this method with overlapping or out-of-bounds indices is undefined
+    /// behavior even if the resulting references are not used.
+    ///
+    /// [`get_many_mut`]: Self::get_many_mut
+    #[inline]
+    pub unsafe fn get_many_unchecked_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> [Param::RefMut<'_>; N] {
+        let storage = self.storage;
+        core::array::from_fn(|i| Param::as_mut(Param::ptr_at(storage, indices[i])))
+    }
+
+    /// Sorts the slice with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal rows). As the columns are stored
+    /// separately, a permutation of row indices is sorted first, and then every column is
+    /// reordered by following the cycles of the permutation, moving each row at most once.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    ///
+    /// This defers to the `core` implemenation of [`slice::sort_by`], so any properties it
+    /// has will also hold for this function.
+    ///
+    /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+    pub fn sort_by<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
         let base = Param::as_ptr(self.storage);
         self.sort_via(|indices| {
             indices.sort_by(|a, b| unsafe {
@@ -375,16 +1061,21 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 
     /// Sorts the slice with a key extraction function.
     ///
+    /// This sort is stable (i.e., does not reorder equal rows). Only the row indices are
+    /// moved while sorting, so the comparisons only touch the columns read by `f`. Every
+    /// column is then reordered once, which makes this well suited to sorting on a small key
+    /// column when the other columns are large.
+    ///
     /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
     /// buffer.
     ///
     /// This defers to the `core` implemenation of [`slice::sort_by_key`], so any properties it
     /// has will also hold for this function.
     ///
-    /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
-    pub fn sort_by_key<K, F>(&mut self, f: F)
+    /// [`slice::sort_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
     where
-        F: Fn(Param::Ref<'a>) -> K,
+        F: FnMut(Param::Ref<'_>) -> K,
         K: Ord,
     {
         let base = Param::as_ptr(self.storage);
@@ -393,184 +1084,1262 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         });
     }
 
+    /// Starts a stable lexicographic sort over multiple keys, with `f` as the primary key.
+    ///
+    /// Further keys are added with [`then_by`](SortByKeys::then_by), and the slice is sorted
+    /// once [`sort`](SortByKeys::sort) is called. Each key is only extracted when all of the
+    /// previous keys compare equal.
+    ///
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec = ParallelVec::from(vec![(2, 'b', 0.5), (1, 'z', 1.0), (2, 'a', 2.0)]);
+    /// vec.sort_by_keys(|(a, _, _)| *a).then_by(|(_, b, _)| *b).sort();
+    /// assert_eq!(vec, vec![(1, 'z', 1.0), (2, 'a', 2.0), (2, 'b', 0.5)]);
+    /// ```
+    pub fn sort_by_keys<K, F>(
+        &mut self,
+        mut f: F,
+    ) -> SortByKeys<'_, 'a, Param, impl FnMut(Param::Ptr, Param::Ptr) -> Ordering>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        // SAFE: The comparator is only called with pointers to rows within the slice.
+        SortByKeys::new(self, move |a, b| unsafe {
+            f(Param::as_ref(a)).cmp(&f(Param::as_ref(b)))
+        })
+    }
+
+    /// Sorts the slice with a key extraction function, calling it only once per row.
+    ///
+    /// This sort is stable (i.e., does not reorder equal rows). The key of every row is
+    /// computed up front and cached alongside its index, so unlike
+    /// [`sort_by_key`](Self::sort_by_key), the rows are never read during comparisons. This is
+    /// faster when the key function is expensive or reads columns that are otherwise cold.
+    ///
+    /// This function will allocate `(sizeof(K) + 2 * sizeof(usize)) * self.len` bytes as
+    /// intermediate sorting buffers.
+    ///
+    /// This mirrors [`slice::sort_by_cached_key`].
+    ///
+    /// [`slice::sort_by_cached_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_cached_key
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let base = Param::as_ptr(self.storage);
+        let len = self.len;
+        self.sort_via(|indices| {
+            // SAFE: All of the indices are within the slice.
+            let mut keys: Vec<(K, usize)> = (0..len)
+                .map(|idx| unsafe { (f(Param::as_ref(Param::add(base, idx))), idx) })
+                .collect();
+            // The indices are unique, so this is stable with respect to the keys.
+            keys.sort_unstable();
+            for (dst, (_, src)) in indices.iter_mut().zip(keys) {
+                *dst = src;
+            }
+        });
+    }
+
+    /// Sorts the slice by a `u32` key with a radix sort instead of a comparison sort.
+    ///
+    /// This sort is stable (i.e., does not reorder equal rows) and `O(n)`. The key of every
+    /// row is extracted once, the keys are sorted with a least significant digit radix sort,
+    /// and then every column is reordered once. Radix passes over bytes that are the same for
+    /// every key are skipped.
+    ///
+    /// This function will allocate `(sizeof(u64) + 2 * sizeof(usize)) * self.len` bytes as
+    /// intermediate sorting buffers.
+    pub fn sort_by_u32_key<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> u32,
+    {
+        self.sort_by_radix_key(|row| u64::from(f(row)), 4);
+    }
+
+    /// Sorts the slice by a `u64` key with a radix sort instead of a comparison sort.
+    ///
+    /// See [`sort_by_u32_key`](Self::sort_by_u32_key) for details.
+    pub fn sort_by_u64_key<F>(&mut self, f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> u64,
+    {
+        self.sort_by_radix_key(f, 8);
+    }
+
+    fn sort_by_radix_key<F>(&mut self, mut f: F, bytes: usize)
+    where
+        F: FnMut(Param::Ref<'_>) -> u64,
+    {
+        let base = Param::as_ptr(self.storage);
+        let len = self.len;
+        self.sort_via(|indices| {
+            // SAFE: All of the indices are within the slice.
+            let keys: Vec<u64> = (0..len)
+                .map(|idx| unsafe { f(Param::as_ref(Param::add(base, idx))) })
+                .collect();
+            sort::radix_sort_indices(indices, &keys, bytes);
+        });
+    }
+
     /// Sorts the slice with a comparator function, but might not preserve the order of equal
     /// elements.
     ///
+    /// This sort is unstable (i.e., may reorder equal rows), in-place (i.e., does not
+    /// allocate), and `O(n * log(n))` worst-case. Rows are exchanged directly with
+    /// [`swap`](Self::swap), which avoids the intermediate buffer [`sort_by`](Self::sort_by)
+    /// needs, but moves every column of a row each time it is exchanged. For rows with large
+    /// columns that are sorted on a small key, [`sort_unstable_by_key`](Self::sort_unstable_by_key)
+    /// may be faster.
+    ///
+    /// The current algorithm is an introsort: a quicksort with median-of-three pivots that
+    /// falls back to heapsort if it degrades.
+    pub fn sort_unstable_by<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        // SAFE: The storage points to len initialized rows.
+        unsafe {
+            sort::sort_unstable_by::<Param, _>(
+                Param::as_ptr(self.storage),
+                self.len,
+                &mut |a, b| f(a, b) == Ordering::Less,
+            );
+        }
+    }
+
+    /// Sorts the slice with a key extraction function, but might not preserve the order of equal
+    /// elements.
+    ///
     /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
     /// buffer.
     ///
-    /// This defers to the `core` implemenation of [`slice::sort_unstable_by`], so any properties it
-    /// has will also hold for this function.
+    /// This defers to the `core` implemenation of [`slice::sort_unstable_by_key`], so any properties
+    /// it has will also hold this function.
     ///
-    /// [`slice::sort_unstable_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by
-    pub fn sort_unstable_by<F>(&mut self, f: F)
+    /// [`slice::sort_unstable_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by_key
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
     where
-        F: Fn(Param::Ref<'a>, Param::Ref<'a>) -> Ordering,
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
     {
         let base = Param::as_ptr(self.storage);
         self.sort_via(|indices| {
-            indices.sort_unstable_by(|a, b| unsafe {
-                f(
-                    Param::as_ref(Param::add(base, *a)),
-                    Param::as_ref(Param::add(base, *b)),
-                )
+            indices.sort_unstable_by_key(|idx| unsafe { f(Param::as_ref(Param::add(base, *idx))) });
+        });
+    }
+
+    /// Sorts the slice by the floating point values of the `N`th column.
+    ///
+    /// Values are compared with `total_cmp`, so this never panics and NaNs are
+    /// ordered deterministically: negative NaNs sort before every other value
+    /// and positive NaNs sort after every other value. The sort is stable.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    pub fn sort_by_float_key_column<const N: usize>(&mut self)
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: FloatColumn,
+    {
+        let column = Param::column_ptr(self.as_mut_ptrs());
+        self.sort_via(|indices| {
+            indices.sort_by(|a, b| unsafe { (*column.add(*a)).total_cmp(&*column.add(*b)) });
+        });
+    }
+
+    /// Sorts the slice with a comparator function, and applies the same reordering
+    /// to `companion`.
+    ///
+    /// This is useful for keeping auxiliary per-row data that is stored outside
+    /// of the slice in the same order as the slice.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    ///
+    /// # Panics
+    /// This function will panic if `companion` is not the same length as the slice.
+    pub fn sort_by_with_companion<U, F>(&mut self, mut f: F, companion: &mut [U])
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        assert_eq!(
+            companion.len(),
+            self.len,
+            "companion must be the same length as the slice"
+        );
+        let base = Param::as_ptr(self.storage);
+        self.sort_via_with(
+            |indices| {
+                indices.sort_by(|a, b| unsafe {
+                    f(
+                        Param::as_ref(Param::add(base, *a)),
+                        Param::as_ref(Param::add(base, *b)),
+                    )
+                });
+            },
+            |a, b| companion.swap(a, b),
+        );
+    }
+
+    /// Reorders the slice with a comparator function such that the row at `index` is at its
+    /// final sorted position.
+    ///
+    /// Every row before `index` is less than or equal to it, and every row after it is greater
+    /// than or equal to it, but neither side is otherwise sorted. This is useful for finding
+    /// medians and percentiles without a full sort.
+    ///
+    /// Returns the rows before `index`, the row at `index`, and the rows after it.
+    ///
+    /// This reorders in place (i.e. does not allocate), and is `O(n)` on average and
+    /// `O(n * log(n))` in the worst case. This mirrors [`slice::select_nth_unstable_by`].
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    ///
+    /// [`slice::select_nth_unstable_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable_by
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        mut f: F,
+    ) -> (
+        ParallelSliceMut<'_, Param>,
+        Param::RefMut<'_>,
+        ParallelSliceMut<'_, Param>,
+    )
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        assert_in_bounds(index, self.len);
+        let base = Param::as_ptr(self.storage);
+        // SAFE: The storage points to len initialized rows, and index is in bounds.
+        unsafe {
+            sort::select_nth_unstable_by::<Param, _>(base, self.len, index, &mut |a, b| {
+                f(a, b) == Ordering::Less
             });
+            let after = Param::add(base, index + 1);
+            (
+                ParallelSliceMut::from_raw_parts(self.storage, index),
+                Param::as_mut(Param::add(base, index)),
+                ParallelSliceMut::from_raw_parts(Param::as_storage(after), self.len - index - 1),
+            )
+        }
+    }
+
+    /// Reorders the slice in place so that every row for which `pred` returns `true` comes
+    /// before every row for which it returns `false`, and returns the number of rows for which
+    /// it returned `true`.
+    ///
+    /// This is a Hoare-style partition: rows are scanned from both ends and out of place pairs
+    /// are swapped across every column. `pred` is called exactly once per row. The relative
+    /// order of the rows within each group is not preserved; see
+    /// [`stable_partition`](Self::stable_partition) for that.
+    pub fn partition<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        let base = Param::as_ptr(self.storage);
+        // Every row before left is in the first group, and every row at or
+        // after right is in the second group.
+        let (mut left, mut right) = (0, self.len);
+        // SAFE: left..right is always within the slice.
+        unsafe {
+            loop {
+                while left < right && pred(Param::as_ref(Param::add(base, left))) {
+                    left += 1;
+                }
+                // The row at left, if any, is known to be in the second group.
+                loop {
+                    if left == right || left == right - 1 {
+                        return left;
+                    }
+                    right -= 1;
+                    if pred(Param::as_ref(Param::add(base, right))) {
+                        break;
+                    }
+                }
+                self.swap_unchecked(left, right);
+                left += 1;
+            }
+        }
+    }
+
+    /// Reorders the slice so that every row for which `pred` returns `true` comes before every
+    /// row for which it returns `false`, preserving the relative order of the rows within each
+    /// group, and returns the number of rows for which it returned `true`.
+    ///
+    /// `pred` is called exactly once per row, and every row is then moved at most once.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate buffer,
+    /// plus one bit per row to hold the results of `pred`.
+    pub fn stable_partition<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        let base = Param::as_ptr(self.storage);
+        // SAFE: All of the indices are within the slice.
+        let flags: PackedBools = (0..self.len)
+            .map(|idx| unsafe { pred(Param::as_ref(Param::add(base, idx))) })
+            .collect();
+        let split = flags.count_ones();
+        self.sort_via(|indices| {
+            let (mut front, mut back) = (0, split);
+            for (idx, flag) in flags.iter().enumerate() {
+                let slot = if flag { &mut front } else { &mut back };
+                indices[*slot] = idx;
+                *slot += 1;
+            }
+        });
+        split
+    }
+
+    /// Reorders the rows according to a permutation, so that the row at `indices[i]` is
+    /// moved to `i`.
+    ///
+    /// This applies an externally computed order, such as the output of an argsort, to every
+    /// column without round-tripping through a `Vec` of rows. Each row is moved at most once.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate
+    /// buffer.
+    ///
+    /// # Errors
+    /// Returns a [`PermutationError`] and leaves the slice unchanged if `indices` is not the
+    /// same length as the slice or is not a permutation of `0..self.len()`.
+    pub fn apply_permutation(&mut self, indices: &[usize]) -> Result<(), PermutationError> {
+        if indices.len() != self.len {
+            return Err(PermutationError::UnevenLengths);
+        }
+        let mut seen = PackedBools::repeat(false, self.len);
+        for &idx in indices {
+            match seen.get(idx) {
+                None => return Err(PermutationError::IndexOutOfBounds),
+                Some(true) => return Err(PermutationError::DuplicateIndices),
+                Some(false) => seen.set(idx, true),
+            }
+        }
+        // SAFE: indices has been checked to be a permutation of 0..len.
+        unsafe { self.apply_permutation_unchecked(indices) };
+        Ok(())
+    }
+
+    /// Reorders the rows according to a permutation, so that the row at `indices[i]` is
+    /// moved to `i`, without checking that `indices` is a valid permutation.
+    ///
+    /// For a safe alternative see [`apply_permutation`].
+    ///
+    /// # Safety
+    /// `indices` must be the same length as the slice, and contain every index in
+    /// `0..self.len()` exactly once. Otherwise rows may be duplicated or leaked, and memory
+    /// outside of the slice may be accessed.
+    ///
+    /// [`apply_permutation`]: Self::apply_permutation
+    pub unsafe fn apply_permutation_unchecked(&mut self, indices: &[usize]) {
+        let mut indices = indices.to_vec();
+        sort::apply_permutation::<Param, _>(Param::as_ptr(self.storage), &mut indices, |_, _| {});
+    }
+
+    pub(crate) fn sort_by_ptrs<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ptr, Param::Ptr) -> Ordering,
+    {
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            indices.sort_by(|a, b| unsafe { f(Param::add(base, *a), Param::add(base, *b)) });
         });
     }
 
-    /// Sorts the slice with a key extraction function, but might not preserve the order of equal
-    /// elements.
-    ///
-    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
-    /// buffer.
+    #[inline(always)]
+    fn sort_via<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Vec<usize>),
+    {
+        self.sort_via_with(f, |_, _| {});
+    }
+
+    #[inline(always)]
+    fn sort_via_with<F, S>(&mut self, f: F, swap: S)
+    where
+        F: FnOnce(&mut Vec<usize>),
+        S: FnMut(usize, usize),
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut indices: Vec<usize> = (0..self.len).collect();
+        f(&mut indices);
+        // SAFE: The storage points to len initialized rows, and the sorted
+        // indices are a permutation of 0..len.
+        unsafe {
+            sort::apply_permutation::<Param, _>(Param::as_ptr(self.storage), &mut indices, swap)
+        };
+    }
+
+    /// Returns raw pointers to the slice’s buffers, one per column.
+    ///
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage.
+    ///
+    /// The caller must also ensure that the memory the pointers point to is never written to through these pointers.
+    /// Use [`as_mut_ptrs`] if the memory needs to be mutated.
+    ///
+    /// [`as_mut_ptrs`]: Self::as_mut_ptrs
+    #[inline]
+    pub fn as_ptrs(&self) -> Param::Ptr {
+        Param::as_ptr(self.storage)
+    }
+
+    /// Returns raw pointers to the slice’s buffers, one per column.
+    ///
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage.
+    ///
+    /// Modifying the container referenced by this slice may cause its buffer to be reallocated, which would also make any
+    /// pointers to it invalid.
+    #[inline]
+    pub fn as_mut_ptrs(&mut self) -> Param::Ptr {
+        Param::as_ptr(self.storage)
+    }
+
+    /// Gets the individual slices for every sub-slice.
+    #[inline]
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
+    }
+
+    /// Gets the individual columns as mutable fixed-size arrays, or `None` if the
+    /// length of the slice is not exactly `N`.
+    #[inline]
+    pub fn as_arrays_mut<const N: usize>(&mut self) -> Option<Param::ArraysMut<'_, N>> {
+        if self.len != N {
+            return None;
+        }
+        unsafe { Some(Param::as_arrays_mut(self.as_mut_ptrs())) }
+    }
+
+    /// Gets the bytes of every column as [`IoSliceMut`]s, in column order.
+    ///
+    /// The result can be passed to [`Read::read_vectored`] to fill the entire
+    /// slice without first reading into an intermediate buffer.
+    ///
+    /// [`IoSliceMut`]: std::io::IoSliceMut
+    /// [`Read::read_vectored`]: std::io::Read::read_vectored
+    #[cfg(all(feature = "std", feature = "bytemuck"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "bytemuck"))))]
+    #[inline]
+    pub fn as_io_slices_mut(&mut self) -> Param::IoSlicesMut<'_>
+    where
+        Param: ParallelPod,
+    {
+        Param::io_slices_mut(self.as_slices_mut())
+    }
+
+    /// Gets a mutable view of the `LANE`th element of every array in the `COL`th column.
+    ///
+    /// Array columns are stored one array after another, so the view is strided
+    /// by the length of the array rather than being a contiguous slice.
+    ///
+    /// Fails to compile if `LANE` is out of bounds for the array type.
+    #[inline]
+    pub fn column_lane_mut<const COL: usize, const LANE: usize>(
+        &mut self,
+    ) -> LaneMut<'_, <Param as ParallelColumn<COL>>::Column>
+    where
+        Param: ParallelColumn<COL>,
+        <Param as ParallelColumn<COL>>::Column: ArrayColumn,
+    {
+        const {
+            assert!(LANE < <<Param as ParallelColumn<COL>>::Column as ArrayColumn>::LANES);
+        }
+        LaneMut {
+            rows: self.column_mut::<COL>(),
+            lane: LANE,
+        }
+    }
+
+    /// Gets the mutable slice of the `N`th column.
+    #[inline]
+    pub fn column_mut<const N: usize>(&mut self) -> &mut [<Param as ParallelColumn<N>>::Column]
+    where
+        Param: ParallelColumn<N>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(self.as_mut_ptrs());
+            core::slice::from_raw_parts_mut(ptr, self.len)
+        }
+    }
+
+    /// Gets mutable individual slices for every sub-slice.
+    #[inline]
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        unsafe { Param::as_slices_mut(self.as_mut_ptrs(), self.len) }
+    }
+
+    /// Swaps two elements.
+    ///
+    /// # Arguments
+    ///  - `a` - The index of the first element
+    ///  - `b` - The index of the second element
+    ///
+    /// # Panics
+    /// Panics if a or b are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert_in_bounds(a, self.len);
+        assert_in_bounds(b, self.len);
+        unsafe { self.swap_unchecked(a, b) }
+    }
+
+    /// Swaps two elements in the slice, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`swap`].
+    ///
+    /// # Arguments
+    ///  - `a` - The index of the first element
+    ///  - `b` - The index of the second element
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// The caller has to ensure that `a < self.len()` and `b < self.len()`.
+    ///
+    /// [`swap`]: Self::swap
+    #[inline]
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let base = Param::as_ptr(self.storage);
+        Param::swap(Param::add(base, a), Param::add(base, b));
+    }
+
+    /// Reverses the order of elements in the [`ParallelSliceMut`], in place.
+    ///
+    /// This is a `O(n)` operation.
+    pub fn reverse(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+        Param::reverse(self.as_slices_mut())
+    }
+
+    /// Swaps all elements in `self` with those in `other`.
+    ///
+    /// The length of other must be the same as `self`.  
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    pub fn swap_with(&mut self, other: &mut Self) {
+        if self.len != other.len {
+            panic!(
+                "Attempted to use swap_with with slices of different lenghths: {} vs {}",
+                self.len, other.len
+            )
+        }
+        unsafe {
+            let mut a = self.as_mut_ptrs();
+            let mut b = other.as_mut_ptrs();
+            for _ in 0..self.len {
+                Param::swap(a, b);
+                a = Param::add(a, 1);
+                b = Param::add(b, 1);
+            }
+        }
+    }
+
+    /// Swaps the rows in range `a` with the rows in range `b`.
+    ///
+    /// Each column is swapped with a single bulk swap.
+    ///
+    /// # Panics
+    /// This function will panic if either range is decreasing or out of
+    /// bounds, if the ranges have different lengths, or if they overlap.
+    pub fn swap_ranges(&mut self, a: impl RangeBounds<usize>, b: impl RangeBounds<usize>) {
+        let a = resolve_range(a, self.len);
+        let b = resolve_range(b, self.len);
+        assert_eq!(a.len(), b.len(), "ranges must have the same length");
+        assert!(
+            a.is_empty() || a.end <= b.start || b.end <= a.start,
+            "ranges must not overlap"
+        );
+        // SAFE: Both ranges are in bounds and do not overlap.
+        unsafe {
+            let base = self.as_mut_ptrs();
+            Param::swap_nonoverlapping(
+                Param::add(base, a.start),
+                Param::add(base, b.start),
+                a.len(),
+            );
+        }
+    }
+
+    /// Returns an iterator over the [`ParallelSliceMut`].
+    pub fn iter(&self) -> Iter<'a, Param> {
+        Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the rows of the slice along with their indices.
+    ///
+    /// This is equivalent to `iter().enumerate()`, but derives the index of each row from its
+    /// offset into the slice instead of tracking a separate counter.
+    pub fn iter_enumerated(&self) -> IterEnumerated<'_, Param> {
+        IterEnumerated {
+            base: Param::as_ptr(self.storage),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Calls `f` on every row of the slice, in order.
+    ///
+    /// This is equivalent to `iter().for_each(f)`, but indexes every column
+    /// from a single loop counter, which is more amenable to vectorization than
+    /// zipping multiple column iterators.
+    #[inline]
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>),
+    {
+        let base = Param::as_ptr(self.storage);
+        for idx in 0..self.len {
+            unsafe { f(Param::as_ref(Param::add(base, idx))) }
+        }
+    }
+
+    /// Calls `f` on a mutable reference to every row of the slice, in order.
+    ///
+    /// See [`for_each`].
+    ///
+    /// [`for_each`]: Self::for_each
+    #[inline]
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::RefMut<'_>),
+    {
+        let base = self.as_mut_ptrs();
+        for idx in 0..self.len {
+            unsafe { f(Param::as_mut(Param::add(base, idx))) }
+        }
+    }
+
+    /// Returns an iterator over the raw pointers to every row of the slice.
+    ///
+    /// The pointers are valid for both reads and writes. See [`IterPtrs`] for details.
+    pub fn iter_ptrs(&mut self) -> IterPtrs<'_, Param> {
+        self.iter_ptrs_range(..)
+    }
+
+    /// Returns an iterator over the raw pointers to the rows of the slice
+    /// within `range`.
+    ///
+    /// The pointers are valid for both reads and writes. See [`IterPtrs`] for details.
+    ///
+    /// # Panics
+    /// This function will panic if the range is out of bounds.
+    pub fn iter_ptrs_range<R: RangeBounds<usize>>(&mut self, range: R) -> IterPtrs<'_, Param> {
+        let range = resolve_range(range, self.len);
+        IterPtrs {
+            ptr: unsafe { Param::add(self.as_mut_ptrs(), range.start) },
+            remaining: range.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each value.
+    pub fn iter_mut(&mut self) -> IterMut<'a, Param> {
+        IterMut {
+            ptr: self.as_mut_ptrs(),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each row along with its index.
+    ///
+    /// This is equivalent to `iter_mut().enumerate()`, but derives the index of each row from
+    /// its offset into the slice instead of tracking a separate counter.
+    pub fn iter_mut_enumerated(&mut self) -> IterMutEnumerated<'_, Param> {
+        IterMutEnumerated {
+            base: self.as_mut_ptrs(),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each row, yielded along with the elements at
+    /// the same index of one or more sidecar slices.
+    ///
+    /// `slices` may be a `&[T]`, a `&mut [T]`, or a tuple of up to four of them. Their lengths
+    /// are checked once up front, so no per-row bounds checks are needed.
+    ///
+    /// # Panics
+    /// This function will panic if any of the slices is not the same length as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use parallel_vec::ParallelVec;
+    /// let mut positions = ParallelVec::from(vec![(0.0f32, 1.0f32), (2.0, 3.0)]);
+    /// let velocities = [1.0f32, -1.0];
+    /// let mut speeds = [0.0f32; 2];
+    /// for ((x, _), (v, speed)) in positions.zip_slices((&velocities[..], &mut speeds[..])) {
+    ///     *x += *v;
+    ///     *speed = v.abs();
+    /// }
+    /// assert_eq!(positions.as_slices().0, &[1.0, 1.0]);
+    /// assert_eq!(speeds, [1.0, 1.0]);
+    /// ```
+    pub fn zip_slices<S: SidecarSlices>(&mut self, slices: S) -> ZipSlices<'_, Param, S> {
+        assert_eq!(
+            slices.get_len(),
+            Some(self.len),
+            "sidecar slices must all have the same length as the slice"
+        );
+        ZipSlices {
+            base: self.as_mut_ptrs(),
+            slices: slices.into_ptr(),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the rows of `self` and `other` in lockstep.
+    ///
+    /// The lengths are checked once up front, so this avoids the per-row checks of zipping
+    /// the two iterators manually.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is not the same length as `self`.
+    pub fn zip_iter<'b, Other: ParallelParam>(
+        &'b self,
+        other: &'b ParallelSliceMut<'_, Other>,
+    ) -> Zip<'b, Param, Other> {
+        assert_eq!(
+            self.len, other.len,
+            "zipped slices must have the same length"
+        );
+        Zip {
+            a: Param::as_ptr(self.storage),
+            b: other.as_ptrs(),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying the rows of `self` and `other` in lockstep.
+    ///
+    /// The lengths are checked once up front, so this avoids the per-row checks of zipping
+    /// the two iterators manually.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is not the same length as `self`.
+    pub fn zip_iter_mut<'b, Other: ParallelParam>(
+        &'b mut self,
+        other: &'b mut ParallelSliceMut<'_, Other>,
+    ) -> ZipMut<'b, Param, Other> {
+        assert_eq!(
+            self.len, other.len,
+            "zipped slices must have the same length"
+        );
+        ZipMut {
+            a: self.as_mut_ptrs(),
+            b: other.as_mut_ptrs(),
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the slice of the `N`th column.
+    #[inline]
+    pub fn column<const N: usize>(&self) -> &[<Param as ParallelColumn<N>>::Column]
+    where
+        Param: ParallelColumn<N>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(Param::as_ptr(self.storage));
+            core::slice::from_raw_parts(ptr, self.len)
+        }
+    }
+
+    /// Finds the smallest value of the floating point `N`th column, alongside
+    /// its index. NaNs are ignored.
+    ///
+    /// Returns `None` if the slice is empty or the column contains only NaNs. If
+    /// there are multiple minimums, the first is returned.
+    pub fn min_float_column<const N: usize>(
+        &self,
+    ) -> Option<(usize, <Param as ParallelColumn<N>>::Column)>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: FloatColumn,
+    {
+        float_extreme(self.column::<N>(), Ordering::Less)
+    }
+
+    /// Finds the largest value of the floating point `N`th column, alongside
+    /// its index. NaNs are ignored.
+    ///
+    /// Returns `None` if the slice is empty or the column contains only NaNs. If
+    /// there are multiple maximums, the first is returned.
+    pub fn max_float_column<const N: usize>(
+        &self,
+    ) -> Option<(usize, <Param as ParallelColumn<N>>::Column)>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: FloatColumn,
+    {
+        float_extreme(self.column::<N>(), Ordering::Greater)
+    }
+
+    /// Binary searches the slice with a comparator function.
+    ///
+    /// The comparator should return an order code that indicates whether its
+    /// argument is `Less`, `Equal` or `Greater` than the desired target. The
+    /// slice must be sorted consistently with the comparator.
+    ///
+    /// If a matching row is found, [`Result::Ok`] is returned with its index.
+    /// If there are multiple matches, any one of them may be returned. If no
+    /// match is found, [`Result::Err`] is returned with the index where a
+    /// matching row could be inserted while maintaining the sorted order.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        binary_search_by(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Binary searches the slice with a key extraction function.
+    ///
+    /// The slice must be sorted by the key, i.e. with
+    /// [`sort_by_key`](ParallelSliceMut::sort_by_key) using the same function. See
+    /// [`binary_search_by`](Self::binary_search_by) for the returned value.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|row| f(row).cmp(b))
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate, i.e. the index of the first row for which `pred` returns
+    /// `false`.
+    ///
+    /// The slice must be partitioned by the predicate, i.e. all rows for which
+    /// it returns `true` must come before all rows for which it returns `false`.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(Param::Ref<'_>) -> bool,
+    {
+        partition_point::<Param, _>(Param::as_ptr(self.storage), self.len, pred)
+    }
+
+    /// Returns the range of rows whose key, as extracted by `f`, is equal to
+    /// `key`. The range is empty, and positioned where such rows could be
+    /// inserted, if there are none.
+    ///
+    /// The slice must be sorted by the key.
+    pub fn equal_range<K, F>(&self, key: &K, mut f: F) -> Range<usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let ptr = Param::as_ptr(self.storage);
+        let start = partition_point::<Param, _>(ptr, self.len, |row| f(row) < *key);
+        // SAFE: start is at most len, so the remaining rows are within the slice.
+        let rest = unsafe { Param::add(ptr, start) };
+        let end = start + partition_point::<Param, _>(rest, self.len - start, |row| f(row) <= *key);
+        start..end
+    }
+
+    /// Checks if the rows of the slice are sorted using the given comparator
+    /// function.
+    ///
+    /// `compare` is called on every pair of consecutive rows, and should
+    /// return `true` if they are in order. This is a cheap `O(N)` check of the
+    /// invariant required by [`binary_search_by`](Self::binary_search_by) and
+    /// the other search functions.
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        is_sorted_by::<Param, _>(Param::as_ptr(self.storage), self.len, compare)
+    }
+
+    /// Maps every row of the slice into a new [`ParallelVec`], in order.
+    ///
+    /// The output is allocated exactly once with the length of the slice, and each result is
+    /// written directly into its columns, avoiding an intermediate `Vec` of rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![(1u32, 2.0f32), (3, 4.0)]);
+    /// let mapped = src.map(|(a, b)| (*a as f32 * *b, *a == 3));
+    /// assert_eq!(mapped.as_slices(), (&[2.0, 12.0][..], &[false, true][..]));
+    /// ```
+    pub fn map<New, F>(&self, f: F) -> ParallelVec<New>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> New,
+    {
+        map_rows::<Param, _, _>(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Maps the rows of the slice into a new [`ParallelVec`], in order, keeping only the
+    /// results that are `Some`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![(1u32, 2.0f32), (2, 4.0), (3, 6.0)]);
+    /// let odd = src.filter_map(|(a, b)| (*a % 2 == 1).then(|| (*b, *a)));
+    /// assert_eq!(odd.as_slices(), (&[2.0, 6.0][..], &[1, 3][..]));
+    /// ```
+    pub fn filter_map<New, F>(&self, f: F) -> ParallelVec<New>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> Option<New>,
+    {
+        self.iter().filter_map(f).collect()
+    }
+
+    /// Maps every row of the slice into a new [`ParallelVec`], in order, stopping at the
+    /// first error.
+    ///
+    /// Like [`map`](Self::map), the output is allocated exactly once. If `f` returns an
+    /// error, the rows mapped so far are dropped and the error is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let src = ParallelVec::from(vec![("1", 'a'), ("2", 'b')]);
+    /// let parsed = src.try_map(|(num, c)| num.parse::<u32>().map(|num| (num, *c)));
+    /// assert_eq!(parsed.unwrap().as_slices(), (&[1, 2][..], &['a', 'b'][..]));
+    /// ```
+    pub fn try_map<New, E, F>(&self, f: F) -> Result<ParallelVec<New>, E>
+    where
+        New: ParallelParam,
+        F: FnMut(Param::Ref<'_>) -> Result<New, E>,
+    {
+        try_map_rows::<Param, _, _, _>(Param::as_ptr(self.storage), self.len, f)
+    }
+
+    /// Clones the rows at `indices`, in order, into a new [`ParallelVec`].
+    ///
+    /// This is the "take" operation of columnar processing. Indices may be repeated and may
+    /// appear in any order. Every index is checked before anything is allocated, and the
+    /// result is allocated exactly once and filled in order.
+    ///
+    /// # Panics
+    /// This function will panic if any of the indices are out of bounds.
+    pub fn gather(&self, indices: &[usize]) -> ParallelVec<Param>
+    where
+        Param: Clone,
+    {
+        gather(Param::as_ptr(self.storage), self.len, indices)
+    }
+
+    /// Returns an iterator over the runs of consecutive rows for which `pred` returns `true`
+    /// between every adjacent pair, yielding each run as a [`ParallelSlice`].
     ///
-    /// This defers to the `core` implemenation of [`slice::sort_unstable_by_key`], so any properties
-    /// it has will also hold this function.
+    /// `pred` is called on each pair of adjacent rows, and a new run starts whenever it returns
+    /// `false`. When the slice is sorted by some key, an equality predicate on that key yields
+    /// one run per distinct key. This mirrors [`slice::chunk_by`].
     ///
-    /// [`slice::sort_unstable_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by_key
-    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    /// [`slice::chunk_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunk_by
+    pub fn chunk_by<P>(&self, pred: P) -> ChunkBy<'_, Param, P>
     where
-        F: Fn(Param::Ref<'a>) -> K,
-        K: Ord,
+        P: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
     {
-        let base = Param::as_ptr(self.storage);
-        self.sort_via(|indices| {
-            indices.sort_unstable_by_key(|idx| unsafe { f(Param::as_ref(Param::add(base, *idx))) });
-        });
+        ChunkBy {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            pred,
+            _marker: PhantomData,
+        }
     }
 
-    #[inline(always)]
-    fn sort_via<F>(&mut self, f: F)
+    /// Returns an iterator over the runs of consecutive rows with equal keys, yielding each key
+    /// along with the range of rows it covers.
+    ///
+    /// When the slice is sorted by the key, this yields every distinct key exactly once, and
+    /// the ranges can be used as per-group offsets. The key of each row is computed exactly
+    /// once.
+    pub fn runs_by_key<K, F>(&self, f: F) -> RunsByKey<'_, Param, K, F>
     where
-        F: FnOnce(&mut Vec<usize>),
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: PartialEq,
     {
-        if self.len < 2 {
-            return;
+        RunsByKey {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            idx: 0,
+            next_key: None,
+            back_key: None,
+            f,
+            _marker: PhantomData,
         }
+    }
 
-        let mut indices: Vec<usize> = (0..self.len).collect();
-        f(&mut indices);
-
-        // SAFE: All of the indices used here are valid.
-        unsafe {
-            for src in 0..self.len {
-                let dst = *indices.get_unchecked(src);
-                if src == dst {
-                    continue;
-                }
-                self.swap_unchecked(src, dst);
-                // TODO: Use swap_unchecked here when stablized.
-                indices.swap(src, dst);
-            }
-        }
+    /// Computes the prefix scan of the `N`th column with the associative
+    /// operation `op`, i.e. prefix sums when `op` is addition.
+    ///
+    /// The output has one value per row. See [`Scan`] for the difference
+    /// between inclusive and exclusive scans.
+    pub fn scan_column<const N: usize, F>(
+        &self,
+        scan: Scan<<Param as ParallelColumn<N>>::Column>,
+        op: F,
+    ) -> Vec<<Param as ParallelColumn<N>>::Column>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy,
+        F: Fn(
+            <Param as ParallelColumn<N>>::Column,
+            <Param as ParallelColumn<N>>::Column,
+        ) -> <Param as ParallelColumn<N>>::Column,
+    {
+        crate::scan::scan(self.column::<N>(), scan, op)
     }
 
-    /// Returns a raw pointer to the slice’s buffer.
+    /// Computes the prefix scan of the `N`th column in parallel.
     ///
-    /// The caller must ensure that the slice outlives the pointer this function returns, or else it will end up pointing
-    /// to garbage.
+    /// `op` must be associative, as the column is split into chunks that are
+    /// combined in a different order than a sequential scan. Otherwise, this
+    /// produces the same results as [`scan_column`].
     ///
-    /// Modifying the container referenced by this slice may cause its buffer to be reallocated, which would also make any
-    /// pointers to it invalid.
-    #[inline]
-    pub fn as_mut_ptrs(&mut self) -> Param::Ptr {
-        Param::as_ptr(self.storage)
+    /// [`scan_column`]: Self::scan_column
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn par_scan_column<const N: usize, F>(
+        &self,
+        scan: Scan<<Param as ParallelColumn<N>>::Column>,
+        op: F,
+    ) -> Vec<<Param as ParallelColumn<N>>::Column>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy + Send + Sync,
+        F: Fn(
+                <Param as ParallelColumn<N>>::Column,
+                <Param as ParallelColumn<N>>::Column,
+            ) -> <Param as ParallelColumn<N>>::Column
+            + Sync,
+    {
+        crate::scan::par_scan(self.column::<N>(), scan, op)
     }
 
-    /// Gets the individual slices for every sub-slice.
+    /// Gets a view of the `LANE`th element of every array in the `COL`th column.
+    ///
+    /// Array columns are stored one array after another, so the view is strided
+    /// by the length of the array rather than being a contiguous slice.
+    ///
+    /// Fails to compile if `LANE` is out of bounds for the array type.
     #[inline]
-    pub fn as_slices(&self) -> Param::Slices<'_> {
-        unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
+    pub fn column_lane<const COL: usize, const LANE: usize>(
+        &self,
+    ) -> Lane<'_, <Param as ParallelColumn<COL>>::Column>
+    where
+        Param: ParallelColumn<COL>,
+        <Param as ParallelColumn<COL>>::Column: ArrayColumn,
+    {
+        const {
+            assert!(LANE < <<Param as ParallelColumn<COL>>::Column as ArrayColumn>::LANES);
+        }
+        Lane {
+            rows: self.column::<COL>(),
+            lane: LANE,
+        }
     }
 
-    /// Gets mutable individual slices for every sub-slice.
-    #[inline]
-    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
-        unsafe { Param::as_slices_mut(self.as_mut_ptrs(), self.len) }
+    /// Returns a [`Rolling`] window helper over the rows of the [`ParallelSliceMut`].
+    ///
+    /// # Panics
+    /// This function will panic if `window` is 0.
+    pub fn rolling(&self, window: usize) -> Rolling<'_, Param> {
+        assert!(window != 0, "window size must be non-zero");
+        Rolling {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            window,
+            _marker: PhantomData,
+        }
     }
 
-    /// Swaps two elements.
+    /// Randomly samples `k` indices with replacement, where the probability of
+    /// selecting a row is proportional to the weight returned by `weight`.
     ///
-    /// # Arguments
-    ///  - `a` - The index of the first element
-    ///  - `b` - The index of the second element
+    /// This builds an alias table in `O(N)` time, after which every sample
+    /// takes `O(1)` time.
     ///
-    /// # Panics
-    /// Panics if a or b are out of bounds.
-    pub fn swap(&mut self, a: usize, b: usize) {
-        assert_in_bounds(a, self.len);
-        assert_in_bounds(b, self.len);
-        unsafe { self.swap_unchecked(a, b) }
+    /// The rows passed to `weight` are only borrowed for the duration of the
+    /// call, and cannot be kept:
+    ///
+    /// ```compile_fail
+    /// # use parallel_vec::ParallelVec;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let mut src = ParallelVec::from(vec![(1.0, String::from("a"))]);
+    /// let mut names: Vec<&'static String> = Vec::new();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let _ = src.sample_weighted(&mut rng, 1, |(weight, name)| {
+    ///     names.push(name);
+    ///     *weight
+    /// });
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the slice is empty, any weight is negative or not
+    /// finite, or all of the weights are zero.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sample_weighted<R, F>(
+        &self,
+        rng: &mut R,
+        k: usize,
+        weight: F,
+    ) -> Result<Vec<usize>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(Param::Ref<'_>) -> f64,
+    {
+        let rows: Iter<'_, Param> = Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        };
+        sample::sample_weighted(rows, rng, k, weight)
     }
 
-    /// Swaps two elements in the slice, without doing bounds checking.
+    /// Randomly samples `k` rows with replacement, where the probability of
+    /// selecting a row is proportional to the weight returned by `weight`,
+    /// and gathers clones of them into a new [`ParallelVec`].
     ///
-    /// For a safe alternative see [`swap`].
+    /// See [`sample_weighted`] for details.
     ///
-    /// # Arguments
-    ///  - `a` - The index of the first element
-    ///  - `b` - The index of the second element
+    /// [`ParallelVec`]: crate::ParallelVec
+    /// [`sample_weighted`]: Self::sample_weighted
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sample_weighted_rows<R, F>(
+        &self,
+        rng: &mut R,
+        k: usize,
+        weight: F,
+    ) -> Result<ParallelVec<Param>, WeightedError>
+    where
+        Param: Clone,
+        R: Rng + ?Sized,
+        F: FnMut(Param::Ref<'_>) -> f64,
+    {
+        let indices = self.sample_weighted(rng, k, weight)?;
+        Ok(self.gather(&indices))
+    }
+
+    /// Randomly samples `k` indices with replacement, using the values of the
+    /// `N`th column as weights.
     ///
-    /// # Safety
-    /// Calling this method with an out-of-bounds index is undefined behavior.
-    /// The caller has to ensure that `a < self.len()` and `b < self.len()`.
+    /// See [`sample_weighted`] for details.
     ///
-    /// [`swap`]: Self::swap
-    #[inline]
-    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
-        let base = Param::as_ptr(self.storage);
-        Param::swap(Param::add(base, a), Param::add(base, b));
+    /// [`sample_weighted`]: Self::sample_weighted
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn choose_weighted_by_column<const N: usize, R>(
+        &self,
+        rng: &mut R,
+        k: usize,
+    ) -> Result<Vec<usize>, WeightedError>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Copy + Into<f64>,
+        R: Rng + ?Sized,
+    {
+        let weights = self.column::<N>().iter().map(|weight| (*weight).into());
+        let table = sample::AliasTable::new(weights)?;
+        Ok((0..k).map(|_| table.sample(rng)).collect())
     }
 
-    /// Reverses the order of elements in the [`ParallelSliceMut`], in place.
-    ///
-    /// This is a `O(n)` operation.
-    pub fn reverse(&mut self) {
-        if self.len == 0 {
-            return;
+    /// Gets the individual columns as fixed-size arrays, or `None` if the
+    /// length of the slice is not exactly `N`.
+    #[inline]
+    pub fn as_arrays<const N: usize>(&self) -> Option<Param::Arrays<'_, N>> {
+        if self.len != N {
+            return None;
         }
-        Param::reverse(self.as_slices_mut())
+        unsafe { Some(Param::as_arrays(Param::as_ptr(self.storage))) }
     }
 
-    /// Swaps all elements in `self` with those in `other`.
+    /// Gets the bytes of every column as [`IoSlice`]s, in column order.
     ///
-    /// The length of other must be the same as `self`.  
+    /// The result can be passed to [`Write::write_vectored`] to write the
+    /// entire slice without first copying it into a single buffer.
     ///
-    /// # Panics
+    /// [`IoSlice`]: std::io::IoSlice
+    /// [`Write::write_vectored`]: std::io::Write::write_vectored
+    #[cfg(all(feature = "std", feature = "bytemuck"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "bytemuck"))))]
+    #[inline]
+    pub fn as_io_slices(&self) -> Param::IoSlices<'_>
+    where
+        Param: ParallelPod,
+    {
+        Param::io_slices(self.as_slices())
+    }
+
+    /// Computes a deterministic checksum over the length and the values of
+    /// every column.
     ///
-    /// This function will panic if the two slices have different lengths.
-    pub fn swap_with(&mut self, other: &mut Self) {
-        if self.len != other.len {
-            panic!(
-                "Attempted to use swap_with with slices of different lenghths: {} vs {}",
-                self.len, other.len
-            )
-        }
-        unsafe {
-            let mut a = self.as_mut_ptrs();
-            let mut b = other.as_mut_ptrs();
-            for _ in 0..self.len {
-                Param::swap(a, b);
-                a = Param::add(a, 1);
-                b = Param::add(b, 1);
-            }
-        }
+    /// Each column is hashed as a whole, which is much faster than hashing the
+    /// slice row by row. Values are hashed as little-endian bytes, so for
+    /// columns of primitive numbers the checksum is stable across releases and
+    /// platforms, making it suitable for verifying that replicated copies of
+    /// the data are identical. For columns of other plain old data, such as
+    /// structs or arrays, it is only stable across platforms with the same
+    /// endianness. See [`ParallelPod::hash_columns_le`].
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn checksum(&self) -> u64
+    where
+        Param: ParallelPod,
+    {
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_u64(self.len as u64);
+        Param::hash_columns_le(self.as_slices(), &mut hasher);
+        hasher.finish()
     }
 
-    /// Returns an iterator over the [`ParallelSliceMut`].
-    pub fn iter(&self) -> Iter<'a, Param> {
-        Iter {
-            ptr: Param::as_ptr(self.storage),
-            remaining: self.len,
-            _marker: PhantomData,
+    /// Computes a deterministic checksum over the length and the [`Hash`]
+    /// of every row.
+    ///
+    /// This supports columns that are not plain old data, but is slower than
+    /// [`checksum`]. The checksum is stable across releases and platforms as
+    /// long as the [`Hash`] implementations of the columns are.
+    ///
+    /// [`checksum`]: Self::checksum
+    pub fn checksum_by_rows(&self) -> u64
+    where
+        for<'r> Param::Ref<'r>: Hash,
+    {
+        let mut hasher = ChecksumHasher::new();
+        hasher.write_u64(self.len as u64);
+        for row in self.iter() {
+            row.hash(&mut hasher);
         }
+        hasher.finish()
     }
 
-    /// Returns an iterator that allows modifying each value.
-    pub fn iter_mut(&mut self) -> IterMut<'a, Param> {
-        IterMut {
-            ptr: self.as_mut_ptrs(),
-            remaining: self.len,
+    /// Returns a lazy [`ParallelView`] over the rows of the [`ParallelSliceMut`].
+    pub fn view(&self) -> ParallelView<'_, Param, fn(&Param::Ref<'_>) -> bool> {
+        ParallelView {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            predicate: |_| true,
             _marker: PhantomData,
         }
     }
@@ -595,34 +2364,90 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 }
 
 impl<'a, Param: ParallelParam + Clone> ParallelSliceMut<'a, Param> {
-    /// Fills self with elements by cloning value.
-    #[inline(always)]
+    /// Fills self with elements by cloning value, dropping the previous
+    /// values in place.
+    ///
+    /// The last row takes `value` itself instead of a clone.
     pub fn fill(&mut self, value: Param) {
-        self.fill_with(|| value.clone());
+        if let Some(last) = self.len.checked_sub(1) {
+            for idx in 0..last {
+                // SAFE: idx is in bounds.
+                unsafe { self.set_unchecked(idx, value.clone()) };
+            }
+            // SAFE: last is in bounds.
+            unsafe { self.set_unchecked(last, value) };
+        }
+    }
+
+    /// Overwrites the rows in `range` with clones of the rows given as one
+    /// slice per column, dropping the previous values in place.
+    ///
+    /// The rest of the slice is left untouched.
+    ///
+    /// # Panics
+    /// This function will panic if the range is decreasing or out of bounds,
+    /// or if any of the slices does not have the same length as the range.
+    pub fn clone_from_slices(&mut self, range: impl RangeBounds<usize>, slices: Param::Slices<'_>) {
+        let Range { start, end } = resolve_range(range, self.len);
+        assert_eq!(
+            Param::get_slices_len(&slices),
+            Some(end - start),
+            "source slices must all have the same length as the destination range"
+        );
+        let src = Param::get_slices_ptrs(slices);
+        for idx in 0..end - start {
+            // SAFE: The source row is initialized. The destination is in bounds.
+            unsafe {
+                let row = clone_row::<Param>(Param::add(src, idx));
+                self.set_unchecked(start + idx, row);
+            }
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam + Copy> ParallelSliceMut<'a, Param> {
+    /// Copies the rows in `src` to another part of the slice, starting at
+    /// `dest`. The two ranges may overlap.
+    ///
+    /// Each column is moved with a single `memmove`.
+    ///
+    /// # Panics
+    /// This function will panic if `src` is decreasing or out of bounds, or if
+    /// the end of the destination range exceeds the length of the slice.
+    pub fn copy_within(&mut self, src: impl RangeBounds<usize>, dest: usize) {
+        let Range { start, end } = resolve_range(src, self.len);
+        let count = end - start;
+        assert!(dest <= self.len - count, "dest is out of bounds");
+        // SAFE: Both ranges are in bounds, and the rows are Copy, so they can
+        // be overwritten and duplicated bitwise.
+        unsafe {
+            let base = self.as_mut_ptrs();
+            Param::copy_to(Param::add(base, start), Param::add(base, dest), count);
+        }
     }
 }
 
 impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
-    /// Fills self with elements returned by calling a closure repeatedly.
+    /// Fills self with elements returned by calling a closure repeatedly,
+    /// dropping the previous values in place.
     ///
     /// This method uses a closure to create new values. If you’d rather [`Clone`]
     /// a given value, use fill. If you want to use the [`Default`] trait to generate
     /// values, you can pass `Default::default` as the argument.
     pub fn fill_with<F: FnMut() -> Param>(&mut self, mut f: F) {
-        unsafe {
-            let base = self.as_mut_ptrs();
-            for idx in 0..self.len {
-                Param::write(Param::add(base, idx), f());
-            }
+        for idx in 0..self.len {
+            let value = f();
+            // SAFE: idx is in bounds. The new value is created before the old
+            // one is dropped, so a panic in f leaves every row initialized.
+            unsafe { self.set_unchecked(idx, value) };
         }
     }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSliceMut<'s, Param>
+impl<'s, Param> Hash for ParallelSliceMut<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -635,21 +2460,190 @@ where
     }
 }
 
+impl<'s, 'b, Param> PartialEq<&'b [Param]> for ParallelSliceMut<'s, Param>
+where
+    Param: ParallelParam + 's,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &&'b [Param]) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
+impl<'s, Param> PartialEq<Vec<Param>> for ParallelSliceMut<'s, Param>
+where
+    Param: ParallelParam + 's,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &Vec<Param>) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
+fn float_extreme<F: FloatColumn>(column: &[F], ordering: Ordering) -> Option<(usize, F)> {
+    column
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, value)| !value.is_nan())
+        .reduce(|best, next| {
+            if next.1.total_cmp(&best.1) == ordering {
+                next
+            } else {
+                best
+            }
+        })
+}
+
+fn binary_search_by<Param, F>(ptr: Param::Ptr, len: usize, mut f: F) -> Result<usize, usize>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>) -> Ordering,
+{
+    let mut left = 0;
+    let mut right = len;
+    while left < right {
+        let mid = left + (right - left) / 2;
+        // SAFE: mid is always within left..right, which is within the slice.
+        match f(unsafe { Param::as_ref(Param::add(ptr, mid)) }) {
+            Ordering::Less => left = mid + 1,
+            Ordering::Greater => right = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(left)
+}
+
+pub(crate) fn partition_point<Param, P>(ptr: Param::Ptr, len: usize, mut pred: P) -> usize
+where
+    Param: ParallelParam,
+    P: FnMut(Param::Ref<'_>) -> bool,
+{
+    binary_search_by::<Param, _>(ptr, len, |row| {
+        if pred(row) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    })
+    .unwrap_or_else(|idx| idx)
+}
+
+fn is_sorted_by<Param, F>(ptr: Param::Ptr, len: usize, mut compare: F) -> bool
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    // SAFE: Both idx - 1 and idx are always within the slice.
+    (1..len).all(|idx| unsafe {
+        compare(
+            Param::as_ref(Param::add(ptr, idx - 1)),
+            Param::as_ref(Param::add(ptr, idx)),
+        )
+    })
+}
+
+fn gather<Param>(ptr: Param::Ptr, len: usize, indices: &[usize]) -> ParallelVec<Param>
+where
+    Param: ParallelParam + Clone,
+{
+    for &idx in indices {
+        assert_in_bounds(idx, len);
+    }
+    let mut gathered = ParallelVec::with_capacity(indices.len());
+    for &idx in indices {
+        // SAFE: The index is in bounds.
+        gathered.push(unsafe { clone_row::<Param>(Param::add(ptr, idx)) });
+    }
+    gathered
+}
+
+/// Clones the row at `ptr` without taking ownership of it.
+///
+/// # Safety
+/// `ptr` must point to an initialized row.
+pub(crate) unsafe fn clone_row<Param>(ptr: Param::Ptr) -> Param
+where
+    Param: ParallelParam + Clone,
+{
+    // The bitwise copy is only ever borrowed, and is never moved or dropped, so
+    // ownership of the values stays with the source row.
+    let mut row = MaybeUninit::<Param>::uninit();
+    Param::into_rows(ptr, row.as_mut_ptr(), 1);
+    (*row.as_ptr()).clone()
+}
+
+fn map_rows<Param, New, F>(ptr: Param::Ptr, len: usize, mut f: F) -> ParallelVec<New>
+where
+    Param: ParallelParam,
+    New: ParallelParam,
+    F: FnMut(Param::Ref<'_>) -> New,
+{
+    match try_map_rows::<Param, _, Infallible, _>(ptr, len, |row| Ok(f(row))) {
+        Ok(mapped) => mapped,
+        Err(never) => match never {},
+    }
+}
+
+fn try_map_rows<Param, New, E, F>(
+    ptr: Param::Ptr,
+    len: usize,
+    mut f: F,
+) -> Result<ParallelVec<New>, E>
+where
+    Param: ParallelParam,
+    New: ParallelParam,
+    F: FnMut(Param::Ref<'_>) -> Result<New, E>,
+{
+    let mut mapped = ParallelVec::with_capacity(len);
+    for idx in 0..len {
+        // SAFE: The index is in bounds of both the source and the capacity of the output,
+        // and the length is only extended once the row has been written, so only the
+        // written rows are dropped if `f` fails or panics.
+        unsafe {
+            let value = f(Param::as_ref(Param::add(ptr, idx)))?;
+            New::write(New::add(mapped.as_mut_ptrs(), idx), value);
+            mapped.set_len(idx + 1);
+        }
+    }
+    Ok(mapped)
+}
+
+/// Compares the rows of a [`ParallelSlice`] with a slice of rows.
+pub(crate) fn rows_eq<'r, Param>(rows: Iter<'r, Param>, other: &'r [Param]) -> bool
+where
+    Param: ParallelParam,
+    Param::Ref<'r>: PartialEq,
+{
+    rows.len() == other.len() && rows.zip(other).all(|(a, b)| a == Param::row_ref(b))
+}
+
+/// Indexes into a slice. The output may only borrow from the slice for as
+/// long as the slice itself is borrowed.
 pub trait ParallelSliceIndex<T> {
-    type Output;
-    fn get(self, slice: &T) -> Option<Self::Output>;
-    fn index(self, slice: &T) -> Self::Output;
+    type Output<'b>
+    where
+        T: 'b;
+    fn get(self, slice: &T) -> Option<Self::Output<'_>>;
+    fn index(self, slice: &T) -> Self::Output<'_>;
 }
 
+/// Mutably indexes into a slice. The output may only borrow from the slice
+/// for as long as the slice itself is mutably borrowed.
 pub trait ParallelSliceIndexMut<T> {
-    type Output;
-    fn get_mut(self, slice: &mut T) -> Option<Self::Output>;
-    fn index_mut(self, slice: &mut T) -> Self::Output;
+    type Output<'b>
+    where
+        T: 'b;
+    fn get_mut(self, slice: &mut T) -> Option<Self::Output<'_>>;
+    fn index_mut(self, slice: &mut T) -> Self::Output<'_>;
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for usize {
-    type Output = Param::Ref<'s>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = Param::Ref<'s>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
         if self >= slice.len {
             return None;
         }
@@ -657,15 +2651,18 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         unsafe { Some(Param::as_ref(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_ref(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for usize {
-    type Output = Param::Ref<'s>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = Param::Ref<'b>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         if self >= slice.len {
             return None;
         }
@@ -673,15 +2670,18 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         unsafe { Some(Param::as_ref(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_ref(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for usize {
-    type Output = Param::RefMut<'s>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = Param::RefMut<'b>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         if self >= slice.len {
             return None;
         }
@@ -689,16 +2689,19 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         unsafe { Some(Param::as_mut(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_mut(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for Range<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
+    type Output<'b>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
+        if self.start > self.end || self.end > slice.len {
             return None;
         }
 
@@ -711,20 +2714,22 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         }
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
+        let Range { start, end } = resolve_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSlice::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), end - start)
         }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for Range<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
+    type Output<'b>
+        = ParallelSlice<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
+        if self.start > self.end || self.end > slice.len {
             return None;
         }
 
@@ -737,20 +2742,22 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         }
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
+        let Range { start, end } = resolve_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSlice::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), end - start)
         }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for Range<usize> {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
+    type Output<'b>
+        = ParallelSliceMut<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
+        if self.start > self.end || self.end > slice.len {
             return None;
         }
 
@@ -763,12 +2770,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         }
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
+        let Range { start, end } = resolve_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, start);
+            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), end - start)
         }
     }
 }
@@ -776,8 +2782,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -785,7 +2794,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>>
         range.get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -797,8 +2806,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>>
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -806,7 +2818,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
         range.get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -818,8 +2830,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSliceMut<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -828,7 +2843,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         range.get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         let range = Range {
             start: *self.start(),
             end: *self.end() + 1,
@@ -838,8 +2853,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeTo<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: self.end,
@@ -847,7 +2865,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: self.end,
@@ -857,8 +2875,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for RangeTo<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: self.end,
@@ -866,7 +2887,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: self.end,
@@ -878,8 +2899,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeTo<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSliceMut<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: self.end,
@@ -887,7 +2911,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut<'a>(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: self.end,
@@ -897,8 +2921,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeFrom<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -906,7 +2933,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
         Range {
             start: self.start,
             end: slice.len,
@@ -918,8 +2945,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
     for RangeFrom<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -927,7 +2957,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: self.start,
             end: slice.len,
@@ -939,8 +2969,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeFrom<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSliceMut<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -948,7 +2981,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: self.start,
             end: slice.len,
@@ -958,8 +2991,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeFull {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: slice.len,
@@ -967,7 +3003,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSlice<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: slice.len,
@@ -977,8 +3013,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for RangeFull {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSlice<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: slice.len,
@@ -986,7 +3025,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'b>(self, slice: &'b ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: slice.len,
@@ -996,8 +3035,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for RangeFull {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'b>
+        = ParallelSliceMut<'b, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'b;
+    fn get_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'b>> {
         Range {
             start: 0,
             end: slice.len,
@@ -1005,7 +3047,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'b>(self, slice: &'b mut ParallelSliceMut<'s, Param>) -> Self::Output<'b> {
         Range {
             start: 0,
             end: slice.len,
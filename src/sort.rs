@@ -0,0 +1,452 @@
+use crate::{ParallelParam, ParallelSliceMut};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Slices at most this long are sorted with insertion sort.
+const INSERTION_THRESHOLD: usize = 20;
+
+/// A stable lexicographic sort over multiple keys.
+///
+/// Rows are ordered by the first key, then rows with equal first keys are
+/// ordered by the second key, and so on. Later keys are only extracted to
+/// break ties, and no intermediate rows are materialized.
+///
+/// This is created by [`ParallelSliceMut::sort_by_keys`], and the slice is
+/// sorted once [`sort`](Self::sort) is called.
+#[must_use = "the slice is only sorted once `sort` is called"]
+pub struct SortByKeys<'s, 'a, Param: ParallelParam, C> {
+    slice: &'s mut ParallelSliceMut<'a, Param>,
+    // Only ever called with pointers to rows within the slice.
+    compare: C,
+}
+
+impl<'s, 'a, Param, C> SortByKeys<'s, 'a, Param, C>
+where
+    Param: ParallelParam,
+    C: FnMut(Param::Ptr, Param::Ptr) -> Ordering,
+{
+    pub(crate) fn new(slice: &'s mut ParallelSliceMut<'a, Param>, compare: C) -> Self {
+        Self { slice, compare }
+    }
+
+    /// Adds a key to break ties between rows that are equal on every
+    /// previous key.
+    pub fn then_by<K, F>(
+        self,
+        mut f: F,
+    ) -> SortByKeys<'s, 'a, Param, impl FnMut(Param::Ptr, Param::Ptr) -> Ordering>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let mut compare = self.compare;
+        SortByKeys {
+            slice: self.slice,
+            compare: move |a, b| {
+                // SAFE: The comparator is only called with pointers to rows
+                // within the slice.
+                compare(a, b).then_with(|| unsafe { f(Param::as_ref(a)).cmp(&f(Param::as_ref(b))) })
+            },
+        }
+    }
+
+    /// Sorts the slice by all of the keys.
+    ///
+    /// This sort is stable, and like [`ParallelSliceMut::sort_by`] allocates
+    /// `sizeof(usize) * self.len` bytes as an intermediate sorting buffer.
+    pub fn sort(self) {
+        self.slice.sort_by_ptrs(self.compare);
+    }
+}
+
+/// Sorts the `len` rows starting at `ptr` in place, exchanging rows with
+/// [`ParallelParam::swap`] and never allocating.
+///
+/// This is an introsort: a quicksort with median-of-three pivots and a
+/// balanced partition for runs of equal rows, which falls back to heapsort
+/// once the recursion gets too deep, so it is `O(N * log(N))` in the worst
+/// case. Every step leaves the rows as a permutation of the input, so a
+/// panicking comparator cannot leave the slice in an invalid state.
+///
+/// # Safety
+/// `ptr` must point to `len` initialized rows that are valid for reads and
+/// writes.
+pub(crate) unsafe fn sort_unstable_by<Param, F>(ptr: Param::Ptr, len: usize, is_less: &mut F)
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    let limit = usize::BITS - len.leading_zeros();
+    quicksort::<Param, F>(ptr, len, is_less, 2 * limit);
+}
+
+/// Reorders the `len` rows starting at `ptr` in place so that the row at
+/// `index` is the one that would be there if the rows were sorted, with every
+/// row before it not greater than it and every row after it not less than it.
+///
+/// This is a quickselect using the same partitioning as [`sort_unstable_by`],
+/// falling back to heapsort on the remaining rows if it degrades, so it is
+/// `O(N)` on average and `O(N * log(N))` in the worst case.
+///
+/// # Safety
+/// `ptr` must point to `len` initialized rows that are valid for reads and
+/// writes, and `index` must be less than `len`.
+pub(crate) unsafe fn select_nth_unstable_by<Param, F>(
+    mut ptr: Param::Ptr,
+    mut len: usize,
+    mut index: usize,
+    is_less: &mut F,
+) where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    let mut limit = 2 * (usize::BITS - len.leading_zeros());
+    loop {
+        if len <= INSERTION_THRESHOLD {
+            insertion_sort::<Param, F>(ptr, len, is_less);
+            return;
+        }
+        if limit == 0 {
+            heapsort::<Param, F>(ptr, len, is_less);
+            return;
+        }
+        limit -= 1;
+
+        let mid = partition::<Param, F>(ptr, len, is_less);
+        match index.cmp(&mid) {
+            Ordering::Equal => return,
+            Ordering::Less => len = mid,
+            Ordering::Greater => {
+                ptr = Param::add(ptr, mid + 1);
+                len -= mid + 1;
+                index -= mid + 1;
+            }
+        }
+    }
+}
+
+/// Stably sorts `indices` by `keys[idx]` with a least significant digit radix
+/// sort over the low `bytes` bytes of each key.
+///
+/// The histograms for every digit are built in a single pass over the keys,
+/// and digits that are the same for every key are skipped entirely.
+pub(crate) fn radix_sort_indices(indices: &mut Vec<usize>, keys: &[u64], bytes: usize) {
+    let mut counts = alloc::vec![[0usize; 256]; bytes];
+    for key in keys {
+        for (digit, count) in counts.iter_mut().enumerate() {
+            count[((key >> (digit * 8)) & 0xFF) as usize] += 1;
+        }
+    }
+
+    let mut scratch = alloc::vec![0; indices.len()];
+    for (digit, count) in counts.iter_mut().enumerate() {
+        if count.contains(&indices.len()) {
+            continue;
+        }
+        let mut offset = 0;
+        for slot in count.iter_mut() {
+            offset += core::mem::replace(slot, offset);
+        }
+        for &idx in indices.iter() {
+            let slot = &mut count[((keys[idx] >> (digit * 8)) & 0xFF) as usize];
+            scratch[*slot] = idx;
+            *slot += 1;
+        }
+        core::mem::swap(indices, &mut scratch);
+    }
+}
+
+/// Reorders the rows starting at `ptr` so that the row at `indices[dst]` ends
+/// up at `dst`, for every `dst`.
+///
+/// The permutation is applied by following each of its cycles, moving every
+/// row of every column at most once: the first row of a cycle is read out,
+/// every other row is moved into the hole left by the previous one, and the
+/// first row is written into the final hole. `swap` is called with the same
+/// sequence of exchanges that would apply the permutation with swaps, so
+/// that it can be mirrored onto other storage.
+///
+/// On return, every element of `indices` is equal to its own index.
+///
+/// # Safety
+/// `ptr` must point to `indices.len()` initialized rows that are valid for
+/// reads and writes, and `indices` must be a permutation of
+/// `0..indices.len()`.
+pub(crate) unsafe fn apply_permutation<Param, S>(
+    ptr: Param::Ptr,
+    indices: &mut [usize],
+    mut swap: S,
+) where
+    Param: ParallelParam,
+    S: FnMut(usize, usize),
+{
+    // Visited slots are marked as in place by setting `indices[dst] = dst`.
+    for start in 0..indices.len() {
+        if *indices.get_unchecked(start) == start {
+            continue;
+        }
+        // No user code runs until the cycle is closed, so the hole is always
+        // filled before anything can observe it.
+        let first = Param::read(Param::add(ptr, start));
+        let mut dst = start;
+        loop {
+            let src = core::mem::replace(indices.get_unchecked_mut(dst), dst);
+            if src == start {
+                Param::write(Param::add(ptr, dst), first);
+                break;
+            }
+            Param::copy_to_nonoverlapping(Param::add(ptr, src), Param::add(ptr, dst), 1);
+            swap(dst, src);
+            dst = src;
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn less<Param, F>(ptr: Param::Ptr, a: usize, b: usize, is_less: &mut F) -> bool
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    is_less(
+        Param::as_ref(Param::add(ptr, a)),
+        Param::as_ref(Param::add(ptr, b)),
+    )
+}
+
+#[inline(always)]
+unsafe fn swap<Param: ParallelParam>(ptr: Param::Ptr, a: usize, b: usize) {
+    Param::swap(Param::add(ptr, a), Param::add(ptr, b));
+}
+
+unsafe fn quicksort<Param, F>(mut ptr: Param::Ptr, mut len: usize, is_less: &mut F, mut limit: u32)
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    loop {
+        if len <= INSERTION_THRESHOLD {
+            insertion_sort::<Param, F>(ptr, len, is_less);
+            return;
+        }
+        if limit == 0 {
+            heapsort::<Param, F>(ptr, len, is_less);
+            return;
+        }
+        limit -= 1;
+
+        let mid = partition::<Param, F>(ptr, len, is_less);
+        // Recurse into the shorter side and loop on the longer one to bound
+        // the stack depth.
+        let right = Param::add(ptr, mid + 1);
+        let right_len = len - mid - 1;
+        if mid < right_len {
+            quicksort::<Param, F>(ptr, mid, is_less, limit);
+            ptr = right;
+            len = right_len;
+        } else {
+            quicksort::<Param, F>(right, right_len, is_less, limit);
+            len = mid;
+        }
+    }
+}
+
+/// Partitions the rows around a median-of-three pivot, returning the final
+/// index of the pivot. Rows equal to the pivot may end up on either side, so
+/// runs of equal rows are split evenly.
+unsafe fn partition<Param, F>(ptr: Param::Ptr, len: usize, is_less: &mut F) -> usize
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    let (a, b, c) = (len / 4, len / 2, len / 4 * 3);
+    if less::<Param, F>(ptr, b, a, is_less) {
+        swap::<Param>(ptr, a, b);
+    }
+    if less::<Param, F>(ptr, c, b, is_less) {
+        swap::<Param>(ptr, b, c);
+        if less::<Param, F>(ptr, b, a, is_less) {
+            swap::<Param>(ptr, a, b);
+        }
+    }
+    swap::<Param>(ptr, 0, b);
+
+    // The pivot stays at index 0 until the end.
+    let mut left = 1;
+    let mut right = len - 1;
+    loop {
+        while left <= right && less::<Param, F>(ptr, left, 0, is_less) {
+            left += 1;
+        }
+        while left <= right && less::<Param, F>(ptr, 0, right, is_less) {
+            right -= 1;
+        }
+        if left >= right {
+            break;
+        }
+        swap::<Param>(ptr, left, right);
+        left += 1;
+        right -= 1;
+    }
+    swap::<Param>(ptr, 0, right);
+    right
+}
+
+unsafe fn insertion_sort<Param, F>(ptr: Param::Ptr, len: usize, is_less: &mut F)
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    for idx in 1..len {
+        let mut cur = idx;
+        while cur > 0 && less::<Param, F>(ptr, cur, cur - 1, is_less) {
+            swap::<Param>(ptr, cur, cur - 1);
+            cur -= 1;
+        }
+    }
+}
+
+unsafe fn heapsort<Param, F>(ptr: Param::Ptr, len: usize, is_less: &mut F)
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    let mut sift_down = |mut node: usize, end: usize| loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end && less::<Param, F>(ptr, child, child + 1, is_less) {
+            child += 1;
+        }
+        if !less::<Param, F>(ptr, node, child, is_less) {
+            break;
+        }
+        swap::<Param>(ptr, node, child);
+        node = child;
+    };
+    for node in (0..len / 2).rev() {
+        sift_down(node, len);
+    }
+    for end in (1..len).rev() {
+        swap::<Param>(ptr, 0, end);
+        sift_down(0, end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_permutation, radix_sort_indices, select_nth_unstable_by, sort_unstable_by};
+    use crate::ParallelVec;
+    use std::{string::String, vec::Vec};
+
+    fn check(keys: Vec<u32>, limit: Option<u32>) {
+        let mut src: ParallelVec<_> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let mut is_less = |(a, _): (&u32, &usize), (b, _): (&u32, &usize)| a < b;
+        let len = src.len();
+        unsafe {
+            match limit {
+                Some(limit) => {
+                    super::quicksort::<(u32, usize), _>(src.as_mut_ptrs(), len, &mut is_less, limit)
+                }
+                None => sort_unstable_by::<(u32, usize), _>(src.as_mut_ptrs(), len, &mut is_less),
+            }
+        }
+        let mut expected = keys.clone();
+        expected.sort_unstable();
+        let (sorted, ids) = src.as_slices();
+        assert_eq!(sorted, expected.as_slice());
+        // Every row must have been moved as a whole.
+        assert!(sorted.iter().zip(ids).all(|(key, id)| keys[*id] == *key));
+        let mut ids = ids.to_vec();
+        ids.sort_unstable();
+        assert!(ids.iter().copied().eq(0..keys.len()));
+    }
+
+    #[test]
+    fn test_sort_patterns() {
+        for len in [0, 1, 2, 3, 20, 21, 100, 1000] {
+            check((0..len).collect(), None);
+            check((0..len).rev().collect(), None);
+            check(alloc::vec![7; len as usize], None);
+            check(
+                (0..len).map(|x| x.wrapping_mul(2654435761) % 13).collect(),
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn test_heapsort_fallback() {
+        check(
+            (0..500)
+                .map(|x: u32| x.wrapping_mul(2654435761) % 97)
+                .collect(),
+            Some(0),
+        );
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let names = ["a", "b", "c", "d", "e", "f"];
+        let mut src: ParallelVec<_> = names.iter().map(|n| (String::from(*n), *n)).collect();
+        let mut companion = names;
+        let mut indices = [2, 0, 1, 3, 5, 4];
+        let len = src.len();
+        unsafe {
+            apply_permutation::<(String, &str), _>(src.as_mut_ptrs(), &mut indices, |a, b| {
+                companion.swap(a, b)
+            });
+        }
+        let expected = ["c", "a", "b", "d", "f", "e"];
+        assert_eq!(indices, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(companion, expected);
+        assert_eq!(src.len(), len);
+        assert!(src
+            .iter()
+            .map(|(a, b)| (a.as_str(), *b))
+            .eq(expected.iter().map(|n| (*n, *n))));
+    }
+
+    #[test]
+    fn test_select_nth() {
+        let keys: Vec<u32> = (0..300)
+            .map(|x: u32| x.wrapping_mul(2654435761) % 41)
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        for index in [0, 1, 20, 150, 299] {
+            let mut src: ParallelVec<_> = keys.iter().map(|k| (*k, ())).collect();
+            let len = src.len();
+            unsafe {
+                select_nth_unstable_by::<(u32, ()), _>(
+                    src.as_mut_ptrs(),
+                    len,
+                    index,
+                    &mut |a, b| a.0 < b.0,
+                );
+            }
+            let (column, _) = src.as_slices();
+            assert_eq!(column[index], sorted[index]);
+            assert!(column[..index].iter().all(|k| *k <= sorted[index]));
+            assert!(column[index + 1..].iter().all(|k| *k >= sorted[index]));
+        }
+    }
+
+    #[test]
+    fn test_radix_sort_indices() {
+        let keys: Vec<u64> = (0..1000u64)
+            .map(|x| x.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (x % 50))
+            .collect();
+        let mut indices: Vec<usize> = (0..keys.len()).collect();
+        radix_sort_indices(&mut indices, &keys, 8);
+        let mut expected: Vec<usize> = (0..keys.len()).collect();
+        expected.sort_by_key(|idx| keys[*idx]);
+        assert_eq!(indices, expected);
+
+        let keys = [3u64, 1, 3, 0, 1];
+        let mut indices: Vec<usize> = (0..keys.len()).collect();
+        radix_sort_indices(&mut indices, &keys, 4);
+        assert_eq!(indices, [3, 1, 4, 0, 2]);
+    }
+}
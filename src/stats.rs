@@ -0,0 +1,284 @@
+use crate::{assert_in_bounds, out_of_bounds, param::ParallelStats, ParallelVec};
+use core::{cmp::Ordering, ops::Deref};
+
+/// The bounds of the values in a single column of a [`StatsParallelVec`].
+///
+/// The bounds are always conservative: every value in the column lies within
+/// `min..=max`. Removing the row that holds the current minimum or maximum does
+/// not tighten the bounds, as that would require a full scan of the column.
+/// Instead the bounds are marked as inexact until they are recomputed with
+/// [`StatsParallelVec::refresh_stats`].
+///
+/// Values that are unordered with respect to themselves, such as NaN, are
+/// not included in the bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats<T> {
+    min: Option<T>,
+    max: Option<T>,
+    exact: bool,
+}
+
+impl<T> ColumnStats<T> {
+    /// Gets the lower bound of the values in the column, or `None` if the
+    /// column has no ordered values.
+    pub fn min(&self) -> Option<&T> {
+        self.min.as_ref()
+    }
+
+    /// Gets the upper bound of the values in the column, or `None` if the
+    /// column has no ordered values.
+    pub fn max(&self) -> Option<&T> {
+        self.max.as_ref()
+    }
+
+    /// Returns `true` if the bounds are the actual minimum and maximum of
+    /// the column, rather than a conservative superset of them.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+}
+
+impl<T: PartialOrd + Clone> ColumnStats<T> {
+    pub(crate) fn observe(&mut self, value: &T) {
+        if value.partial_cmp(value).is_none() {
+            return;
+        }
+        if !matches!(&self.min, Some(min) if min <= value) {
+            self.min = Some(value.clone());
+        }
+        if !matches!(&self.max, Some(max) if max >= value) {
+            self.max = Some(value.clone());
+        }
+    }
+
+    pub(crate) fn forget(&mut self, value: &T) {
+        let on_min = self
+            .min
+            .as_ref()
+            .and_then(|min| value.partial_cmp(min))
+            .is_some_and(|ord| ord != Ordering::Greater);
+        let on_max = self
+            .max
+            .as_ref()
+            .and_then(|max| value.partial_cmp(max))
+            .is_some_and(|ord| ord != Ordering::Less);
+        if on_min || on_max {
+            self.exact = false;
+        }
+    }
+}
+
+impl<T> Default for ColumnStats<T> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            exact: true,
+        }
+    }
+}
+
+/// A [`ParallelVec`] that incrementally maintains the bounds of every column
+/// across mutations, queryable in `O(1)` via [`stats`].
+///
+/// Pushing or inserting a row widens the bounds. Removing a row never narrows
+/// them, so they remain valid for pruning but may become inexact; see
+/// [`ColumnStats`]. Mutable access to the underlying rows is intentionally not
+/// provided, as it would allow the values to change without updating the
+/// statistics. Use [`set`] to replace a row instead.
+///
+/// [`stats`]: Self::stats
+/// [`set`]: Self::set
+pub struct StatsParallelVec<Param: ParallelStats> {
+    vec: ParallelVec<Param>,
+    stats: Param::Stats,
+}
+
+impl<Param: ParallelStats> StatsParallelVec<Param> {
+    /// Constructs a new, empty `StatsParallelVec`.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new() -> Self {
+        Self::from_vec(ParallelVec::new())
+    }
+
+    /// Constructs a new, empty `StatsParallelVec` with the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_vec(ParallelVec::with_capacity(capacity))
+    }
+
+    /// Computes the statistics over all of the rows in an existing
+    /// [`ParallelVec`].
+    pub fn from_vec(vec: ParallelVec<Param>) -> Self {
+        let mut stats = Param::Stats::default();
+        for row in vec.iter() {
+            Param::observe(&mut stats, row);
+        }
+        Self { vec, stats }
+    }
+
+    /// Gets the statistics for every column, as a tuple of [`ColumnStats`].
+    pub fn stats(&self) -> &Param::Stats {
+        &self.stats
+    }
+
+    /// Recomputes the statistics with a full scan, making the bounds of every
+    /// column exact. This is a `O(N)` operation.
+    pub fn refresh_stats(&mut self) {
+        self.stats = Param::Stats::default();
+        for row in self.vec.iter() {
+            Param::observe(&mut self.stats, row);
+        }
+    }
+
+    /// Appends an element to the back of the collection.
+    pub fn push(&mut self, value: Param) {
+        Param::observe(&mut self.stats, Param::row_ref(&value));
+        self.vec.push(value);
+    }
+
+    /// Removes the last element from the vector and returns it,
+    /// or [`None`] if it is empty.
+    ///
+    /// [`None`]: Option::None
+    pub fn pop(&mut self) -> Option<Param> {
+        let value = self.vec.pop()?;
+        self.forget(&value);
+        Some(value)
+    }
+
+    /// Removes an element from the vector and returns it.
+    ///
+    /// The removed element is replaced by the last element of the vector.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than or equal to
+    /// `len()`.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        assert_in_bounds(index, self.vec.len());
+        let value = self.vec.swap_remove(index);
+        self.forget(&value);
+        value
+    }
+
+    /// Inserts a value at `index`. Moves all of the elements above
+    /// `index` up one index. This is a `O(N)` operation.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: Param) {
+        if index > self.vec.len() {
+            out_of_bounds(index, self.vec.len());
+        }
+        Param::observe(&mut self.stats, Param::row_ref(&value));
+        self.vec.insert(index, value);
+    }
+
+    /// Removes a value at `index`. Moves all of the elements above
+    /// `index` down one index. This is a `O(N)` operation.
+    ///
+    /// Returns `None` if `index` is is greater than or equal to `len()`.
+    pub fn remove(&mut self, index: usize) -> Option<Param> {
+        let value = self.vec.remove(index)?;
+        self.forget(&value);
+        Some(value)
+    }
+
+    /// Replaces the value at `index`, updating the statistics.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len()`.
+    pub fn set(&mut self, index: usize, value: Param) {
+        assert_in_bounds(index, self.vec.len());
+        Param::forget(&mut self.stats, self.vec.index(index));
+        Param::observe(&mut self.stats, Param::row_ref(&value));
+        self.vec.set(index, value);
+    }
+
+    /// Clears the vector and resets the statistics, removing all values.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the vector.
+    pub fn clear(&mut self) {
+        self.stats = Param::Stats::default();
+        self.vec.clear();
+    }
+
+    /// Consumes the `StatsParallelVec`, discarding the statistics and
+    /// returning the underlying [`ParallelVec`].
+    pub fn into_inner(self) -> ParallelVec<Param> {
+        self.vec
+    }
+
+    fn forget(&mut self, value: &Param) {
+        if self.vec.is_empty() {
+            self.stats = Param::Stats::default();
+        } else {
+            Param::forget(&mut self.stats, Param::row_ref(value));
+        }
+    }
+}
+
+impl<Param: ParallelStats> Default for StatsParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelStats> Deref for StatsParallelVec<Param> {
+    type Target = ParallelVec<Param>;
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatsParallelVec;
+    use crate::ParallelVec;
+
+    #[test]
+    fn test_push_widens_bounds() {
+        let mut src = StatsParallelVec::<(i32, f64)>::new();
+        assert_eq!(src.stats().0.min(), None);
+        src.push((5, 1.0));
+        src.push((2, 3.0));
+        src.push((9, f64::NAN));
+        let (a, b) = src.stats();
+        assert_eq!((a.min(), a.max()), (Some(&2), Some(&9)));
+        assert_eq!((b.min(), b.max()), (Some(&1.0), Some(&3.0)));
+        assert!(a.is_exact() && b.is_exact());
+    }
+
+    #[test]
+    fn test_remove_keeps_conservative_bounds() {
+        let vec = ParallelVec::from(vec![(1, 10), (2, 20), (3, 30)]);
+        let mut src = StatsParallelVec::from_vec(vec);
+        assert_eq!(src.remove(1), Some((2, 20)));
+        assert!(src.stats().0.is_exact());
+        assert_eq!(src.swap_remove(0), (1, 10));
+        let (a, b) = src.stats();
+        assert_eq!((a.min(), a.max()), (Some(&1), Some(&3)));
+        assert_eq!((b.min(), b.max()), (Some(&10), Some(&30)));
+        assert!(!a.is_exact() && !b.is_exact());
+
+        src.refresh_stats();
+        let (a, _) = src.stats();
+        assert_eq!((a.min(), a.max()), (Some(&3), Some(&3)));
+        assert!(a.is_exact());
+
+        assert_eq!(src.pop(), Some((3, 30)));
+        assert!(src.stats().0.min().is_none());
+        assert!(src.stats().0.is_exact());
+    }
+
+    #[test]
+    fn test_set_updates_bounds() {
+        let mut src = StatsParallelVec::from_vec(ParallelVec::from(vec![(1, 1), (2, 2)]));
+        src.set(1, (7, 0));
+        let (a, b) = src.stats();
+        assert_eq!((a.min(), a.max()), (Some(&1), Some(&7)));
+        assert_eq!((b.min(), b.max()), (Some(&0), Some(&2)));
+        assert!(!a.is_exact() && !b.is_exact());
+        assert_eq!(src.index(1), (&7, &0));
+    }
+}
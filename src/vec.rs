@@ -1,9 +1,27 @@
-use crate::{assert_in_bounds, iter::IntoIter, out_of_bounds, ParallelParam, ParallelSliceMut};
+#[cfg(feature = "numa")]
+use crate::NumaPolicy;
+#[cfg(feature = "bytemuck")]
+use crate::ParallelZeroable;
+use crate::{
+    assert_in_bounds, assert_in_bounds_inclusive,
+    iter::{Drain, ExtractIf, IntoIter, Iter, IterMut, Splice},
+    out_of_bounds,
+    param::{ParallelInit, ParallelJoin, ParallelUninit},
+    resolve_range,
+    slice::{clone_row, partition_point, rows_eq},
+    PackedBools, ParallelColumn, ParallelParam, ParallelSliceMut, ParallelVecConversionError,
+    TryReserveError,
+};
+use alloc::collections::BinaryHeap;
 use alloc::vec::Vec;
 use core::{
+    cmp::Reverse,
+    convert::Infallible,
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut, Range, RangeBounds},
 };
 
 /// A contiguously growable heterogenous array type.
@@ -22,6 +40,12 @@ pub struct ParallelVec<Param: ParallelParam> {
     pub(crate) len: usize,
     pub(crate) storage: Param::Storage,
     pub(crate) capacity: usize,
+    #[cfg(feature = "mlock")]
+    pub(crate) locked: bool,
+    #[cfg(feature = "numa")]
+    pub(crate) numa: Option<NumaPolicy>,
+    // Marks the vector as owning values of `Param` for drop check, as `Vec` does.
+    pub(crate) _marker: PhantomData<Param>,
 }
 
 impl<Param: ParallelParam> ParallelVec<Param> {
@@ -39,6 +63,12 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     ///
     /// It is important to note that although the returned vector has the capacity specified,
     /// the vector will have a zero length.
+    ///
+    /// # Panics
+    /// Panics if the layout of any column would exceed the maximum allocation
+    /// size. See [`try_with_capacity`] for a fallible alternative.
+    ///
+    /// [`try_with_capacity`]: Self::try_with_capacity
     pub fn with_capacity(capacity: usize) -> Self {
         unsafe {
             Self {
@@ -49,17 +79,78 @@ impl<Param: ParallelParam> ParallelVec<Param> {
                 } else {
                     Param::alloc(capacity)
                 },
+                #[cfg(feature = "mlock")]
+                locked: false,
+                #[cfg(feature = "numa")]
+                numa: None,
+                _marker: PhantomData,
             }
         }
     }
 
+    /// Tries to construct a new, empty `ParallelVec` with the specified
+    /// capacity, returning an error instead of panicking or aborting if it
+    /// cannot be allocated.
+    ///
+    /// # Errors
+    /// Returns [`TryReserveError::CapacityOverflow`] if the layout of any
+    /// column would exceed the maximum allocation size, or
+    /// [`TryReserveError::AllocError`] if the allocator fails.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut vec = Self::new();
+        vec.try_reserve_exact(capacity)?;
+        Ok(vec)
+    }
+
     /// Returns the number of elements the vector can hold without reallocating.
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
+    /// Returns the remaining spare capacity of the vector as a set of
+    /// mutable slices of [`MaybeUninit`], one per column.
+    ///
+    /// The returned slices can be used to fill the vector with data, i.e.
+    /// from a decoder or a GPU readback, before marking the data as
+    /// initialized with [`set_len`].
+    ///
+    /// [`MaybeUninit`]: core::mem::MaybeUninit
+    /// [`set_len`]: Self::set_len
+    pub fn spare_capacity_mut(&mut self) -> Param::UninitSlicesMut<'_> {
+        // SAFE: The rows within `len..capacity` are allocated, and the
+        // returned slices cannot outlive the mutable borrow of self.
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, self.len);
+            Param::as_uninit_slices_mut(ptr, self.capacity - self.len)
+        }
+    }
+
+    /// Forces the length of the vector to `new_len`.
+    ///
+    /// This is a low-level operation that maintains none of the normal
+    /// invariants of the type. Normally changing the length of a vector is
+    /// done using one of the safe operations instead, such as [`truncate`],
+    /// [`extend`], or [`clear`].
+    ///
+    /// # Safety
+    /// - `new_len` must be less than or equal to [`capacity()`].
+    /// - Every value in every column within `old_len..new_len` must be
+    ///   initialized.
+    ///
+    /// [`truncate`]: Self::truncate
+    /// [`extend`]: Extend::extend
+    /// [`clear`]: Self::clear
+    /// [`capacity()`]: Self::capacity
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+    }
+
     /// Clears the vector, removing all values.
     ///
+    /// This is `O(1)` if none of the columns need to be dropped.
+    ///
     /// Note that this method has no effect on the allocated capacity of the vector.
     pub fn clear(&mut self) {
         self.truncate(0);
@@ -80,7 +171,68 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the
+    /// difference, with each additional row filled with the result of calling
+    /// `f`. Space is reserved once up front. If `new_len` is less than `len`,
+    /// the vector is simply truncated.
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> Param,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        self.reserve(new_len - self.len);
+        let base = Param::as_ptr(self.storage);
+        let mut len = SetLenOnDrop::new(&mut self.len);
+        while len.local_len < new_len {
+            // SAFE: local_len is within capacity, and the length is only ever
+            // committed after the value is written.
+            unsafe { Param::write(Param::add(base, len.local_len), f()) };
+            len.local_len += 1;
+        }
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the
+    /// difference, with each additional row filled with a clone of `value`.
+    /// If `new_len` is less than `len`, the vector is simply truncated.
+    pub fn resize(&mut self, new_len: usize, value: Param)
+    where
+        Param: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        let mut remaining = new_len - self.len - 1;
+        let mut value = Some(value);
+        self.resize_with(new_len, || {
+            if remaining == 0 {
+                // The last row takes the value itself instead of a clone.
+                value.take().unwrap()
+            } else {
+                remaining -= 1;
+                value.clone().unwrap()
+            }
+        });
+    }
+
+    /// Drops the rows within `start..end` in place.
+    ///
+    /// This is a no-op if none of the columns need to be dropped, which makes
+    /// [`truncate`], [`clear`], and dropping the vector `O(1)` for them.
+    ///
+    /// [`truncate`]: Self::truncate
+    /// [`clear`]: Self::clear
     pub(crate) unsafe fn drop_range(&mut self, start: usize, end: usize) {
+        if !core::mem::needs_drop::<Param>() {
+            return;
+        }
         let base = Param::as_ptr(self.storage);
         for idx in start..end {
             Param::drop(Param::add(base, idx));
@@ -93,15 +245,20 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     /// the supplied value.
     ///
     /// If the current capacity is less than the lower limit, this is a no-op.
+    /// Otherwise every column is reallocated to the smaller capacity, moving
+    /// its rows if the allocator cannot shrink it in place. Shrinking to a
+    /// capacity of zero frees the columns entirely.
     pub fn shrink_to(&mut self, min_capacity: usize) {
         if min_capacity > self.capacity {
             return;
         }
         let capacity = core::cmp::max(self.len, min_capacity);
+        if capacity == self.capacity {
+            return;
+        }
         unsafe {
-            self.storage = Param::realloc(self.storage, self.capacity, capacity);
+            self.realloc(capacity);
         }
-        self.capacity = capacity;
     }
 
     /// Shrinks the capacity of the vector as much as possible.
@@ -113,6 +270,9 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     }
 
     /// Moves all the elements of `other` into `Self`, leaving `other` empty.
+    ///
+    /// This reserves space once and moves the rows with a single bulk copy per
+    /// column. The capacity of `other` is left unchanged.
     pub fn append(&mut self, other: &mut ParallelVec<Param>) {
         self.reserve(other.len);
         unsafe {
@@ -126,6 +286,75 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the rows in the range
+    /// `[at, len)`, moved with one bulk copy per column. After the call, the
+    /// original vector will be left containing the rows `[0, at)` with its
+    /// previous capacity unchanged.
+    ///
+    /// # Panics
+    /// This function will panic if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert_in_bounds_inclusive(at, self.len);
+        let mut other = Self::with_capacity(self.len - at);
+        self.split_off_into(at, &mut other);
+        other
+    }
+
+    /// Moves the rows in the range `[at, len)` into `dest`, replacing its
+    /// contents, and shortens the vector to `at`.
+    ///
+    /// The existing rows in `dest` are dropped, but its allocation is reused
+    /// and only grown if it is too small to hold the moved rows.
+    ///
+    /// # Panics
+    /// This function will panic if `at > len`.
+    pub fn split_off_into(&mut self, at: usize, dest: &mut ParallelVec<Param>) {
+        assert_in_bounds_inclusive(at, self.len);
+        dest.clear();
+        let count = self.len - at;
+        dest.reserve(count);
+        unsafe {
+            let src = Param::ptr_at(self.storage, at);
+            Param::copy_to_nonoverlapping(src, Param::as_ptr(dest.storage), count);
+        }
+        self.len = at;
+        dest.len = count;
+    }
+
+    /// Converts the vector into a set of `Vec`s, one per column.
+    ///
+    /// This is the inverse of the `TryFrom` conversion from a tuple of `Vec`s, and
+    /// allows columns to be handed off to other columnar libraries.
+    pub fn into_vecs(mut self) -> Param::Vecs {
+        let len = self.len;
+        // SAFE: This is a move. The length is zeroed before the rows are moved
+        // out so that only the allocation is freed when self is dropped.
+        unsafe {
+            self.len = 0;
+            Param::into_vecs(Param::as_ptr(self.storage), len)
+        }
+    }
+
+    /// Consumes and leaks the vector, returning mutable slices over each of
+    /// the columns with a `'static` lifetime.
+    ///
+    /// This mirrors [`Vec::leak`]: the rows are not dropped and the
+    /// allocation is never freed, which is primarily useful for data that
+    /// lives for the remainder of the program, such as initialize-once global
+    /// tables. Any unused capacity is leaked along with the rows; call
+    /// [`shrink_to_fit`] beforehand to avoid this.
+    ///
+    /// [`Vec::leak`]: alloc::vec::Vec::leak
+    /// [`shrink_to_fit`]: Self::shrink_to_fit
+    pub fn leak(self) -> Param::SlicesMut<'static> {
+        let vec = ManuallyDrop::new(self);
+        // SAFE: The vector is never dropped, so the allocation and its
+        // initialized rows are valid for the remainder of the program.
+        unsafe { Param::as_slices_mut(Param::as_ptr(vec.storage), vec.len) }
+    }
+
     /// Appends an element to the back of a collection.
     pub fn push(&mut self, value: Param) {
         unsafe {
@@ -136,6 +365,27 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
+    /// Appends an element to the back of a collection if there is spare
+    /// capacity, otherwise the element is returned back to the caller.
+    ///
+    /// Unlike [`push`], this method never reallocates, so it can be used in
+    /// code paths that must not allocate. Use [`reserve`] beforehand to
+    /// ensure there is enough capacity.
+    ///
+    /// [`push`]: Self::push
+    /// [`reserve`]: Self::reserve
+    pub fn push_within_capacity(&mut self, value: Param) -> Result<(), Param> {
+        if self.len == self.capacity {
+            return Err(value);
+        }
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, self.len);
+            Param::write(ptr, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from the vector and returns it,
     /// or [`None`] if it is empty.
     ///
@@ -215,6 +465,60 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
+    /// Removes and drops the rows in `range` from the vector, shifting the
+    /// rows after the range down to fill the gap.
+    ///
+    /// This is cheaper than dropping the iterator returned by [`drain`], as
+    /// the removed rows are dropped in place and each column is moved with a
+    /// single `memmove`.
+    ///
+    /// # Panics
+    /// This function will panic if the range is decreasing or out of bounds.
+    ///
+    /// [`drain`]: Self::drain
+    pub fn remove_range(&mut self, range: impl RangeBounds<usize>) {
+        let Range { start, end } = resolve_range(range, self.len);
+        if start == end {
+            return;
+        }
+        // Each dropped row is counted as deleted before it is dropped, so if
+        // a drop panics, the remaining rows are still moved to close the gap.
+        let mut guard = RetainGuard::new(self);
+        guard.processed = start;
+        while guard.processed < end {
+            let idx = guard.processed;
+            guard.processed += 1;
+            guard.deleted += 1;
+            // SAFE: The row is in bounds and is never accessed again.
+            unsafe { Param::drop(Param::ptr_at(guard.vec.storage, idx)) };
+        }
+    }
+
+    /// Removes the rows in `range` from the vector, returning them as an
+    /// iterator of owned values. The rows after the range are shifted down to
+    /// fill the gap when the iterator is dropped.
+    ///
+    /// Any rows that are not consumed by the iterator are dropped with it. If
+    /// the iterator is leaked, i.e. via [`mem::forget`], the vector is left
+    /// truncated to `range.start`, leaking the drained and tail rows.
+    ///
+    /// # Panics
+    /// This function will panic if the range is decreasing or out of bounds.
+    ///
+    /// [`mem::forget`]: core::mem::forget
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, Param> {
+        let Range { start, end } = resolve_range(range, self.len);
+        let tail_len = self.len - end;
+        self.len = start;
+        Drain {
+            vec: self,
+            idx: start,
+            end,
+            tail_start: end,
+            tail_len,
+        }
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted in the
     /// given [`ParallelVec`]. The collection may reserve more space to avoid frequent
     /// reallocations. After calling reserve, capacity will be greater than or
@@ -224,568 +528,3112 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         unsafe {
             let new_capacity = self.len.checked_add(additional).expect("capacity overflow");
             if new_capacity > self.capacity {
-                let capacity = new_capacity.next_power_of_two().max(4);
-                debug_assert!(capacity > self.len, "capacity overflow");
-                self.storage = Param::realloc(self.storage, self.capacity, capacity);
-                self.capacity = capacity;
+                let capacity = new_capacity
+                    .checked_next_power_of_two()
+                    .expect("capacity overflow")
+                    .max(4);
+                self.realloc(capacity);
             }
         }
     }
-}
 
-impl<Param: ParallelParam + Copy> ParallelVec<Param> {
-    /// Creates a [`ParallelVec`] by repeating `self` `n` times.
-    pub fn repeat(&self, n: usize) -> ParallelVec<Param> {
-        let mut new = ParallelVec::with_capacity(n * self.len);
-        let mut dst = Param::as_ptr(new.storage);
-        new.len = n * self.len;
-        unsafe {
-            let base = Param::as_ptr(self.storage);
-            for _ in 0..n {
-                for idx in 0..self.len {
-                    let value = Param::read(Param::add(base, idx));
-                    Param::write(dst, value);
-                    dst = Param::add(dst, 1);
-                }
-            }
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the given [`ParallelVec`], growing the same way as
+    /// [`reserve`](Self::reserve).
+    ///
+    /// # Errors
+    /// Returns [`TryReserveError::CapacityOverflow`] if the new capacity
+    /// overflows, including if the layout of any column would exceed the
+    /// maximum allocation size, or [`TryReserveError::AllocError`] if the
+    /// allocator fails. The vector is left unchanged if an error is returned.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_capacity <= self.capacity {
+            return Ok(());
         }
-        new
+        let capacity = new_capacity
+            .checked_next_power_of_two()
+            .ok_or(TryReserveError::CapacityOverflow)?
+            .max(4);
+        unsafe { self.try_realloc(capacity) }
     }
-}
 
-impl<Param: ParallelParam> Drop for ParallelVec<Param> {
-    fn drop(&mut self) {
-        let end = self.len;
-        // Set len to 0 first in case one of the Drop impls panics
-        self.len = 0;
-        unsafe {
-            self.drop_range(0, end);
-            Param::dealloc(self.storage, self.capacity);
+    /// Tries to reserve the minimum capacity for exactly `additional` more
+    /// elements to be inserted in the given [`ParallelVec`], like
+    /// [`reserve_exact`](Self::reserve_exact).
+    ///
+    /// # Errors
+    /// See [`try_reserve`](Self::try_reserve).
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_capacity <= self.capacity {
+            return Ok(());
         }
+        unsafe { self.try_realloc(new_capacity) }
     }
-}
-
-impl<Param: ParallelParam> From<Vec<Param>> for ParallelVec<Param> {
-    fn from(value: Vec<Param>) -> Self {
-        Self::from_iter(value.into_iter())
-    }
-}
 
-impl<'a, Param: ParallelParam> PartialEq for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        if self.len != other.len {
-            return false;
-        }
-        if self.storage == other.storage {
-            // Pointing to the same storage. Shortcut out.
-            return true;
+    /// Reserves the minimum capacity for exactly `additional` more elements to
+    /// be inserted in the given [`ParallelVec`]. After calling `reserve_exact`,
+    /// capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// Unlike [`reserve`], this does not over-allocate to amortize future
+    /// insertions, so it should be preferred when the final length is known.
+    ///
+    /// [`reserve`]: Self::reserve
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows `usize`.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let new_capacity = self.len.checked_add(additional).expect("capacity overflow");
+        if new_capacity > self.capacity {
+            unsafe {
+                self.realloc(new_capacity);
+            }
         }
-        self.iter().zip(other.iter()).all(|(a, b)| a.eq(&b))
     }
-}
-
-impl<'a, Param: ParallelParam> Eq for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Eq,
-{
-}
 
-impl<'a, Param: ParallelParam> Debug for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Debug,
-{
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
-        fmt.write_str("ParallelVec")?;
-        fmt.debug_list().entries(self.iter()).finish()
+    /// Retains only the rows specified by the predicate, removing the rest.
+    /// This preserves the order of the retained rows.
+    ///
+    /// In other words, remove all rows `r` such that `f(r)` returns `false`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        // SAFE: retain_ptrs only provides pointers to initialized rows.
+        self.retain_ptrs(|ptr| f(unsafe { Param::as_ref(ptr) }));
     }
-}
 
-impl<'a, Param: ParallelParam> Hash for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Hash,
-{
-    fn hash<H>(&self, hasher: &mut H)
+    /// Retains only the rows specified by the predicate, removing the rest.
+    /// This preserves the order of the retained rows.
+    ///
+    /// Unlike [`retain`], the predicate is given mutable references to each
+    /// row, allowing the retained rows to be updated while filtering.
+    ///
+    /// [`retain`]: Self::retain
+    pub fn retain_mut<F>(&mut self, mut f: F)
     where
-        H: Hasher,
+        F: FnMut(Param::RefMut<'_>) -> bool,
     {
-        self.deref().hash(hasher);
+        // SAFE: retain_ptrs only provides pointers to initialized rows, and
+        // no other references to the rows exist while it runs.
+        self.retain_ptrs(|ptr| f(unsafe { Param::as_mut(ptr) }));
     }
-}
 
-impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
-    fn from_iter<T>(iter: T) -> Self
+    /// Replaces the rows in `range` with the rows from `replace_with`,
+    /// returning an iterator over the removed rows.
+    ///
+    /// As with [`drain`], the removed rows that are not consumed are dropped
+    /// with the returned iterator, and the replacement rows are written in
+    /// place when it is dropped. The rows after the range are moved only once
+    /// if the lower bound of `replace_with`'s [`size_hint`] is exact.
+    ///
+    /// # Panics
+    /// This function will panic if the range is decreasing or out of bounds.
+    ///
+    /// [`drain`]: Self::drain
+    /// [`size_hint`]: Iterator::size_hint
+    pub fn splice<I>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replace_with: I,
+    ) -> Splice<'_, Param, I::IntoIter>
     where
-        T: IntoIterator<Item = Param>,
+        I: IntoIterator<Item = Param>,
     {
-        let iter = iter.into_iter();
-        let (min, _) = iter.size_hint();
-        let mut parallel_vec = Self::with_capacity(min);
-        for item in iter {
-            parallel_vec.push(item);
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
         }
-        parallel_vec
     }
-}
 
-impl<Param: ParallelParam> IntoIterator for ParallelVec<Param> {
-    type Item = Param;
-    type IntoIter = IntoIter<Param>;
-    fn into_iter(self) -> Self::IntoIter {
-        let iter = IntoIter {
-            storage: self.storage,
-            capacity: self.capacity,
-            len: self.len,
+    /// Creates an iterator which uses a closure to determine if a row should
+    /// be removed. Matching rows are removed and yielded as the iterator is
+    /// advanced, while the rest are compacted in place and kept in order.
+    ///
+    /// If the iterator is dropped before it is exhausted, the remaining rows
+    /// are kept. If the iterator is leaked, i.e. via [`mem::forget`], the vector
+    /// is left empty, leaking its rows.
+    ///
+    /// [`mem::forget`]: core::mem::forget
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, Param, F>
+    where
+        F: FnMut(Param::RefMut<'_>) -> bool,
+    {
+        let original_len = self.len;
+        self.len = 0;
+        ExtractIf {
+            vec: self,
             idx: 0,
-        };
-        core::mem::forget(self);
-        iter
+            deleted: 0,
+            original_len,
+            pred,
+        }
     }
-}
 
-impl<Param: ParallelParam> Extend<Param> for ParallelVec<Param> {
-    fn extend<T>(&mut self, iter: T)
+    /// Removes all but the first of consecutive rows that resolve to the same
+    /// key. This preserves the order of the retained rows.
+    ///
+    /// If the vector is sorted by the key, this removes all duplicates.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
     where
-        T: IntoIterator<Item = Param>,
+        F: FnMut(Param::RefMut<'_>) -> K,
+        K: PartialEq,
     {
-        let iterator = iter.into_iter();
-        let (min, _) = iterator.size_hint();
-        self.reserve(min);
-        for param in iterator {
-            self.push(param);
-        }
+        self.dedup_by(|prev, curr| key(prev) == key(curr));
     }
-}
 
-impl<Param: ParallelParam + Clone> Clone for ParallelVec<Param> {
-    fn clone(&self) -> Self {
-        let mut clone = Self::with_capacity(self.len);
-        unsafe {
-            let base = Param::as_ptr(self.storage);
-            for idx in 0..self.len {
-                let value = Param::read(Param::add(base, idx));
-                clone.push(value.clone());
+    /// Removes all but the first of consecutive rows that satisfy the given
+    /// equality relation. This preserves the order of the retained rows.
+    ///
+    /// `same_bucket` is passed the last retained row and the row being
+    /// considered, in that order. If it returns `true`, the latter is removed.
+    /// Retained rows are moved down at most once each.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(Param::RefMut<'_>, Param::RefMut<'_>) -> bool,
+    {
+        let base = Param::as_ptr(self.storage);
+        let mut guard = RetainGuard::new(self);
+        guard.processed = guard.original_len.min(1);
+        while guard.processed < guard.original_len {
+            let idx = guard.processed;
+            // SAFE: Both rows are in bounds, initialized, and distinct, as the
+            // last retained row is always before idx.
+            unsafe {
+                let prev = Param::add(base, idx - guard.deleted - 1);
+                let curr = Param::add(base, idx);
+                let same = same_bucket(Param::as_mut(prev), Param::as_mut(curr));
+                guard.processed += 1;
+                if same {
+                    guard.deleted += 1;
+                    Param::drop(curr);
+                } else if guard.deleted > 0 {
+                    Param::copy_to_nonoverlapping(curr, Param::add(base, idx - guard.deleted), 1);
+                }
             }
         }
-        clone
     }
-}
 
-impl<Param: ParallelParam> Default for ParallelVec<Param> {
-    fn default() -> Self {
-        Self::new()
+    /// Retains only the rows whose `N`th column satisfies `f`, removing the
+    /// rest. This preserves the order of the retained rows.
+    ///
+    /// `f` is first evaluated over the entire `N`th column, without touching
+    /// the other columns. The rows are then compacted as with
+    /// [`compact`](Self::compact), moving each run of retained rows with a
+    /// single memmove per column. If `f` panics, the vector is left unchanged.
+    pub fn retain_by_column<const N: usize, F>(&mut self, f: F)
+    where
+        Param: ParallelColumn<N>,
+        F: FnMut(&<Param as ParallelColumn<N>>::Column) -> bool,
+    {
+        let mask: PackedBools = self.column::<N>().iter().map(f).collect();
+        self.compact(&mask);
     }
-}
 
-impl<Param: ParallelParam> Deref for ParallelVec<Param> {
-    type Target = ParallelSliceMut<'static, Param>;
-    fn deref(&self) -> &Self::Target {
-        // SAFE: Both ParallelVec and ParallelSliceMut have the same
-        // layout in memory due to #[repr(C)]
-        unsafe {
-            let ptr: *const Self = self;
-            &*(ptr.cast::<Self::Target>())
-        }
+    /// Keeps only the rows whose bit in `mask` is set, dropping the others
+    /// and shifting the kept rows down.
+    ///
+    /// This complements [`retain`](Self::retain) when the selection has
+    /// already been computed, e.g. in an earlier vectorized pass over a
+    /// column. Each run of consecutive kept rows is moved with a single
+    /// memmove per column.
+    ///
+    /// # Panics
+    /// This function will panic if `mask` is not the same length as the
+    /// vector.
+    pub fn compact(&mut self, mask: &PackedBools) {
+        assert_eq!(
+            mask.len(),
+            self.len,
+            "mask must be the same length as the vector"
+        );
+        // SAFE: The mask is the same length as the vector.
+        self.compact_by(|idx| unsafe { mask.get(idx).unwrap_unchecked() });
     }
-}
 
-impl<Param: ParallelParam> DerefMut for ParallelVec<Param> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFE: Both ParallelVec and ParallelSliceMut have the same
-        // layout in memory due to #[repr(C)]
-        unsafe {
-            let ptr: *mut Self = self;
-            &mut *(ptr.cast::<Self::Target>())
-        }
+    /// Keeps only the rows whose value in `mask` is `true`, dropping the
+    /// others and shifting the kept rows down.
+    ///
+    /// See [`compact`](Self::compact) for details.
+    ///
+    /// # Panics
+    /// This function will panic if `mask` is not the same length as the
+    /// vector.
+    pub fn compact_bools(&mut self, mask: &[bool]) {
+        assert_eq!(
+            mask.len(),
+            self.len,
+            "mask must be the same length as the vector"
+        );
+        // SAFE: The mask is the same length as the vector.
+        self.compact_by(|idx| unsafe { *mask.get_unchecked(idx) });
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::ParallelVec;
-    use std::convert::From;
-    use std::rc::Rc;
+    fn compact_by(&mut self, mut keep: impl FnMut(usize) -> bool) {
+        let base = Param::as_ptr(self.storage);
+        let mut guard = RetainGuard::new(self);
+        let len = guard.original_len;
+        while guard.processed < len {
+            let start = guard.processed;
+            let mut end = start;
+            while end < len && keep(end) {
+                end += 1;
+            }
+            // SAFE: The rows in start..end have not been moved or dropped, and
+            // the rows they are moved over have already been moved or dropped.
+            unsafe {
+                if guard.deleted > 0 && end > start {
+                    let dst = Param::add(base, start - guard.deleted);
+                    Param::copy_to(Param::add(base, start), dst, end - start);
+                }
+                guard.processed = end;
+                while guard.processed < len && !keep(guard.processed) {
+                    let idx = guard.processed;
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    Param::drop(Param::add(base, idx));
+                }
+            }
+        }
+    }
+
+    /// Retains only the rows for which `f` returns `true` when given a
+    /// pointer to the row, compacting the retained rows in place.
+    fn retain_ptrs(&mut self, mut f: impl FnMut(Param::Ptr) -> bool) {
+        let base = Param::as_ptr(self.storage);
+        let mut guard = RetainGuard::new(self);
+        while guard.processed < guard.original_len {
+            let idx = guard.processed;
+            // SAFE: idx is in bounds and the row has not been moved or dropped.
+            let keep = f(unsafe { Param::add(base, idx) });
+            guard.processed += 1;
+            unsafe {
+                if keep {
+                    if guard.deleted > 0 {
+                        let dst = Param::add(base, idx - guard.deleted);
+                        Param::copy_to_nonoverlapping(Param::add(base, idx), dst, 1);
+                    }
+                } else {
+                    guard.deleted += 1;
+                    Param::drop(Param::add(base, idx));
+                }
+            }
+        }
+    }
+
+    /// Concatenates multiple vectors into one.
+    ///
+    /// The total length is computed up front so that the result is allocated
+    /// exactly once, and the rows of each vector are moved in with bulk copies.
+    pub fn concat<I>(vecs: I) -> Self
+    where
+        I: IntoIterator<Item = ParallelVec<Param>>,
+    {
+        let mut vecs: Vec<_> = vecs.into_iter().collect();
+        let len = vecs
+            .iter()
+            .try_fold(0usize, |len, vec| len.checked_add(vec.len))
+            .expect("capacity overflow");
+        let mut concat = Self::with_capacity(len);
+        for vec in vecs.iter_mut() {
+            concat.append(vec);
+        }
+        concat
+    }
+
+    /// Merges multiple vectors that are each sorted by the same key into one
+    /// sorted vector.
+    ///
+    /// The merge is stable: rows with equal keys keep their relative order,
+    /// with rows from earlier vectors placed before rows from later ones. A
+    /// heap holds a cursor into each vector, and each maximal run of rows
+    /// that can be taken from a single vector is moved with one bulk copy per
+    /// column. The result is allocated exactly once.
+    ///
+    /// The key of each row is computed at most once. If any of the inputs are
+    /// not sorted by the key, the rows are still all moved into the result,
+    /// but it will not be sorted.
+    pub fn kmerge_by_key<I, K, F>(vecs: I, mut f: F) -> Self
+    where
+        I: IntoIterator<Item = ParallelVec<Param>>,
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let mut vecs: Vec<_> = vecs.into_iter().collect();
+        let len = vecs
+            .iter()
+            .try_fold(0usize, |len, vec| len.checked_add(vec.len))
+            .expect("capacity overflow");
+        let mut merged = Self::with_capacity(len);
+        // Take ownership of the rows up front. If `f` panics, the rows that
+        // have not been moved yet are leaked instead of being dropped twice.
+        let mut sources: Vec<(Param::Ptr, usize, usize)> = vecs
+            .iter_mut()
+            .map(|vec| {
+                (
+                    Param::as_ptr(vec.storage),
+                    0,
+                    core::mem::replace(&mut vec.len, 0),
+                )
+            })
+            .collect();
+
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (src, &(ptr, _, len)) in sources.iter().enumerate() {
+            if len > 0 {
+                // SAFE: The first row of the vector is initialized.
+                heap.push(Reverse((f(unsafe { Param::as_ref(ptr) }), src)));
+            }
+        }
+
+        while let Some(Reverse((_, src))) = heap.pop() {
+            let (ptr, start, len) = sources[src];
+            let mut end = start + 1;
+            let mut next = None;
+            // SAFE: Every row in start..len has not been moved out yet.
+            unsafe {
+                match heap.peek() {
+                    None => end = len,
+                    Some(Reverse((top, top_src))) => {
+                        while end < len {
+                            let key = f(Param::as_ref(Param::add(ptr, end)));
+                            if key < *top || (key == *top && src < *top_src) {
+                                end += 1;
+                            } else {
+                                next = Some(key);
+                                break;
+                            }
+                        }
+                    }
+                }
+                let dst = Param::ptr_at(merged.storage, merged.len);
+                Param::copy_to_nonoverlapping(Param::add(ptr, start), dst, end - start);
+            }
+            merged.len += end - start;
+            sources[src].1 = end;
+            if let Some(key) = next {
+                heap.push(Reverse((key, src)));
+            }
+        }
+        merged
+    }
+
+    /// Merges two vectors that are both sorted by the same key into one sorted
+    /// vector.
+    ///
+    /// The merge is stable: rows with equal keys keep their relative order,
+    /// with rows from `self` placed before rows from `other`. The end of each
+    /// run of rows that can be taken from one input is found with a galloping
+    /// search, and the run is moved with one bulk copy per column, so inputs
+    /// that interleave in long runs are merged with few key comparisons. The
+    /// result is allocated exactly once.
+    ///
+    /// If either input is not sorted by the key, the rows are still all moved
+    /// into the result, but it will not be sorted.
+    pub fn merge_sorted<K, F>(mut self, mut other: Self, mut f: F) -> Self
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let len = self.len.checked_add(other.len).expect("capacity overflow");
+        let mut merged = Self::with_capacity(len);
+        let (a, b) = (Param::as_ptr(self.storage), Param::as_ptr(other.storage));
+        // Take ownership of the rows up front. If `f` panics, the rows that
+        // have not been moved yet are leaked instead of being dropped twice.
+        let a_len = core::mem::replace(&mut self.len, 0);
+        let b_len = core::mem::replace(&mut other.len, 0);
+        let (mut i, mut j) = (0, 0);
+        // SAFE: Rows at or after i and j have not been moved out yet, and the
+        // merged vector has capacity for every row.
+        unsafe {
+            let mut take = |src: Param::Ptr, count: usize| {
+                let dst = Param::ptr_at(merged.storage, merged.len);
+                Param::copy_to_nonoverlapping(src, dst, count);
+                merged.len += count;
+            };
+            while i < a_len && j < b_len {
+                let key = f(Param::as_ref(Param::add(b, j)));
+                let run = gallop::<Param, _>(Param::add(a, i), a_len - i, |row| f(row) <= key);
+                take(Param::add(a, i), run);
+                i += run;
+                if i == a_len {
+                    break;
+                }
+                let key = f(Param::as_ref(Param::add(a, i)));
+                let run = gallop::<Param, _>(Param::add(b, j), b_len - j, |row| f(row) < key);
+                take(Param::add(b, j), run);
+                j += run;
+            }
+            take(Param::add(a, i), a_len - i);
+            take(Param::add(b, j), b_len - j);
+        }
+        merged
+    }
+
+    /// Moves every row into `dst`, writing the `i`th row to `dst[indices[i]]`
+    /// and dropping the row it replaces.
+    ///
+    /// This is the dual of [`gather`](ParallelSliceMut::gather). Every target
+    /// is checked to be in bounds before any rows are moved. In debug builds,
+    /// the targets are also checked to be unique; if a target is repeated in
+    /// a release build, the last row written to it is kept.
+    ///
+    /// # Panics
+    /// This function will panic if `indices` is not the same length as the
+    /// vector, or if any of the targets are out of bounds.
+    pub fn scatter_into(self, dst: &mut ParallelSliceMut<'_, Param>, indices: &[usize]) {
+        assert_eq!(
+            indices.len(),
+            self.len,
+            "indices must be the same length as the vector"
+        );
+        for &idx in indices {
+            assert_in_bounds(idx, dst.len());
+        }
+        #[cfg(debug_assertions)]
+        {
+            let mut seen = PackedBools::repeat(false, dst.len());
+            for &idx in indices {
+                assert!(!seen.get(idx).unwrap(), "duplicate scatter target: {}", idx);
+                seen.set(idx, true);
+            }
+        }
+        for (value, &idx) in self.into_iter().zip(indices) {
+            dst.set(idx, value);
+        }
+    }
+
+    /// Clones and appends all of the rows in `other` to the vector.
+    pub fn extend_from_slice(&mut self, other: &[Param])
+    where
+        Param: Clone,
+    {
+        self.extend_desugared(other.iter().cloned());
+    }
+
+    /// Clones and appends the rows given as one slice per column to the
+    /// vector.
+    ///
+    /// Space is reserved once up front and each row is cloned directly into
+    /// the new tail, which is much faster than pushing the rows one at a time.
+    /// If the rows are [`Copy`], [`extend_from_slices_copied`] copies each
+    /// column with a single memcpy instead.
+    ///
+    /// # Errors
+    /// Returns [`ParallelVecConversionError::UnevenLengths`] if the slices
+    /// are not all the same length. The vector is left unchanged.
+    ///
+    /// [`extend_from_slices_copied`]: Self::extend_from_slices_copied
+    pub fn extend_from_slices(
+        &mut self,
+        slices: Param::Slices<'_>,
+    ) -> Result<(), ParallelVecConversionError>
+    where
+        Param: Clone,
+    {
+        let count =
+            Param::get_slices_len(&slices).ok_or(ParallelVecConversionError::UnevenLengths)?;
+        let src = Param::get_slices_ptrs(slices);
+        self.reserve(count);
+        let base = Param::as_ptr(self.storage);
+        let mut len = SetLenOnDrop::new(&mut self.len);
+        for idx in 0..count {
+            // SAFE: The source row is initialized. The destination is within
+            // capacity, and the length is only committed after the clone is written.
+            unsafe {
+                let row = clone_row::<Param>(Param::add(src, idx));
+                Param::write(Param::add(base, len.local_len), row);
+            }
+            len.local_len += 1;
+        }
+        Ok(())
+    }
+
+    /// Clones and inserts the rows given as one slice per column at `index`,
+    /// moving all of the rows at and above `index` up to make room.
+    ///
+    /// Unlike calling [`insert`] once per row, the tail of the vector is only
+    /// moved once.
+    ///
+    /// # Errors
+    /// Returns [`ParallelVecConversionError::UnevenLengths`] if the slices
+    /// are not all the same length. The vector is left unchanged.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    ///
+    /// [`insert`]: Self::insert
+    pub fn insert_from_slices(
+        &mut self,
+        index: usize,
+        slices: Param::Slices<'_>,
+    ) -> Result<(), ParallelVecConversionError>
+    where
+        Param: Clone,
+    {
+        assert_in_bounds_inclusive(index, self.len);
+        let count =
+            Param::get_slices_len(&slices).ok_or(ParallelVecConversionError::UnevenLengths)?;
+        let src = Param::get_slices_ptrs(slices);
+        self.reserve(count);
+        // SAFE: The vector has the capacity for the moved tail.
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, index);
+            Param::copy_to(ptr, Param::add(ptr, count), self.len - index);
+        }
+        // The gap is treated as deleted rows, so if a clone panics the tail is
+        // moved back down to close whatever remains of it.
+        let mut guard = RetainGuard::new(self);
+        guard.original_len += count;
+        guard.processed = index + count;
+        guard.deleted = count;
+        for idx in 0..count {
+            // SAFE: The source row is initialized. The destination is within the gap.
+            unsafe {
+                let row = clone_row::<Param>(Param::add(src, idx));
+                Param::write(Param::ptr_at(guard.vec.storage, index + idx), row);
+            }
+            guard.deleted -= 1;
+        }
+        Ok(())
+    }
+
+    /// Clones the rows in `src` and appends them to the end of the vector.
+    ///
+    /// Space is reserved once up front and each row is cloned directly into
+    /// the new tail, without collecting the rows into a temporary buffer.
+    ///
+    /// # Panics
+    /// This function will panic if the range is decreasing or out of bounds.
+    pub fn extend_from_within(&mut self, src: impl RangeBounds<usize>)
+    where
+        Param: Clone,
+    {
+        let Range { start, end } = resolve_range(src, self.len);
+        self.reserve(end - start);
+        let base = Param::as_ptr(self.storage);
+        let mut len = SetLenOnDrop::new(&mut self.len);
+        for idx in start..end {
+            // SAFE: The source row is initialized. The destination is within
+            // capacity, and the length is only committed after the clone is written.
+            unsafe {
+                let row = clone_row::<Param>(Param::add(base, idx));
+                Param::write(Param::add(base, len.local_len), row);
+            }
+            len.local_len += 1;
+        }
+    }
+
+    /// Appends every item of `iter`, reserving up front from its `size_hint`.
+    ///
+    /// Items are written directly into the spare capacity, and the length is
+    /// only updated once the spare capacity is exhausted, the iterator ends,
+    /// or the iterator panics.
+    fn extend_desugared<I: Iterator<Item = Param>>(&mut self, mut iter: I) {
+        let (min, _) = iter.size_hint();
+        self.reserve(min);
+        loop {
+            {
+                let base = Param::as_ptr(self.storage);
+                let capacity = self.capacity;
+                let mut len = SetLenOnDrop::new(&mut self.len);
+                while len.local_len < capacity {
+                    match iter.next() {
+                        // SAFE: local_len is within capacity, and the length is
+                        // only ever committed after the value is written.
+                        Some(value) => unsafe {
+                            Param::write(Param::add(base, len.local_len), value);
+                            len.local_len += 1;
+                        },
+                        None => return,
+                    }
+                }
+            }
+            match iter.next() {
+                Some(value) => {
+                    let (min, _) = iter.size_hint();
+                    self.reserve(min.saturating_add(1));
+                    unsafe {
+                        Param::write(Param::ptr_at(self.storage, self.len), value);
+                    }
+                    self.len += 1;
+                }
+                None => return,
+            }
+        }
+    }
+
+    unsafe fn realloc(&mut self, capacity: usize) {
+        let result = self.reallocate_with(capacity, |storage, current| {
+            Ok::<_, Infallible>(Param::realloc(storage, current, capacity))
+        });
+        result.unwrap_or_else(|never| match never {});
+    }
+
+    /// Reallocates the storage to `capacity` without panicking or aborting.
+    ///
+    /// The new columns are allocated before the old ones are freed, so that
+    /// the vector is left untouched if any of them fail to allocate.
+    unsafe fn try_realloc(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        let len = self.capacity.min(capacity);
+        self.reallocate_with(capacity, |storage, current| {
+            let new_storage = if capacity == 0 {
+                Param::dangling()
+            } else {
+                Param::try_alloc(capacity)?
+            };
+            Param::copy_to_nonoverlapping(Param::as_ptr(storage), Param::as_ptr(new_storage), len);
+            Param::dealloc(storage, current);
+            Ok(new_storage)
+        })
+    }
+
+    /// Replaces the storage with the result of `f`, which is passed the
+    /// current storage and capacity, keeping the memory locking and NUMA
+    /// policy of the vector. If `f` fails, the current storage is kept.
+    unsafe fn reallocate_with<E>(
+        &mut self,
+        capacity: usize,
+        f: impl FnOnce(Param::Storage, usize) -> Result<Param::Storage, E>,
+    ) -> Result<(), E> {
+        #[cfg(feature = "mlock")]
+        if self.locked {
+            self.unlock_pages();
+        }
+        #[cfg(feature = "numa")]
+        if self.numa.is_some() {
+            let _ = self.apply_numa_policy(None);
+        }
+        let result = f(self.storage, self.capacity).map(|storage| {
+            self.storage = storage;
+            self.capacity = capacity;
+        });
+        #[cfg(feature = "numa")]
+        if let Some(policy) = self.numa {
+            // Growth cannot report errors, so a failure to re-apply the policy
+            // is surfaced through numa_policy instead.
+            if self.apply_numa_policy(Some(policy)).is_err() {
+                self.numa = None;
+            }
+        }
+        #[cfg(feature = "mlock")]
+        if self.locked {
+            // Growth cannot report errors, so a failure to re-lock is surfaced
+            // through is_memory_locked instead.
+            self.locked = self.lock_pages().is_ok();
+        }
+        result
+    }
+}
+
+#[cfg(feature = "numa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "numa")))]
+impl<Param: ParallelParam> ParallelVec<Param> {
+    /// Sets the NUMA placement policy of the backing memory of the vector.
+    ///
+    /// Pages that have already been touched are migrated to match the policy.
+    /// The policy is applied again after every reallocation, and is reset when
+    /// the vector is dropped. If applying the policy after a reallocation fails,
+    /// the policy is cleared and [`numa_policy`] will return `None`.
+    ///
+    /// Only whole pages are affected. Pages at either end of a column that are
+    /// shared with other allocations keep their existing policy, so columns
+    /// smaller than a page may not be affected at all. Cloned vectors do not
+    /// inherit the policy.
+    ///
+    /// This is currently only supported on Linux.
+    ///
+    /// # Errors
+    /// Returns an error if the platform is not supported or the OS rejects the
+    /// policy, i.e. if the mask includes nodes that do not exist. The current
+    /// policy is left unchanged if this fails.
+    ///
+    /// [`numa_policy`]: Self::numa_policy
+    pub fn set_numa_policy(&mut self, policy: NumaPolicy) -> std::io::Result<()> {
+        unsafe { self.apply_numa_policy(Some(policy))? };
+        self.numa = Some(policy);
+        Ok(())
+    }
+
+    /// Resets the NUMA placement policy of the backing memory of the vector
+    /// to the default policy of the process.
+    ///
+    /// Does nothing if no policy has been set.
+    pub fn clear_numa_policy(&mut self) {
+        if self.numa.take().is_some() {
+            let _ = unsafe { self.apply_numa_policy(None) };
+        }
+    }
+
+    /// Gets the NUMA placement policy of the backing memory of the vector, if set.
+    pub fn numa_policy(&self) -> Option<NumaPolicy> {
+        self.numa
+    }
+
+    unsafe fn apply_numa_policy(&self, policy: Option<NumaPolicy>) -> std::io::Result<()> {
+        let mut result = Ok(());
+        Param::for_each_allocation(self.storage, self.capacity, |ptr, len| {
+            if result.is_ok() {
+                result = crate::numa::apply(policy, ptr, len);
+            }
+        });
+        result
+    }
+}
+
+/// Returns the index of the first row for which `pred` returns `false`,
+/// probing exponentially increasing offsets before binary searching, so that
+/// short prefixes are found in time proportional to their length.
+fn gallop<Param, P>(ptr: Param::Ptr, len: usize, mut pred: P) -> usize
+where
+    Param: ParallelParam,
+    P: FnMut(Param::Ref<'_>) -> bool,
+{
+    let (mut lo, mut probe, mut step) = (0, 0, 1);
+    // SAFE: probe is always within the rows.
+    while probe < len && pred(unsafe { Param::as_ref(Param::add(ptr, probe)) }) {
+        lo = probe + 1;
+        probe += step;
+        step *= 2;
+    }
+    let hi = probe.min(len);
+    // SAFE: lo is at most hi, which is at most len.
+    lo + partition_point::<Param, _>(unsafe { Param::add(ptr, lo) }, hi - lo, pred)
+}
+
+/// Restores a vector to a consistent state after a retain-like operation,
+/// including while unwinding from a panic.
+///
+/// Rows before `processed` have either been dropped or moved `deleted` rows
+/// towards the front. On drop, the unprocessed rows are moved to close the gap.
+struct RetainGuard<'a, Param: ParallelParam> {
+    vec: &'a mut ParallelVec<Param>,
+    processed: usize,
+    deleted: usize,
+    original_len: usize,
+}
+
+impl<'a, Param: ParallelParam> RetainGuard<'a, Param> {
+    fn new(vec: &'a mut ParallelVec<Param>) -> Self {
+        let original_len = vec.len;
+        // Avoid double drops if the guard is leaked.
+        vec.len = 0;
+        Self {
+            vec,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        }
+    }
+}
+
+impl<Param: ParallelParam> Drop for RetainGuard<'_, Param> {
+    fn drop(&mut self) {
+        if self.deleted > 0 && self.processed < self.original_len {
+            unsafe {
+                let src = Param::ptr_at(self.vec.storage, self.processed);
+                let dst = Param::ptr_at(self.vec.storage, self.processed - self.deleted);
+                Param::copy_to(src, dst, self.original_len - self.processed);
+            }
+        }
+        self.vec.len = self.original_len - self.deleted;
+    }
+}
+
+/// Commits a locally tracked length back to a vector when dropped, including
+/// while unwinding from a panic.
+struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
+
+impl<'a> SetLenOnDrop<'a> {
+    #[inline]
+    fn new(len: &'a mut usize) -> Self {
+        let local_len = *len;
+        Self { len, local_len }
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
+
+#[cfg(feature = "mlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mlock")))]
+impl<Param: ParallelParam> ParallelVec<Param> {
+    /// Constructs a new, empty [`ParallelVec`] with the specified capacity and
+    /// locks its memory into RAM.
+    ///
+    /// See [`lock_memory`] for details.
+    ///
+    /// [`lock_memory`]: Self::lock_memory
+    pub fn with_capacity_locked(capacity: usize) -> std::io::Result<Self> {
+        let mut vec = Self::with_capacity(capacity);
+        vec.lock_memory()?;
+        Ok(vec)
+    }
+
+    /// Locks the backing memory of the vector into RAM, preventing it from being
+    /// paged out. This is done with `mlock` on unix platforms and `VirtualLock`
+    /// on Windows.
+    ///
+    /// Once locked, the vector is locked again after every reallocation, and
+    /// unlocked when it is dropped. If re-locking after a reallocation fails,
+    /// the vector is left unlocked and [`is_memory_locked`] will return `false`.
+    ///
+    /// Note that the OS locks whole pages, and does not count how many times a
+    /// page has been locked. To avoid unlocking other allocations, unlocking
+    /// only unlocks the pages that each column owns entirely, and the partial
+    /// pages at either end of a column stay locked after it is unlocked or
+    /// freed. Cloned vectors are not locked.
+    ///
+    /// # Errors
+    /// Returns an error if the OS refuses to lock the memory, usually due to
+    /// `RLIMIT_MEMLOCK` or the working set size limits. The vector is left
+    /// unlocked if this fails.
+    ///
+    /// [`is_memory_locked`]: Self::is_memory_locked
+    pub fn lock_memory(&mut self) -> std::io::Result<()> {
+        if !self.locked {
+            unsafe { self.lock_pages()? };
+            self.locked = true;
+        }
+        Ok(())
+    }
+
+    /// Unlocks the backing memory of the vector, allowing it to be paged out.
+    ///
+    /// Does nothing if the memory is not locked.
+    pub fn unlock_memory(&mut self) {
+        if self.locked {
+            unsafe { self.unlock_pages() };
+            self.locked = false;
+        }
+    }
+
+    /// Returns `true` if the backing memory of the vector is locked into RAM.
+    pub fn is_memory_locked(&self) -> bool {
+        self.locked
+    }
+
+    unsafe fn lock_pages(&self) -> std::io::Result<()> {
+        let mut result = Ok(());
+        let mut locked = 0;
+        Param::for_each_allocation(self.storage, self.capacity, |ptr, len| {
+            if result.is_ok() {
+                result = crate::lock::lock(ptr, len);
+                locked += result.is_ok() as usize;
+            }
+        });
+        if result.is_err() {
+            // Roll back the columns that were successfully locked.
+            Param::for_each_allocation(self.storage, self.capacity, |ptr, len| {
+                if locked > 0 {
+                    crate::lock::unlock(ptr, len);
+                    locked -= 1;
+                }
+            });
+        }
+        result
+    }
+
+    unsafe fn unlock_pages(&self) {
+        Param::for_each_allocation(self.storage, self.capacity, |ptr, len| {
+            crate::lock::unlock(ptr, len);
+        });
+    }
+}
+
+impl<Param: ParallelInit> ParallelVec<Param> {
+    /// Constructs a new `ParallelVec` of `len` uninitialized rows, whose columns
+    /// are [`MaybeUninit`] values, with a capacity of exactly `len`.
+    ///
+    /// The columns can be filled in place before the vector is converted into
+    /// a `ParallelVec<Param>` with [`assume_init`]. This avoids initializing
+    /// every row twice when loading large datasets.
+    ///
+    /// This is equivalent to calling [`uninit_with_len`] on the vector of
+    /// [`MaybeUninit`] columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let mut src = ParallelVec::<(u32, f32)>::new_uninit(2);
+    /// let (a, b) = src.as_slices_mut();
+    /// for idx in 0..2 {
+    ///     a[idx].write(idx as u32);
+    ///     b[idx].write(idx as f32 * 0.5);
+    /// }
+    /// let src = unsafe { src.assume_init() };
+    /// assert_eq!(src, vec![(0, 0.0), (1, 0.5)]);
+    /// ```
+    ///
+    /// [`MaybeUninit`]: core::mem::MaybeUninit
+    /// [`assume_init`]: ParallelVec::assume_init
+    /// [`uninit_with_len`]: ParallelVec::uninit_with_len
+    pub fn new_uninit(len: usize) -> ParallelVec<Param::Uninit> {
+        ParallelVec::uninit_with_len(len)
+    }
+}
+
+impl<Param: ParallelUninit> ParallelVec<Param> {
+    /// Constructs a new `ParallelVec` of `len` uninitialized rows, with a
+    /// capacity of exactly `len`.
+    ///
+    /// The columns can be filled, i.e. from multiple threads via
+    /// [`as_slices_mut`], before converting the vector into its initialized
+    /// form with [`assume_init`].
+    ///
+    /// [`as_slices_mut`]: ParallelSliceMut::as_slices_mut
+    /// [`assume_init`]: Self::assume_init
+    pub fn uninit_with_len(len: usize) -> Self {
+        let mut vec = Self::with_capacity(len);
+        vec.len = len;
+        vec
+    }
+
+    /// Converts the vector into the equivalent vector of initialized rows
+    /// without copying.
+    ///
+    /// The type of the returned vector is derived from the type of `self`, so
+    /// unlike a transmute, the columns cannot be reinterpreted as unrelated types.
+    ///
+    /// # Safety
+    /// Every value in every column within `0..len()` must be initialized.
+    pub unsafe fn assume_init(self) -> ParallelVec<Param::Init> {
+        let vec = ManuallyDrop::new(self);
+        ParallelVec {
+            len: vec.len,
+            storage: Param::assume_init_storage(vec.storage),
+            capacity: vec.capacity,
+            #[cfg(feature = "mlock")]
+            locked: vec.locked,
+            #[cfg(feature = "numa")]
+            numa: vec.numa,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Param: ParallelParam> ParallelVec<Param> {
+    /// Fuses `self` and `other` into a single vector whose rows are the columns
+    /// of `self` followed by the columns of `other`.
+    ///
+    /// Each column is moved into the new allocation with a single copy, without
+    /// reading or writing any of the values as rows.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is not the same length as `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let positions = ParallelVec::from(vec![(0.0f32, 1.0f32), (2.0, 3.0)]);
+    /// let ids = ParallelVec::from(vec![(1u32, 'a'), (2, 'b')]);
+    /// let table = positions.join(ids);
+    /// assert_eq!(table.index(1), (&2.0, &3.0, &2, &'b'));
+    /// ```
+    pub fn join<Other: ParallelParam>(
+        mut self,
+        mut other: ParallelVec<Other>,
+    ) -> ParallelVec<Param::Output>
+    where
+        Param: ParallelJoin<Other>,
+    {
+        assert_eq!(
+            self.len, other.len,
+            "joined vectors must have the same length"
+        );
+        let len = self.len;
+        let mut joined = ParallelVec::with_capacity(len);
+        // SAFE: The new vector has the capacity for all of the rows, and the
+        // lengths of the sources are cleared so the values are not dropped twice.
+        unsafe {
+            let src = Param::join_ptrs(self.as_mut_ptrs(), other.as_mut_ptrs());
+            Param::Output::copy_to_nonoverlapping(src, joined.as_mut_ptrs(), len);
+            self.set_len(0);
+            other.set_len(0);
+            joined.set_len(len);
+        }
+        joined
+    }
+
+    /// Splits the vector into two vectors by column, the inverse of [`join`].
+    ///
+    /// The first vector receives the columns of `Left` and the second receives
+    /// the remaining columns. Each column is moved into its new allocation with
+    /// a single copy.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use parallel_vec::ParallelVec;
+    /// let table = ParallelVec::from(vec![(0.0f32, 1.0f32, 1u32, 'a'), (2.0, 3.0, 2, 'b')]);
+    /// let (positions, ids) = table.split_columns::<(f32, f32), (u32, char)>();
+    /// assert_eq!(positions.index(1), (&2.0, &3.0));
+    /// assert_eq!(ids.index(1), (&2, &'b'));
+    /// ```
+    ///
+    /// [`join`]: Self::join
+    pub fn split_columns<Left, Right>(mut self) -> (ParallelVec<Left>, ParallelVec<Right>)
+    where
+        Left: ParallelJoin<Right, Output = Param>,
+        Right: ParallelParam,
+    {
+        let len = self.len;
+        let mut left = ParallelVec::with_capacity(len);
+        let mut right = ParallelVec::with_capacity(len);
+        // SAFE: The new vectors have the capacity for all of the rows, and the
+        // length of the source is cleared so the values are not dropped twice.
+        unsafe {
+            let dst = Left::join_ptrs(left.as_mut_ptrs(), right.as_mut_ptrs());
+            Param::copy_to_nonoverlapping(self.as_mut_ptrs(), dst, len);
+            self.set_len(0);
+            left.set_len(len);
+            right.set_len(len);
+        }
+        (left, right)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+impl<Param: ParallelZeroable> ParallelVec<Param> {
+    /// Constructs a new `ParallelVec` of `len` rows where every value is
+    /// zeroed, with a capacity of exactly `len`.
+    ///
+    /// The memory is requested already zeroed from the allocator, which is
+    /// significantly faster than pushing zeroed rows one at a time, and may
+    /// avoid touching the pages at all until they are written to.
+    ///
+    /// # Panics
+    /// Panics if the layout of any column would exceed the maximum allocation
+    /// size.
+    pub fn zeroed(len: usize) -> Self {
+        let mut vec = Self::new();
+        if len != 0 {
+            // SAFE: The capacity is non-zero and every column is valid when
+            // zeroed, so all of the rows are initialized.
+            vec.storage = unsafe { Param::alloc_zeroed(len) };
+            vec.capacity = len;
+            vec.len = len;
+        }
+        vec
+    }
+}
+
+impl<Param: ParallelParam + Copy> ParallelVec<Param> {
+    /// Creates a [`ParallelVec`] by repeating `self` `n` times.
+    pub fn repeat(&self, n: usize) -> ParallelVec<Param> {
+        let mut new = ParallelVec::with_capacity(n * self.len);
+        let mut dst = Param::as_ptr(new.storage);
+        new.len = n * self.len;
+        // SAFE: The new vector has the capacity for every repetition, and the
+        // rows are Copy, so they can be duplicated bitwise.
+        unsafe {
+            let base = Param::as_ptr(self.storage);
+            for _ in 0..n {
+                Param::copy_to_nonoverlapping(base, dst, self.len);
+                dst = Param::add(dst, self.len);
+            }
+        }
+        new
+    }
+
+    /// Copies and appends the rows given as one slice per column to the
+    /// vector.
+    ///
+    /// This is equivalent to [`extend_from_slices`], but space is reserved
+    /// once up front and each column is copied with a single memcpy.
+    ///
+    /// # Errors
+    /// Returns [`ParallelVecConversionError::UnevenLengths`] if the slices
+    /// are not all the same length. The vector is left unchanged.
+    ///
+    /// [`extend_from_slices`]: Self::extend_from_slices
+    pub fn extend_from_slices_copied(
+        &mut self,
+        slices: Param::Slices<'_>,
+    ) -> Result<(), ParallelVecConversionError> {
+        let count =
+            Param::get_slices_len(&slices).ok_or(ParallelVecConversionError::UnevenLengths)?;
+        let src = Param::get_slices_ptrs(slices);
+        self.reserve(count);
+        // SAFE: The vector has the capacity for the new rows, and the rows are
+        // Copy, so they can be duplicated bitwise. The slices cannot alias the
+        // spare capacity, as it is not reachable through a shared borrow.
+        unsafe {
+            Param::copy_to_nonoverlapping(src, Param::ptr_at(self.storage, self.len), count);
+        }
+        self.len += count;
+        Ok(())
+    }
+
+    /// Copies the rows in `src` from `other` into the vector, inserting them
+    /// at `index`. Moves all of the rows at and above `index` up to make room.
+    /// Passing `self.len()` as `index` appends the rows.
+    ///
+    /// Space is reserved once up front and each column is copied with a
+    /// single bulk copy.
+    ///
+    /// # Panics
+    /// This function will panic if `src` is decreasing or out of bounds of
+    /// `other`, or if `index` is greater than `len()`.
+    pub fn copy_from(
+        &mut self,
+        other: &ParallelSliceMut<'_, Param>,
+        src: impl RangeBounds<usize>,
+        index: usize,
+    ) {
+        let Range { start, end } = resolve_range(src, other.len());
+        assert_in_bounds_inclusive(index, self.len);
+        let count = end - start;
+        self.reserve(count);
+        // SAFE: Both ranges are in bounds and the vector has the capacity for
+        // the new rows. The rows are Copy, so they can be duplicated bitwise.
+        // other cannot alias self as self is mutably borrowed.
+        unsafe {
+            let dst = Param::ptr_at(self.storage, index);
+            Param::copy_to(dst, Param::add(dst, count), self.len - index);
+            Param::copy_to_nonoverlapping(Param::add(other.as_ptrs(), start), dst, count);
+        }
+        self.len += count;
+    }
+}
+
+impl<Param: ParallelParam> Drop for ParallelVec<Param> {
+    fn drop(&mut self) {
+        let end = self.len;
+        // Set len to 0 first in case one of the Drop impls panics
+        self.len = 0;
+        unsafe {
+            self.drop_range(0, end);
+            #[cfg(feature = "mlock")]
+            if self.locked {
+                self.unlock_pages();
+            }
+            #[cfg(feature = "numa")]
+            if self.numa.is_some() {
+                let _ = self.apply_numa_policy(None);
+            }
+            Param::dealloc(self.storage, self.capacity);
+        }
+    }
+}
+
+impl<Param: ParallelParam> From<Vec<Param>> for ParallelVec<Param> {
+    fn from(mut value: Vec<Param>) -> Self {
+        let len = value.len();
+        let mut parallel_vec = Self::with_capacity(len);
+        // SAFE: This is a move. The source Vec's length is zeroed before the
+        // rows are moved out so that it only frees its allocation when dropped.
+        unsafe {
+            value.set_len(0);
+            Param::from_rows(value.as_ptr(), Param::as_ptr(parallel_vec.storage), len);
+            parallel_vec.len = len;
+        }
+        parallel_vec
+    }
+}
+
+impl<Param: ParallelParam> From<ParallelVec<Param>> for Vec<Param> {
+    fn from(mut value: ParallelVec<Param>) -> Self {
+        let len = value.len;
+        let mut vec = Vec::with_capacity(len);
+        // SAFE: This is a move. The source ParallelVec's length is zeroed
+        // before the rows are moved out so that it only frees its allocation
+        // when dropped.
+        unsafe {
+            value.len = 0;
+            Param::into_rows(Param::as_ptr(value.storage), vec.as_mut_ptr(), len);
+            vec.set_len(len);
+        }
+        vec
+    }
+}
+
+impl<'a, Param: ParallelParam> PartialEq for ParallelVec<Param>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        if self.storage == other.storage {
+            // Pointing to the same storage. Shortcut out.
+            return true;
+        }
+        self.iter().zip(other.iter()).all(|(a, b)| a.eq(&b))
+    }
+}
+
+impl<'b, Param> PartialEq<&'b [Param]> for ParallelVec<Param>
+where
+    Param: ParallelParam,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &&'b [Param]) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
+impl<Param> PartialEq<Vec<Param>> for ParallelVec<Param>
+where
+    Param: ParallelParam,
+    for<'r> Param::Ref<'r>: PartialEq,
+{
+    fn eq(&self, other: &Vec<Param>) -> bool {
+        rows_eq(self.iter(), other)
+    }
+}
+
+impl<'a, Param: ParallelParam> Eq for ParallelVec<Param>
+where
+    Param: 'a,
+    Param::Ref<'a>: Eq,
+{
+}
+
+impl<'a, Param: ParallelParam> Debug for ParallelVec<Param>
+where
+    Param: 'a,
+    Param::Ref<'a>: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt.write_str("ParallelVec")?;
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, Param: ParallelParam> Hash for ParallelVec<Param>
+where
+    Param: 'a,
+    Param::Ref<'a>: Hash,
+{
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(hasher);
+    }
+}
+
+impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Param>,
+    {
+        let iter = iter.into_iter();
+        // When the exact length is known up front, allocate exactly that much
+        // instead of rounding up to the next power of two.
+        let mut parallel_vec = match iter.size_hint() {
+            (min, Some(max)) if min == max => Self::with_capacity(min),
+            _ => Self::new(),
+        };
+        parallel_vec.extend_desugared(iter);
+        parallel_vec
+    }
+}
+
+impl<Param: ParallelParam> FromIterator<ParallelVec<Param>> for ParallelVec<Param> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = ParallelVec<Param>>,
+    {
+        Self::concat(iter)
+    }
+}
+
+impl<Param: ParallelParam> IntoIterator for ParallelVec<Param> {
+    type Item = Param;
+    type IntoIter = IntoIter<Param>;
+    #[cfg_attr(not(any(feature = "mlock", feature = "numa")), allow(unused_mut))]
+    fn into_iter(mut self) -> Self::IntoIter {
+        #[cfg(feature = "mlock")]
+        self.unlock_memory();
+        #[cfg(feature = "numa")]
+        self.clear_numa_policy();
+        let iter = IntoIter {
+            storage: self.storage,
+            capacity: self.capacity,
+            len: self.len,
+            idx: 0,
+            _marker: PhantomData,
+        };
+        core::mem::forget(self);
+        iter
+    }
+}
+
+impl<'a, Param: ParallelParam> IntoIterator for &'a ParallelVec<Param> {
+    type Item = Param::Ref<'a>;
+    type IntoIter = Iter<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Param: ParallelParam> IntoIterator for &'a mut ParallelVec<Param> {
+    type Item = Param::RefMut<'a>;
+    type IntoIter = IterMut<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Param: ParallelParam> Extend<Param> for ParallelVec<Param> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = Param>,
+    {
+        self.extend_desugared(iter.into_iter());
+    }
+}
+
+impl<'a, Param: ParallelParam + Copy + 'a> Extend<&'a Param> for ParallelVec<Param> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = &'a Param>,
+    {
+        self.extend_desugared(iter.into_iter().copied());
+    }
+}
+
+impl<Param: ParallelParam + Clone> Clone for ParallelVec<Param> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::with_capacity(self.len);
+        // The slices of a ParallelVec are always the same length.
+        let _ = clone.extend_from_slices(self.as_slices());
+        clone
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+        let len = self.len;
+        self.clone_from_slices(.., source.index(..len).as_slices());
+        let _ = self.extend_from_slices(source.index(len..).as_slices());
+    }
+}
+
+impl<Param: ParallelParam> Default for ParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam> Deref for ParallelVec<Param> {
+    type Target = ParallelSliceMut<'static, Param>;
+    fn deref(&self) -> &Self::Target {
+        // SAFE: Both ParallelVec and ParallelSliceMut have the same
+        // layout in memory due to #[repr(C)]
+        unsafe {
+            let ptr: *const Self = self;
+            &*(ptr.cast::<Self::Target>())
+        }
+    }
+}
+
+impl<Param: ParallelParam> DerefMut for ParallelVec<Param> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFE: Both ParallelVec and ParallelSliceMut have the same
+        // layout in memory due to #[repr(C)]
+        unsafe {
+            let ptr: *mut Self = self;
+            &mut *(ptr.cast::<Self::Target>())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelVec;
+    use crate::{PackedBools, ParallelVecConversionError, PermutationError, TryReserveError};
+    use std::boxed::Box;
+    use std::convert::From;
+    use std::rc::Rc;
+    use std::string::String;
     use std::vec::Vec;
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST;
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct Zst;
+
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct Zst2;
+
+    #[test]
+    fn layouts_do_not_overlap() {
+        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
+        // This verifies we correctly chunk the slices from the larger allocations.
+        let mut vec_ab = ParallelVec::new();
+        let mut vec_ba = ParallelVec::new();
+
+        fn ab(v: usize) -> (u8, f64) {
+            (v as u8, 200.0 + ((v as f64) / 200.0))
+        }
+
+        fn ba(v: usize) -> (f64, u8) {
+            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
+        }
+
+        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
+        for i in 0..100 {
+            vec_ab.push(ab(i));
+            let (a, b) = vec_ab.as_slices();
+            assert_eq!(i + 1, a.len());
+            assert_eq!(i + 1, b.len());
+            assert_eq!(ab(0).0, a[0]);
+            assert_eq!(ab(0).1, b[0]);
+            assert_eq!(ab(i).0, a[i]);
+            assert_eq!(ab(i).1, b[i]);
+
+            vec_ba.push(ba(i));
+            let (b, a) = vec_ba.as_slices();
+            assert_eq!(i + 1, a.len());
+            assert_eq!(i + 1, b.len());
+            assert_eq!(ba(0).0, b[0]);
+            assert_eq!(ba(0).1, a[0]);
+            assert_eq!(ba(i).0, b[i]);
+            assert_eq!(ba(i).1, a[i]);
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::new();
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), 0);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_default() {
+        let src: ParallelVec<(i32, i32, u64)> = Default::default();
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), 0);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::with_capacity(1000);
+        assert_eq!(src.len(), 0);
+        assert!(src.capacity() >= 1000);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut src = ParallelVec::new();
+        src.push((0, 0, 0, 0));
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 1);
+        src.reserve(10);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 10);
+        src.reserve(100);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 100);
+        src.reserve(1000);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 1000);
+        src.reserve(100000);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 10000);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut src = ParallelVec::new();
+        src.push((1.0, 2.0));
+        src.push((3.0, 4.0));
+
+        let dst = src.clone();
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.index(0), (&1.0, &2.0));
+        assert_eq!(dst.index(1), (&3.0, &4.0));
+    }
+
+    #[test]
+    fn test_works_with_zsts() {
+        let mut src = ParallelVec::new();
+        src.push((1, Zst, 20u64, Zst2));
+        src.push((1, Zst, 21u64, Zst2));
+        src.push((1, Zst, 22u64, Zst2));
+        src.push((1, Zst, 23u64, Zst2));
+        assert_eq!(src.index(0), (&1, &Zst, &20u64, &Zst2));
+        assert_eq!(src.index(1), (&1, &Zst, &21u64, &Zst2));
+        assert_eq!(src.index(2), (&1, &Zst, &22u64, &Zst2));
+        assert_eq!(src.index(3), (&1, &Zst, &23u64, &Zst2));
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        assert_eq!(src.index(0), (&1, &2));
+        assert_eq!(src.len(), 1);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        assert_eq!(src.len(), 4);
+        let value = src.pop();
+        assert_eq!(value, Some((7, 8)));
+        assert_eq!(src.len(), 3);
+        let value = src.pop();
+        assert_eq!(value, Some((5, 6)));
+        assert_eq!(src.len(), 2);
+        let value = src.pop();
+        assert_eq!(value, Some((3, 4)));
+        assert_eq!(src.len(), 1);
+        let value = src.pop();
+        assert_eq!(value, Some((1, 2)));
+        assert_eq!(src.len(), 0);
+        let value = src.pop();
+        assert_eq!(value, None);
+        assert_eq!(src.len(), 0);
+        let value = src.pop();
+        assert_eq!(value, None);
+        assert_eq!(src.len(), 0);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut src = ParallelVec::new();
+        src.insert(0, (1, 2));
+        src.insert(0, (3, 4));
+        src.insert(1, (4, 5));
+        assert_eq!(src.index(0), (&3, &4));
+        assert_eq!(src.index(1), (&4, &5));
+        assert_eq!(src.index(2), (&1, &2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_panics() {
+        let mut src = ParallelVec::new();
+        src.insert(0, (1, 2));
+        src.insert(0, (3, 4));
+        src.insert(1, (4, 5));
+        src.insert(20, (4, 5));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        assert_eq!(src.remove(1), Some((3, 4)));
+        assert_eq!(src.remove(0), Some((1, 2)));
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.remove(5), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter();
+        assert_eq!(iter.next(), Some((&1, &2)));
+        assert_eq!(iter.next(), Some((&3, &4)));
+        assert_eq!(iter.next(), Some((&5, &6)));
+        assert_eq!(iter.next(), Some((&7, &8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_iters() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
+        let (mut a, mut b) = src.iters();
+        assert_eq!(a.next(), Some(&1));
+        assert_eq!(a.next(), Some(&3));
+        assert_eq!(a.next(), Some(&5));
+        assert_eq!(a.next(), Some(&7));
+        assert_eq!(a.next(), Some(&9));
+        assert_eq!(a.next(), Some(&11));
+        assert_eq!(a.next(), None);
+        assert_eq!(a.next(), None);
+        assert_eq!(b.next(), Some(&2));
+        assert_eq!(b.next(), Some(&4));
+        assert_eq!(b.next(), Some(&6));
+        assert_eq!(b.next(), Some(&8));
+        assert_eq!(b.next(), Some(&10));
+        assert_eq!(b.next(), Some(&12));
+        assert_eq!(b.next(), None);
+        assert_eq!(b.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
+        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
+        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
+        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to(200);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 200);
+        src.shrink_to(100);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 100);
+        src.shrink_to(10);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 10);
+        src.shrink_to(1);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 4);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_releases_spike() {
+        let rc = Rc::new(());
+        let mut src: ParallelVec<_> = (0..1000).map(|idx| (idx, rc.clone(), Zst)).collect();
+        src.truncate(3);
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 3);
+        assert!(src.iter().map(|(idx, _, _)| *idx).eq(0..3));
+        src.clear();
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 0);
+        assert_eq!(Rc::strong_count(&rc), 1);
+        src.push((7, rc.clone(), Zst));
+        assert_eq!(src.index(0).0, &7);
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut src = ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        src.reserve_exact(10);
+        assert_eq!(src.capacity(), 13);
+        src.reserve_exact(5);
+        assert_eq!(src.capacity(), 13);
+        src.extend((0..10).map(|idx| (idx, 'z')));
+        assert_eq!(src.capacity(), 13);
+        assert_eq!(src.index(2), (&3, &'c'));
+    }
+
+    #[test]
+    fn test_try_reserve() {
+        let mut src = ParallelVec::from(vec![(1u8, 2u64), (3, 4)]);
+        assert_eq!(
+            src.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert!(src.try_reserve_exact(usize::MAX / 4).is_err());
+        assert_eq!(
+            src.try_reserve(usize::MAX / 2),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(src, vec![(1, 2), (3, 4)]);
+        src.try_reserve(10).unwrap();
+        assert_eq!(src.capacity(), 16);
+        src.try_reserve_exact(20).unwrap();
+        assert_eq!(src.capacity(), 22);
+        assert_eq!(src, vec![(1, 2), (3, 4)]);
+        let mut zst = ParallelVec::<(Zst, Zst)>::new();
+        zst.try_reserve_exact(usize::MAX).unwrap();
+    }
+
+    #[test]
+    fn test_try_with_capacity() {
+        let src = ParallelVec::<(u32, u64)>::try_with_capacity(10).unwrap();
+        assert_eq!((src.len(), src.capacity()), (0, 10));
+        assert_eq!(
+            ParallelVec::<(u32, u64)>::try_with_capacity(usize::MAX / 4).err(),
+            Some(TryReserveError::CapacityOverflow)
+        );
+        assert!(ParallelVec::<(u8, u8)>::try_with_capacity(usize::MAX / 4).is_err());
+        assert_eq!(
+            ParallelVec::<(u8, u8)>::try_with_capacity(0)
+                .unwrap()
+                .capacity(),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_with_capacity_overflow() {
+        ParallelVec::<(u32, u64)>::with_capacity(usize::MAX / 4);
+    }
+
+    #[test]
+    fn test_push_within_capacity() {
+        let mut src = ParallelVec::<(u32, String)>::with_capacity(2);
+        assert_eq!(src.push_within_capacity((1, String::from("a"))), Ok(()));
+        assert_eq!(src.push_within_capacity((2, String::from("b"))), Ok(()));
+        assert_eq!(
+            src.push_within_capacity((3, String::from("c"))),
+            Err((3, String::from("c")))
+        );
+        assert_eq!((src.len(), src.capacity()), (2, 2));
+        assert_eq!(src.index(1), (&2, &String::from("b")));
+        let mut empty = ParallelVec::<(u8, u8)>::new();
+        assert_eq!(empty.push_within_capacity((1, 1)), Err((1, 1)));
+    }
+
+    #[test]
+    fn test_leak() {
+        let src = ParallelVec::from(vec![(1u32, String::from("a")), (2, String::from("b"))]);
+        let (numbers, strings): (&'static mut [u32], &'static mut [String]) = src.leak();
+        numbers[0] += 10;
+        strings[1].push('c');
+        assert_eq!(numbers, &[11, 2]);
+        assert_eq!(strings, &[String::from("a"), String::from("bc")]);
+    }
+
+    #[test]
+    fn test_spare_capacity_mut() {
+        let mut src = ParallelVec::<(u32, String)>::with_capacity(4);
+        src.push((0, String::from("0")));
+        let (a, b) = src.spare_capacity_mut();
+        assert_eq!((a.len(), b.len()), (3, 3));
+        for (idx, (a, b)) in a.iter_mut().zip(b.iter_mut()).take(2).enumerate() {
+            a.write(idx as u32 + 1);
+            b.write(format!("{}", idx + 1));
+        }
+        unsafe { src.set_len(3) };
+        let expected: Vec<_> = (0..3u32).map(|idx| (idx, format!("{}", idx))).collect();
+        assert_eq!(src, expected);
+        assert_eq!(src.spare_capacity_mut().0.len(), 1);
+    }
+
+    #[test]
+    fn test_as_ptrs() {
+        let mut src = ParallelVec::from(vec![(1u32, 2u64), (3, 4), (5, 6)]);
+        let (a, b) = src.as_ptrs();
+        assert_eq!(unsafe { (*a.add(2), *b.add(1)) }, (5, 4));
+        let (a, b) = src.as_mut_ptrs();
+        unsafe {
+            *a.add(1) = 30;
+            *b = 20;
+        }
+        assert_eq!(src.as_slices(), (&[1, 30, 5][..], &[20, 4, 6][..]));
+        assert_eq!(src.index(1..).as_ptrs().0, unsafe { a.add(1) });
+    }
+
+    #[test]
+    fn test_extend_from_slices() {
+        let mut src = ParallelVec::from(vec![(0u32, String::from("0"))]);
+        let names = [String::from("1"), String::from("2")];
+        src.extend_from_slices((&[1, 2], &names)).unwrap();
+        assert_eq!(
+            src.extend_from_slices((&[3], &names)),
+            Err(ParallelVecConversionError::UnevenLengths)
+        );
+        let expected: Vec<_> = (0..3u32).map(|idx| (idx, format!("{}", idx))).collect();
+        assert_eq!(src, expected);
+        assert_eq!(names[1], "2");
+        src.extend_from_slices((&[], &[])).unwrap();
+        assert_eq!(src.len(), 3);
+    }
+
+    #[test]
+    fn test_extend_from_slices_copied() {
+        let mut src = ParallelVec::from(vec![(0u32, 0.0f32)]);
+        src.extend_from_slices_copied((&[1, 2], &[1.0, 2.0]))
+            .unwrap();
+        assert_eq!(
+            src.extend_from_slices_copied((&[3], &[])),
+            Err(ParallelVecConversionError::UnevenLengths)
+        );
+        assert_eq!(src, vec![(0, 0.0), (1, 1.0), (2, 2.0)]);
+    }
+
+    #[test]
+    fn test_clone_boxed_rows() {
+        let src = ParallelVec::from(vec![(Box::new(1u32), 1u8), (Box::new(2), 2)]);
+        let mut dst = src.clone();
+        dst.extend_from_within(..);
+        **dst.index_mut(0).0 = 10;
+        assert_eq!(
+            dst.as_slices().0,
+            &[Box::new(10), Box::new(2), Box::new(1), Box::new(2)]
+        );
+        assert_eq!(
+            src.gather(&[1, 0]).as_slices().0,
+            &[Box::new(2), Box::new(1)]
+        );
+        assert_eq!(src.as_slices().0, &[Box::new(1), Box::new(2)]);
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let mut dst = ParallelVec::from(vec![(0u32, 0u8), (1, 1)]);
+        let src = ParallelVec::from(vec![(10u32, 10u8), (11, 11), (12, 12), (13, 13)]);
+        dst.copy_from(&src, 1..3, 1);
+        assert_eq!(dst, vec![(0, 0), (11, 11), (12, 12), (1, 1)]);
+        dst.copy_from(&src, ..1, dst.len());
+        dst.copy_from(&src, 2.., 0);
+        assert_eq!(dst.as_slices().0, &[12, 13, 0, 11, 12, 1, 10]);
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_copy_from_out_of_bounds() {
+        let mut dst = ParallelVec::from(vec![(0u32, 0u8)]);
+        let src = dst.clone();
+        dst.copy_from(&src, .., 2);
+    }
+
+    #[test]
+    fn test_copy_within() {
+        let mut src: ParallelVec<_> = (0..6u32).map(|idx| (idx, idx as u8)).collect();
+        src.copy_within(1..4, 2);
+        assert_eq!(
+            src.as_slices(),
+            (&[0, 1, 1, 2, 3, 5][..], &[0, 1, 1, 2, 3, 5][..])
+        );
+        src.copy_within(3.., 0);
+        assert_eq!(src.as_slices().0, &[2, 3, 5, 2, 3, 5]);
+        src.index_mut(1..4).copy_within(..1, 2);
+        assert_eq!(src.as_slices().1, &[2, 3, 5, 3, 3, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dest is out of bounds")]
+    fn test_copy_within_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(0u32, 0u8), (1, 1), (2, 2)]);
+        src.copy_within(1.., 2);
+    }
+
+    #[test]
+    fn test_clone_from_slices() {
+        let mut src: ParallelVec<_> = (0..5u32).map(|idx| (idx, Rc::new(idx))).collect();
+        let tracked = Rc::clone(src.index(2).1);
+        let rcs = [Rc::new(20), Rc::new(30)];
+        src.clone_from_slices(2..4, (&[20, 30], &rcs));
+        assert_eq!(Rc::strong_count(&tracked), 1);
+        assert_eq!(Rc::strong_count(&rcs[0]), 2);
+        let expected: Vec<_> = [0, 1, 20, 30, 4]
+            .iter()
+            .map(|&idx| (idx, Rc::new(idx)))
+            .collect();
+        assert_eq!(src, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length as the destination range")]
+    fn test_clone_from_slices_uneven() {
+        let mut src = ParallelVec::from(vec![(0u32, 0u8), (1, 1), (2, 2)]);
+        src.clone_from_slices(1.., (&[5, 6], &[5]));
+    }
+
+    #[test]
+    fn test_clone_drops_once() {
+        let src: ParallelVec<_> = (0..5u32).map(|idx| (idx, Rc::new(idx))).collect();
+        let clone = src.clone();
+        assert_eq!(clone, src);
+        assert_eq!(Rc::strong_count(src.index(3).1), 2);
+        drop(clone);
+        assert_eq!(Rc::strong_count(src.index(3).1), 1);
+
+        let mut dst: ParallelVec<_> = (0..8u32).map(|idx| (idx, Rc::new(idx * 10))).collect();
+        dst.clone_from(&src);
+        assert_eq!(dst, src);
+        let mut dst: ParallelVec<_> = (0..2u32).map(|idx| (idx, Rc::new(idx * 10))).collect();
+        dst.clone_from(&src);
+        assert_eq!(dst, src);
+        assert_eq!(Rc::strong_count(src.index(4).1), 3);
+    }
+
+    #[test]
+    fn test_swap_ranges() {
+        let mut src: ParallelVec<_> = (0..8u32).map(|idx| (idx, format!("{}", idx))).collect();
+        src.swap_ranges(0..3, 5..);
+        assert_eq!(src.as_slices().0, &[5, 6, 7, 3, 4, 0, 1, 2]);
+        assert_eq!(src.index(6).1, "1");
+        src.swap_ranges(3..3, 3..3);
+        src.swap_ranges(3..4, 4..5);
+        assert_eq!(src.as_slices().0, &[5, 6, 7, 4, 3, 0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ranges must not overlap")]
+    fn test_swap_ranges_overlapping() {
+        let mut src = ParallelVec::from(vec![(0u32, 0u8), (1, 1), (2, 2)]);
+        src.swap_ranges(0..2, 1..3);
+    }
+
+    #[test]
+    fn test_insert_from_slices() {
+        let mut src: ParallelVec<_> = (0..4u32).map(|idx| (idx, format!("{}", idx))).collect();
+        let names = [String::from("a"), String::from("b")];
+        src.insert_from_slices(1, (&[10, 11], &names)).unwrap();
+        src.insert_from_slices(src.len(), (&[12], &names[..1]))
+            .unwrap();
+        assert_eq!(src.as_slices().0, &[0, 10, 11, 1, 2, 3, 12]);
+        assert_eq!(src.index(2).1, "b");
+        assert_eq!(src.index(3).1, "1");
+        assert_eq!(
+            src.insert_from_slices(0, (&[1], &names)),
+            Err(ParallelVecConversionError::UnevenLengths)
+        );
+        assert_eq!(src.len(), 7);
+    }
+
+    #[test]
+    fn test_insert_from_slices_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        struct Bomb(Rc<u32>);
+        impl Clone for Bomb {
+            fn clone(&self) -> Self {
+                assert!(*self.0 != 0, "boom");
+                Bomb(Rc::clone(&self.0))
+            }
+        }
+        let tracked = Rc::new(1);
+        let mut src: ParallelVec<_> = (0..3u32)
+            .map(|idx| (idx, Bomb(Rc::clone(&tracked))))
+            .collect();
+        let bombs = [Bomb(Rc::clone(&tracked)), Bomb(Rc::new(0))];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            src.insert_from_slices(1, (&[10, 11], &bombs))
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.as_slices().0, &[0, 10, 1, 2]);
+        drop(src);
+        assert_eq!(Rc::strong_count(&tracked), 2);
+    }
+
+    #[test]
+    fn test_remove_range() {
+        let tracked = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..8u32).map(|idx| (idx, Rc::clone(&tracked))).collect();
+        src.remove_range(2..5);
+        assert_eq!(src.as_slices().0, &[0, 1, 5, 6, 7]);
+        assert_eq!(Rc::strong_count(&tracked), 6);
+        src.remove_range(3..3);
+        src.remove_range(3..);
+        assert_eq!(src.as_slices().0, &[0, 1, 5]);
+        src.remove_range(..);
+        assert!(src.is_empty());
+        assert_eq!(Rc::strong_count(&tracked), 1);
+    }
+
+    #[test]
+    fn test_clear_without_drop() {
+        let mut src: ParallelVec<_> = (0..1000u32)
+            .map(|idx| (idx as f32, idx as f32, idx))
+            .collect();
+        let capacity = src.capacity();
+        src.truncate(10);
+        assert_eq!(src.len(), 10);
+        assert_eq!(src.index(9), (&9.0, &9.0, &9));
+        src.clear();
+        assert!(src.is_empty());
+        assert_eq!(src.capacity(), capacity);
+
+        let tracked = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..10u32).map(|idx| (idx, Rc::clone(&tracked))).collect();
+        src.clear();
+        assert_eq!(Rc::strong_count(&tracked), 1);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to_fit();
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= src.len());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.truncate(2);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3]);
+            assert_eq!(b, &[2, 4]);
+            assert_eq!(src.len(), 2);
+            assert!(src.capacity() >= 4);
+        }
+    }
+
+    #[test]
+    fn test_truncate_drops() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+        ]);
+        assert_eq!(Rc::strong_count(&rc), 9);
+        src.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 3);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.reverse();
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[7, 5, 3, 1]);
+            assert_eq!(b, &[8, 6, 4, 2]);
+        }
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.clear();
+        assert_eq!(src.len(), 0);
+        assert!(src.capacity() > 0);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[]);
+        assert_eq!(b, &[]);
+    }
+
+    #[test]
+    fn test_repeat() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let repeated = src.repeat(3);
+        let (a, b) = repeated.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8]);
+        let (a, b) = src.as_slices();
+        assert_eq!(src.len(), 4);
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+        assert_eq!(repeated.len(), 12);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let b = ParallelVec::from(vec![(1, 2), (3, 4), (9, 6), (7, 8)]);
+        let c = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        assert!(a == a);
+        assert!(a != b);
+        assert!(a == c);
+        assert!(b != a);
+        assert!(b == b);
+        assert!(b != c);
+        assert!(c == a);
+        assert!(c != b);
+        assert!(c == c);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let src: Vec<_> = (0..10000u32).map(|i| (i, i as u64 * 2, i as u8)).collect();
+        let dst = ParallelVec::from(src);
+        assert_eq!(dst.len(), 10000);
+        let (a, b, c) = dst.as_slices();
+        for i in 0..10000 {
+            assert_eq!(a[i], i as u32);
+            assert_eq!(b[i], i as u64 * 2);
+            assert_eq!(c[i], i as u8);
+        }
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let src = ParallelVec::from(vec![(1, Zst, 2.0), (3, Zst, 4.0), (5, Zst, 6.0)]);
+        let dst: Vec<_> = src.into();
+        assert_eq!(dst, vec![(1, Zst, 2.0), (3, Zst, 4.0), (5, Zst, 6.0)]);
+    }
+
+    #[test]
+    fn test_from_vec_does_not_drop() {
+        let rc = Rc::new(0);
+        let src = vec![(rc.clone(), 1), (rc.clone(), 2), (rc.clone(), 3)];
+        let parallel = ParallelVec::from(src);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        let vec: Vec<_> = parallel.into();
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_into_vecs() {
+        let rc = Rc::new(0);
+        let src = ParallelVec::from(vec![(1, rc.clone()), (2, rc.clone()), (3, rc.clone())]);
+        let (a, b) = src.into_vecs();
+        assert_eq!(a, vec![1, 2, 3]);
+        assert_eq!(b.len(), 3);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(b);
+        assert_eq!(Rc::strong_count(&rc), 1);
+        let src: ParallelVec<(u8, Zst)> = ParallelVec::new();
+        let (a, b) = src.into_vecs();
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_vecs() {
+        let src = ParallelVec::try_from((vec![1, 2, 3], vec![4.0, 5.0, 6.0])).unwrap();
+        assert_eq!(src.len(), 3);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 2, 3]);
+        assert_eq!(b, &[4.0, 5.0, 6.0]);
+        let err = ParallelVec::try_from((vec![1, 2, 3], vec![4.0, 5.0]));
+        assert_eq!(err, Err(crate::ParallelVecConversionError::UnevenLengths));
+    }
+
+    #[test]
+    fn test_try_into_arrays() {
+        let rc = Rc::new(0);
+        let src = ParallelVec::from(vec![(1, rc.clone()), (2, rc.clone()), (3, rc.clone())]);
+        let (a, b): ([i32; 3], [Rc<i32>; 3]) = src.try_into().unwrap();
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(b);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        let err: Result<([i32; 3], [i32; 3]), _> = src.try_into();
+        assert_eq!(err.unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_as_arrays() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        assert_eq!(src.as_arrays::<3>(), Some((&[1, 3, 5], &[2, 4, 6])));
+        assert_eq!(src.as_arrays::<2>(), None);
+        assert_eq!(src.index(1..3).as_arrays::<2>(), Some((&[3, 5], &[4, 6])));
+        let (a, _) = src.as_arrays_mut::<3>().unwrap();
+        a[0] = 10;
+        assert_eq!(src.index(0), (&10, &2));
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_zeroed() {
+        let mut src = ParallelVec::<(u32, f64, [u8; 3])>::zeroed(1000);
+        assert_eq!((src.len(), src.capacity()), (1000, 1000));
+        assert!(src.iter().all(|row| row == (&0, &0.0, &[0; 3])));
+        src.push((1, 2.0, [3; 3]));
+        assert_eq!(src.index(1000), (&1, &2.0, &[3; 3]));
+        assert!(ParallelVec::<(u8, u16)>::zeroed(0).is_empty());
+        assert_eq!(ParallelVec::<((), u16)>::zeroed(3).as_slices().0.len(), 3);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "bytemuck"))]
+    fn test_io_slices() {
+        use std::io::{Read, Write};
+        let src = ParallelVec::from(vec![(1u32, 2u8, 3.0f64), (4, 5, 6.0)]);
+        let mut bytes = Vec::new();
+        let written = bytes.write_vectored(src.as_io_slices().as_ref()).unwrap();
+        assert_eq!(written, 2 * (4 + 1 + 8));
+        assert_eq!(&bytes[..4], &1u32.to_ne_bytes());
+        assert_eq!(&bytes[8..10], &[2, 5]);
+
+        let mut dst = ParallelVec::from(vec![(0u32, 0u8, 0.0f64); 2]);
+        let read = bytes
+            .as_slice()
+            .read_vectored(dst.as_io_slices_mut().as_mut())
+            .unwrap();
+        assert_eq!(read, written);
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    #[cfg(feature = "mlock")]
+    fn test_lock_memory() {
+        let mut src = ParallelVec::<(u64, u8)>::with_capacity_locked(4).unwrap();
+        assert!(src.is_memory_locked());
+        for idx in 0..1000 {
+            src.push((idx, idx as u8));
+        }
+        assert!(src.is_memory_locked());
+        src.shrink_to_fit();
+        assert!(src.is_memory_locked());
+        src.unlock_memory();
+        assert!(!src.is_memory_locked());
+        src.lock_memory().unwrap();
+        assert_eq!(src.into_iter().count(), 1000);
+    }
+
+    #[test]
+    fn test_cross_type_eq() {
+        let src = ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(src, vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(src, &[(1, 'a'), (2, 'b'), (3, 'c')][..]);
+        assert_eq!(src, (&[1, 2, 3][..], &['a', 'b', 'c'][..]));
+        assert_ne!(src, vec![(1, 'a'), (2, 'b')]);
+        assert_ne!(src, vec![(1, 'a'), (2, 'b'), (3, 'd')]);
+        assert_ne!(src, (&[1, 2][..], &['a', 'b'][..]));
+        assert!(src.index(1..) == vec![(2, 'b'), (3, 'c')]);
+        assert!(src.index(..1) == &[(1, 'a')][..]);
+        assert!(src.index(..2) == (&[1, 2][..], &['a', 'b'][..]));
+    }
+
+    #[test]
+    fn test_extend_underestimated_size_hint() {
+        let mut src = ParallelVec::new();
+        src.push((0, 0));
+        src.extend((1..100).filter(|x| x % 3 != 0).map(|x| (x, x * 2)));
+        assert_eq!(src.len(), 67);
+        let (a, b) = src.as_slices();
+        assert!(a.iter().zip(b).all(|(a, b)| *a * 2 == *b));
+        let collected: ParallelVec<_> = (0..10).filter(|x| x % 2 == 0).map(|x| (x, x)).collect();
+        assert_eq!(collected.len(), 5);
+        let mut dst = ParallelVec::new();
+        dst.extend_from_slice(&[(1, 'a'), (2, 'b')]);
+        assert_eq!(dst, vec![(1, 'a'), (2, 'b')]);
+        dst.extend(&[(3, 'c'), (4, 'd')]);
+        assert_eq!(dst.as_slices().1, &['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn test_collect_exact_size() {
+        let src: ParallelVec<_> = (0..1000u32).map(|x| (x, x as u8)).collect();
+        assert_eq!((src.len(), src.capacity()), (1000, 1000));
+        let src: ParallelVec<_> = (0..1000u32)
+            .filter(|x| x % 2 == 0)
+            .map(|x| (x, x as u8))
+            .collect();
+        assert_eq!(src.len(), 500);
+        assert_eq!(src.index(499), (&998, &230));
+        let src: ParallelVec<_> = core::iter::empty::<(u32, u8)>().collect();
+        assert_eq!(src.capacity(), 0);
+    }
+
+    #[test]
+    fn test_extend_reserves_from_size_hint() {
+        let mut src = ParallelVec::with_capacity(4);
+        src.push((0u32, 0u64));
+        src.extend((1..1000).map(|x| (x, x as u64)));
+        assert_eq!(src.len(), 1000);
+        assert_eq!(src.capacity(), 1024);
+        assert!(src.iter().all(|(a, b)| *a as u64 == *b));
+    }
+
+    #[test]
+    fn test_extend_panic_keeps_written_rows() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.extend((0..10).map(|x| {
+                if x == 5 {
+                    panic!();
+                }
+                (rc.clone(), x)
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.len(), 5);
+        assert_eq!(Rc::strong_count(&rc), 6);
+        drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_for_each() {
+        let mut src = ParallelVec::from(vec![(1, 2.0, 'a'), (2, 3.0, 'b'), (3, 4.0, 'c')]);
+        src.for_each_mut(|(a, b, _)| *b *= *a as f64);
+        let mut rows = Vec::new();
+        src.for_each(|(a, b, c)| rows.push((*a, *b, *c)));
+        assert_eq!(rows, vec![(1, 2.0, 'a'), (2, 6.0, 'b'), (3, 12.0, 'c')]);
+        let mut sum = 0;
+        src.index(1..).for_each(|(a, _, _)| sum += *a);
+        assert_eq!(sum, 5);
+    }
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST2;
+    #[test]
+    fn test_sort_by() {
+        let mut src = ParallelVec::from(vec![(3, 'a'), (1, 'b'), (2, 'c'), (5, 'd'), (4, 'e')]);
+        src.sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(src, vec![(1, 'b'), (2, 'c'), (3, 'a'), (4, 'e'), (5, 'd')]);
+        src.sort_unstable_by_key(|(_, c)| *c);
+        assert_eq!(src, vec![(3, 'a'), (1, 'b'), (2, 'c'), (5, 'd'), (4, 'e')]);
+        src.sort_by_key(|(a, _)| core::cmp::Reverse(*a));
+        assert_eq!(src, vec![(5, 'd'), (4, 'e'), (3, 'a'), (2, 'c'), (1, 'b')]);
+    }
 
     #[test]
-    fn layouts_do_not_overlap() {
-        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
-        // This verifies we correctly chunk the slices from the larger allocations.
-        let mut vec_ab = ParallelVec::new();
-        let mut vec_ba = ParallelVec::new();
+    fn test_sort_by_is_stable() {
+        let mut src: ParallelVec<_> = (0..100).map(|idx| (idx % 7, idx, [idx; 4])).collect();
+        let mut comparisons = 0;
+        src.sort_by(|(a, _, _), (b, _, _)| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(comparisons > 0);
+        assert!(src.is_sorted_by(|(a, x, _), (b, y, _)| (a, x) <= (b, y)));
+        assert!(src.iter().all(|(_, idx, arr)| *arr == [*idx; 4]));
+    }
 
-        fn ab(v: usize) -> (u8, f64) {
-            (v as u8, 200.0 + ((v as f64) / 200.0))
-        }
+    #[test]
+    fn test_sort_unstable_by() {
+        let mut src: ParallelVec<_> = (0..200u32)
+            .map(|idx| {
+                (
+                    idx.wrapping_mul(2654435761) % 31,
+                    String::from("x").repeat(idx as usize % 5),
+                )
+            })
+            .collect();
+        src.sort_unstable_by(|(a, x), (b, y)| a.cmp(b).then(x.len().cmp(&y.len())));
+        assert!(src.is_sorted_by(|(a, x), (b, y)| (a, x.len()) <= (b, y.len())));
+        assert_eq!(src.len(), 200);
+    }
 
-        fn ba(v: usize) -> (f64, u8) {
-            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
-        }
+    #[test]
+    fn test_sort_by_key_column() {
+        let mut src: ParallelVec<_> = (0..50u32)
+            .map(|idx| (idx % 4, String::from("payload").repeat(idx as usize), idx))
+            .collect();
+        let mut calls = 0;
+        src.sort_by_key(|(depth, _, _)| {
+            calls += 1;
+            *depth
+        });
+        assert!(calls >= 50);
+        assert!(src.is_sorted_by(|(a, _, x), (b, _, y)| (a, x) <= (b, y)));
+        assert!(src
+            .iter()
+            .all(|(_, payload, idx)| payload.len() == 7 * *idx as usize));
+    }
 
-        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
-        for i in 0..100 {
-            vec_ab.push(ab(i));
-            let (a, b) = vec_ab.as_slices();
-            assert_eq!(i + 1, a.len());
-            assert_eq!(i + 1, b.len());
-            assert_eq!(ab(0).0, a[0]);
-            assert_eq!(ab(0).1, b[0]);
-            assert_eq!(ab(i).0, a[i]);
-            assert_eq!(ab(i).1, b[i]);
+    #[test]
+    fn test_sort_by_cached_key() {
+        let mut src: ParallelVec<_> = [30, 4, 100, 4, 7].iter().map(|&k| (k, k * 10)).collect();
+        let mut calls = 0;
+        src.sort_by_cached_key(|(a, _)| {
+            calls += 1;
+            std::string::ToString::to_string(a)
+        });
+        assert_eq!(calls, 5);
+        assert_eq!(src, vec![(100, 1000), (30, 300), (4, 40), (4, 40), (7, 70)]);
+        src.index_mut(2..)
+            .sort_by_cached_key(|(_, b)| core::cmp::Reverse(*b));
+        assert_eq!(src, vec![(100, 1000), (30, 300), (7, 70), (4, 40), (4, 40)]);
+    }
 
-            vec_ba.push(ba(i));
-            let (b, a) = vec_ba.as_slices();
-            assert_eq!(i + 1, a.len());
-            assert_eq!(i + 1, b.len());
-            assert_eq!(ba(0).0, b[0]);
-            assert_eq!(ba(0).1, a[0]);
-            assert_eq!(ba(i).0, b[i]);
-            assert_eq!(ba(i).1, a[i]);
-        }
+    #[test]
+    fn test_select_nth_unstable_by() {
+        let mut src: ParallelVec<_> = [9.0f64, 1.5, 4.0, 7.25, 3.0, 8.0, 2.0]
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| (*v, idx))
+            .collect();
+        let (lower, (median, idx), upper) =
+            src.select_nth_unstable_by(3, |(a, _), (b, _)| a.total_cmp(b));
+        assert_eq!((*median, *idx), (4.0, 2));
+        assert!(lower.column::<0>().iter().all(|v| *v < 4.0));
+        assert!(upper.column::<0>().iter().all(|v| *v > 4.0));
+        assert_eq!((lower.len(), upper.len()), (3, 3));
+        let (lower, _, upper) = src.select_nth_unstable_by(6, |(a, _), (b, _)| a.total_cmp(b));
+        assert_eq!((lower.len(), upper.len()), (6, 0));
+        assert_eq!(src.index(6), (&9.0, &0));
     }
 
     #[test]
-    fn test_new() {
-        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::new();
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.capacity(), 0);
-        assert!(src.is_empty());
+    #[should_panic]
+    fn test_select_nth_unstable_by_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 2)]);
+        src.select_nth_unstable_by(1, |a, b| a.cmp(&b));
     }
 
     #[test]
-    fn test_default() {
-        let src: ParallelVec<(i32, i32, u64)> = Default::default();
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.capacity(), 0);
-        assert!(src.is_empty());
+    fn test_sort_by_keys() {
+        let rows = vec![
+            (2, "b", 0),
+            (1, "c", 1),
+            (2, "a", 2),
+            (1, "c", 3),
+            (2, "a", 4),
+            (0, "z", 5),
+        ];
+        let mut src = ParallelVec::from(rows);
+        let mut second = 0;
+        src.sort_by_keys(|(a, _, _)| *a)
+            .then_by(|(_, b, _)| {
+                second += 1;
+                *b
+            })
+            .sort();
+        assert_eq!(
+            src,
+            vec![
+                (0, "z", 5),
+                (1, "c", 1),
+                (1, "c", 3),
+                (2, "a", 2),
+                (2, "a", 4),
+                (2, "b", 0)
+            ]
+        );
+        assert!(second > 0);
+        src.sort_by_keys(|(_, b, _)| core::cmp::Reverse(*b))
+            .then_by(|(_, _, c)| core::cmp::Reverse(*c))
+            .sort();
+        assert_eq!(
+            src,
+            vec![
+                (0, "z", 5),
+                (1, "c", 3),
+                (1, "c", 1),
+                (2, "b", 0),
+                (2, "a", 4),
+                (2, "a", 2)
+            ]
+        );
     }
 
     #[test]
-    fn test_with_capacity() {
-        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::with_capacity(1000);
-        assert_eq!(src.len(), 0);
-        assert!(src.capacity() >= 1000);
-        assert!(src.is_empty());
+    fn test_sort_by_radix_key() {
+        let mut src: ParallelVec<_> = (0..500u32)
+            .map(|idx| (idx.wrapping_mul(2654435761) % 1000, idx, String::from("x")))
+            .collect();
+        src.sort_by_u32_key(|(key, _, _)| *key);
+        assert!(src.is_sorted_by(|(a, x, _), (b, y, _)| (a, x) <= (b, y)));
+        src.sort_by_u64_key(|(_, idx, _)| u64::MAX - u64::from(*idx));
+        assert!(src.iter().map(|(_, idx, _)| *idx).eq((0..500).rev()));
     }
 
     #[test]
-    fn test_reserve() {
-        let mut src = ParallelVec::new();
-        src.push((0, 0, 0, 0));
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 1);
-        src.reserve(10);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 10);
-        src.reserve(100);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 100);
-        src.reserve(1000);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 1000);
-        src.reserve(100000);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 10000);
+    fn test_kmerge_by_key() {
+        let a = ParallelVec::from(vec![(1, "a0"), (4, "a1"), (4, "a2"), (9, "a3")]);
+        let b = ParallelVec::from(vec![(0, "b0"), (4, "b1"), (5, "b2")]);
+        let c = ParallelVec::from(vec![
+            (2, "c0"),
+            (3, "c1"),
+            (4, "c2"),
+            (10, "c3"),
+            (11, "c4"),
+        ]);
+        let merged = ParallelVec::kmerge_by_key(vec![a, ParallelVec::new(), b, c], |(k, _)| *k);
+        let (_, names) = merged.as_slices();
+        assert_eq!(
+            names,
+            ["b0", "a0", "c0", "c1", "a1", "a2", "b1", "c2", "b2", "a3", "c3", "c4"]
+        );
+        assert_eq!(merged.capacity(), 12);
     }
 
     #[test]
-    fn test_clone() {
-        let mut src = ParallelVec::new();
-        src.push((1.0, 2.0));
-        src.push((3.0, 4.0));
+    fn test_kmerge_by_key_moves_rows() {
+        let rc = Rc::new(());
+        let vecs: Vec<_> = (0..3)
+            .map(|offset| {
+                (0..10)
+                    .map(|idx| (idx * 3 + offset, rc.clone()))
+                    .collect::<ParallelVec<_>>()
+            })
+            .collect();
+        let merged = ParallelVec::kmerge_by_key(vecs, |(k, _)| *k);
+        assert!(merged.iter().map(|(k, _)| *k).eq(0..30));
+        assert_eq!(Rc::strong_count(&rc), 31);
+        drop(merged);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
 
-        let dst = src.clone();
-        assert_eq!(dst.len(), 2);
-        assert_eq!(dst.index(0), (&1.0, &2.0));
-        assert_eq!(dst.index(1), (&3.0, &4.0));
+    #[test]
+    fn test_merge_sorted() {
+        let a = ParallelVec::from(vec![(1, 'a'), (2, 'a'), (2, 'a'), (7, 'a'), (8, 'a')]);
+        let b = ParallelVec::from(vec![(0, 'b'), (2, 'b'), (3, 'b'), (4, 'b'), (9, 'b')]);
+        let merged = a.merge_sorted(b, |(k, _)| *k);
+        assert_eq!(
+            merged,
+            vec![
+                (0, 'b'),
+                (1, 'a'),
+                (2, 'a'),
+                (2, 'a'),
+                (2, 'b'),
+                (3, 'b'),
+                (4, 'b'),
+                (7, 'a'),
+                (8, 'a'),
+                (9, 'b')
+            ]
+        );
+
+        let rc = Rc::new(());
+        let a: ParallelVec<_> = (0..100).map(|idx| (idx * 2, rc.clone())).collect();
+        let b: ParallelVec<_> = (0..50).map(|idx| (idx * 4 + 1, rc.clone())).collect();
+        let merged = a.merge_sorted(b, |(k, _)| *k);
+        assert!(merged.is_sorted_by(|(a, _), (b, _)| a <= b));
+        assert_eq!(Rc::strong_count(&rc), 151);
+        let empty = ParallelVec::new().merge_sorted(merged, |(k, _)| *k);
+        assert_eq!(empty.len(), 150);
     }
 
     #[test]
-    fn test_works_with_zsts() {
-        let mut src = ParallelVec::new();
-        src.push((1, ZST, 20u64, ZST2));
-        src.push((1, ZST, 21u64, ZST2));
-        src.push((1, ZST, 22u64, ZST2));
-        src.push((1, ZST, 23u64, ZST2));
-        assert_eq!(src.index(0), (&1, &ZST, &20u64, &ZST2));
-        assert_eq!(src.index(1), (&1, &ZST, &21u64, &ZST2));
-        assert_eq!(src.index(2), (&1, &ZST, &22u64, &ZST2));
-        assert_eq!(src.index(3), (&1, &ZST, &23u64, &ZST2));
-        assert_eq!(src.len(), 4);
+    fn test_partition() {
+        let mut src: ParallelVec<_> = (0..20).map(|idx| (idx % 3 == 0, idx)).collect();
+        let mut calls = 0;
+        let alive = src.partition(|(alive, _)| {
+            calls += 1;
+            *alive
+        });
+        assert_eq!((alive, calls), (7, 20));
+        assert!(src
+            .index(..alive)
+            .iter()
+            .all(|(alive, idx)| *alive && idx % 3 == 0));
+        assert!(src
+            .index(alive..)
+            .iter()
+            .all(|(alive, idx)| !*alive && idx % 3 != 0));
+        assert_eq!(src.partition(|_| true), 20);
+        assert_eq!(src.partition(|_| false), 0);
+        assert_eq!(ParallelVec::<(u8, u8)>::new().partition(|_| true), 0);
     }
 
     #[test]
-    fn test_push() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        assert_eq!(src.index(0), (&1, &2));
-        assert_eq!(src.len(), 1);
+    fn test_stable_partition() {
+        let mut src: ParallelVec<_> = (0..20).map(|idx| (idx % 3 == 0, idx)).collect();
+        let mut calls = 0;
+        let alive = src.stable_partition(|(alive, _)| {
+            calls += 1;
+            *alive
+        });
+        assert_eq!((alive, calls), (7, 20));
+        let (_, order) = src.as_slices();
+        let expected: Vec<_> = (0..20)
+            .filter(|idx| idx % 3 == 0)
+            .chain((0..20).filter(|idx| idx % 3 != 0))
+            .collect();
+        assert_eq!(order, expected.as_slice());
+        assert_eq!(src.index_mut(..1).stable_partition(|_| false), 0);
     }
 
     #[test]
-    fn test_pop() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        assert_eq!(src.len(), 4);
-        let value = src.pop();
-        assert_eq!(value, Some((7, 8)));
-        assert_eq!(src.len(), 3);
-        let value = src.pop();
-        assert_eq!(value, Some((5, 6)));
-        assert_eq!(src.len(), 2);
-        let value = src.pop();
-        assert_eq!(value, Some((3, 4)));
-        assert_eq!(src.len(), 1);
-        let value = src.pop();
-        assert_eq!(value, Some((1, 2)));
-        assert_eq!(src.len(), 0);
-        let value = src.pop();
-        assert_eq!(value, None);
-        assert_eq!(src.len(), 0);
-        let value = src.pop();
-        assert_eq!(value, None);
-        assert_eq!(src.len(), 0);
+    fn test_gather() {
+        let src = ParallelVec::from(vec![
+            (0, String::from("a")),
+            (1, String::from("b")),
+            (2, String::from("c")),
+        ]);
+        let gathered = src.gather(&[2, 0, 2]);
+        assert_eq!(
+            gathered,
+            vec![
+                (2, String::from("c")),
+                (0, String::from("a")),
+                (2, String::from("c"))
+            ]
+        );
+        assert_eq!(gathered.capacity(), 3);
+        assert!(src.index(1..).gather(&[]).is_empty());
+        assert_eq!(src.index(1..).gather(&[1]), vec![(2, String::from("c"))]);
     }
 
     #[test]
-    fn test_insert() {
-        let mut src = ParallelVec::new();
-        src.insert(0, (1, 2));
-        src.insert(0, (3, 4));
-        src.insert(1, (4, 5));
-        assert_eq!(src.index(0), (&3, &4));
-        assert_eq!(src.index(1), (&4, &5));
-        assert_eq!(src.index(2), (&1, &2));
+    #[should_panic]
+    fn test_gather_out_of_bounds() {
+        let src = ParallelVec::from(vec![(0, 1), (1, 2)]);
+        src.index(1..).gather(&[0, 1]);
+    }
+
+    #[test]
+    fn test_scatter_into() {
+        let rc = Rc::new(());
+        let mut dst: ParallelVec<_> = (0..5).map(|idx| (idx, rc.clone())).collect();
+        let src: ParallelVec<_> = [10, 30].iter().map(|idx| (*idx, rc.clone())).collect();
+        src.scatter_into(&mut dst, &[1, 3]);
+        assert!(dst.iter().map(|(k, _)| *k).eq([0, 10, 2, 30, 4]));
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        let src = ParallelVec::from(vec![(7, rc.clone())]);
+        src.scatter_into(&mut dst.index_mut(3..), &[1]);
+        assert!(dst.iter().map(|(k, _)| *k).eq([0, 10, 2, 30, 7]));
+        assert_eq!(Rc::strong_count(&rc), 6);
     }
 
     #[test]
     #[should_panic]
-    fn test_insert_panics() {
-        let mut src = ParallelVec::new();
-        src.insert(0, (1, 2));
-        src.insert(0, (3, 4));
-        src.insert(1, (4, 5));
-        src.insert(20, (4, 5));
+    fn test_scatter_into_out_of_bounds() {
+        let mut dst = ParallelVec::from(vec![(0, 0), (1, 1)]);
+        ParallelVec::from(vec![(2, 2)]).scatter_into(&mut dst, &[2]);
     }
 
     #[test]
-    fn test_remove() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        assert_eq!(src.remove(1), Some((3, 4)));
-        assert_eq!(src.remove(0), Some((1, 2)));
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.remove(5), None);
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_scatter_into_duplicate_targets() {
+        let mut dst = ParallelVec::from(vec![(0, 0), (1, 1)]);
+        ParallelVec::from(vec![(2, 2), (3, 3)]).scatter_into(&mut dst, &[1, 1]);
     }
 
     #[test]
-    fn test_iter() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter();
-        assert_eq!(iter.next(), Some((&1, &2)));
-        assert_eq!(iter.next(), Some((&3, &4)));
-        assert_eq!(iter.next(), Some((&5, &6)));
-        assert_eq!(iter.next(), Some((&7, &8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
-        assert_eq!(src.len(), 4);
+    fn test_compact() {
+        let rc = Rc::new(());
+        let mask: Vec<bool> = (0..150).map(|idx| idx % 7 < 3 || idx > 140).collect();
+        let mut src: ParallelVec<_> = (0..150).map(|idx| (idx, rc.clone())).collect();
+        src.compact(&PackedBools::from(mask.as_slice()));
+        let expected: Vec<_> = (0..150).filter(|idx| mask[*idx]).collect();
+        assert!(src.iter().map(|(idx, _)| *idx).eq(expected.iter().copied()));
+        assert_eq!(Rc::strong_count(&rc), expected.len() + 1);
+
+        let mask: Vec<bool> = (0..src.len()).map(|idx| idx % 2 == 1).collect();
+        src.compact_bools(&mask);
+        assert!(src
+            .iter()
+            .map(|(idx, _)| *idx)
+            .eq(expected.iter().copied().skip(1).step_by(2)));
+        assert_eq!(Rc::strong_count(&rc), src.len() + 1);
+        src.compact_bools(&alloc::vec![false; src.len()]);
+        assert!(src.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn test_iters() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
-        let (mut a, mut b) = src.iters();
-        assert_eq!(a.next(), Some(&1));
-        assert_eq!(a.next(), Some(&3));
-        assert_eq!(a.next(), Some(&5));
-        assert_eq!(a.next(), Some(&7));
-        assert_eq!(a.next(), Some(&9));
-        assert_eq!(a.next(), Some(&11));
-        assert_eq!(a.next(), None);
-        assert_eq!(a.next(), None);
-        assert_eq!(b.next(), Some(&2));
-        assert_eq!(b.next(), Some(&4));
-        assert_eq!(b.next(), Some(&6));
-        assert_eq!(b.next(), Some(&8));
-        assert_eq!(b.next(), Some(&10));
-        assert_eq!(b.next(), Some(&12));
-        assert_eq!(b.next(), None);
-        assert_eq!(b.next(), None);
+    #[should_panic]
+    fn test_compact_uneven() {
+        let mut src = ParallelVec::from(vec![(0, 1), (1, 2)]);
+        src.compact_bools(&[true]);
     }
 
     #[test]
-    fn test_iter_mut() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter_mut();
-        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
-        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
-        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
-        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
+    fn test_chunk_by() {
+        let src = ParallelVec::from(vec![
+            (1, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (3, 'd'),
+            (3, 'e'),
+            (3, 'f'),
+        ]);
+        let chunks: Vec<Vec<char>> = src
+            .chunk_by(|(a, _), (b, _)| a == b)
+            .map(|chunk| chunk.iter().map(|(_, c)| *c).collect())
+            .collect();
+        assert_eq!(chunks, vec![vec!['a', 'b'], vec!['c'], vec!['d', 'e', 'f']]);
+        let lens: Vec<_> = src
+            .chunk_by(|(a, _), (b, _)| a == b)
+            .rev()
+            .map(|c| c.len())
+            .collect();
+        assert_eq!(lens, vec![3, 1, 2]);
+        let mut iter = src.index(1..5).chunk_by(|(a, _), (b, _)| a <= b);
+        assert_eq!(iter.next().map(|c| c.len()), Some(4));
+        assert!(iter.next().is_none());
+        assert_eq!(
+            ParallelVec::<(u8, u8)>::new().chunk_by(|_, _| true).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_runs_by_key() {
+        let src = ParallelVec::from(vec![
+            (1, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (3, 'd'),
+            (3, 'e'),
+            (1, 'f'),
+        ]);
+        let mut calls = 0;
+        let runs: Vec<_> = src
+            .runs_by_key(|(k, _)| {
+                calls += 1;
+                *k
+            })
+            .collect();
+        assert_eq!(runs, vec![(1, 0..2), (2, 2..3), (3, 3..5), (1, 5..6)]);
+        assert_eq!(calls, 6);
+        let runs: Vec<_> = src.index(2..5).runs_by_key(|(k, _)| *k % 2).collect();
+        assert_eq!(runs, vec![(0, 0..1), (1, 1..3)]);
+        assert_eq!(
+            ParallelVec::<(u8, u8)>::new().runs_by_key(|_| ()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_runs_by_key_rev() {
+        let src: ParallelVec<_> = [1, 1, 2, 3, 3, 3, 1, 4, 4]
+            .iter()
+            .map(|&k| (k, k as u8))
+            .collect();
+        let mut calls = 0;
+        let mut runs: Vec<_> = src
+            .runs_by_key(|(k, _)| {
+                calls += 1;
+                *k
+            })
+            .rev()
+            .collect();
+        assert_eq!(calls, 9);
+        runs.reverse();
+        assert_eq!(runs, src.runs_by_key(|(k, _)| *k).collect::<Vec<_>>());
+
+        let mut calls = 0;
+        let mut iter = src.runs_by_key(|(k, _)| {
+            calls += 1;
+            *k
+        });
+        assert_eq!(iter.next(), Some((1, 0..2)));
+        assert_eq!(iter.next_back(), Some((4, 7..9)));
+        assert_eq!(iter.next_back(), Some((1, 6..7)));
+        assert_eq!(iter.next(), Some((2, 2..3)));
+        assert_eq!(iter.next_back(), Some((3, 3..6)));
         assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(calls, 9);
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut src = ParallelVec::from(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        assert_eq!(
+            src.apply_permutation(&[0, 1, 2]),
+            Err(PermutationError::UnevenLengths)
+        );
+        assert_eq!(
+            src.apply_permutation(&[0, 1, 2, 4]),
+            Err(PermutationError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            src.apply_permutation(&[0, 1, 1, 3]),
+            Err(PermutationError::DuplicateIndices)
+        );
+        assert_eq!(src, vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        src.apply_permutation(&[3, 0, 2, 1]).unwrap();
+        assert_eq!(src, vec![(3, 'd'), (0, 'a'), (2, 'c'), (1, 'b')]);
+        unsafe { src.index_mut(1..).apply_permutation_unchecked(&[2, 0, 1]) };
+        assert_eq!(src, vec![(3, 'd'), (1, 'b'), (0, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_sort_by_with_companion() {
+        let mut src = ParallelVec::from(vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b'), (0, 'z')]);
+        let mut names = vec!["three", "one", "four", "two", "zero"];
+        src.sort_by_with_companion(|(a, _), (b, _)| a.cmp(b), &mut names);
+        assert_eq!(src, vec![(0, 'z'), (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+        assert_eq!(names, vec!["zero", "one", "two", "three", "four"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_by_with_companion_uneven() {
+        let mut src = ParallelVec::from(vec![(3, 'c'), (1, 'a')]);
+        src.sort_by_with_companion(|(a, _), (b, _)| a.cmp(b), &mut [0]);
+    }
+
+    #[test]
+    fn test_sort_by_float_key_column() {
+        let mut src = ParallelVec::from(vec![
+            (1.5, 0),
+            (f64::NAN, 1),
+            (-2.0, 2),
+            (-f64::NAN, 3),
+            (0.0, 4),
+            (-2.0, 5),
+        ]);
+        src.sort_by_float_key_column::<0>();
+        let (keys, ids) = src.as_slices();
+        assert_eq!(ids, &[3, 2, 5, 4, 0, 1]);
+        assert!(keys[0].is_nan() && keys[5].is_nan());
+        assert_eq!(&keys[1..5], &[-2.0, -2.0, 0.0, 1.5]);
+    }
+
+    #[test]
+    fn test_min_max_float_column() {
+        let src = ParallelVec::from(vec![('a', f32::NAN), ('b', 2.0), ('c', -1.0), ('d', 2.0)]);
+        assert_eq!(src.min_float_column::<1>(), Some((2, -1.0)));
+        assert_eq!(src.max_float_column::<1>(), Some((1, 2.0)));
+        assert_eq!(src.index(..1).max_float_column::<1>(), None);
+        let empty: ParallelVec<(f64, f64)> = ParallelVec::new();
+        assert_eq!(empty.min_float_column::<0>(), None);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut src = ParallelVec::from(vec![(1, String::from("a")), (2, String::from("b"))]);
+        assert_eq!(src.take(1), (2, String::from("b")));
+        assert_eq!(src.take_column::<1>(0), "a");
+        assert_eq!(src, vec![(1, String::new()), (0, String::new())]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_take_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 2)]);
+        src.take(1);
+    }
+
+    #[test]
+    fn test_iter_ptrs() {
+        let mut src = ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+        for (a, _) in src.iter_ptrs_range(1..3) {
+            unsafe { *a *= 10 };
+        }
+        let firsts: Vec<_> = src.iter_ptrs().map(|(a, _)| unsafe { *a }).collect();
+        assert_eq!(firsts, vec![1, 20, 30, 4]);
+        let lasts: Vec<_> = src
+            .index(..)
+            .iter_ptrs_range(2..)
+            .rev()
+            .map(|(_, b)| unsafe { *b })
+            .collect();
+        assert_eq!(lasts, vec!['d', 'c']);
+        assert_eq!(src.iter_ptrs_range(4..).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_ptrs_range_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 'a')]);
+        src.iter_ptrs_range(0..2);
+    }
+
+    #[test]
+    fn test_retain_by_column() {
+        let rc = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..10).map(|x| (x, rc.clone())).collect();
+        src.retain_by_column::<0, _>(|x| x % 3 == 0);
         assert_eq!(src.len(), 4);
+        assert_eq!(src.as_slices().0, &[0, 3, 6, 9]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        src.retain_by_column::<0, _>(|_| false);
+        assert!(src.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_retain_by_column_panic() {
+        let rc = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..6).map(|x| (x, rc.clone())).collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.retain_by_column::<0, _>(|x| {
+                assert!(*x != 3);
+                x % 2 == 0
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.as_slices().0, &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(Rc::strong_count(&rc), 7);
     }
 
     #[test]
-    fn test_shrink_to() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to(200);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 200);
-        src.shrink_to(100);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 100);
-        src.shrink_to(10);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 10);
-        src.shrink_to(1);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 4);
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+    #[cfg(all(feature = "numa", target_os = "linux"))]
+    fn test_numa_policy() {
+        use crate::NumaPolicy;
+        let mut src: ParallelVec<(u64, u64)> = (0..10000).map(|x| (x, x)).collect();
+        assert_eq!(src.numa_policy(), None);
+        // The sandbox may not support NUMA syscalls, in which case the policy
+        // must be left unset.
+        match src.set_numa_policy(NumaPolicy::Bind(1)) {
+            Ok(()) => {
+                assert_eq!(src.numa_policy(), Some(NumaPolicy::Bind(1)));
+                src.extend((0..100000).map(|x| (x, x)));
+                assert_eq!(src.numa_policy(), Some(NumaPolicy::Bind(1)));
+                src.clear_numa_policy();
+                assert_eq!(src.numa_policy(), None);
+            }
+            Err(_) => assert_eq!(src.numa_policy(), None),
+        }
+        assert!(src.set_numa_policy(NumaPolicy::Preferred(64)).is_err());
+        assert_eq!(src.len(), 110000);
     }
 
     #[test]
-    fn test_shrink_to_fit() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to_fit();
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= src.len());
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+    fn test_concat() {
+        let rc = Rc::new(0);
+        let shards = (0..4).map(|shard| {
+            (0..shard)
+                .map(|x| (shard * 10 + x, rc.clone()))
+                .collect::<ParallelVec<_>>()
+        });
+        let all: ParallelVec<_> = shards.collect();
+        assert_eq!(all.len(), 6);
+        assert_eq!(all.capacity(), 6);
+        assert_eq!(all.as_slices().0, &[10, 20, 21, 30, 31, 32]);
+        assert_eq!(Rc::strong_count(&rc), 7);
+        drop(all);
+        assert_eq!(Rc::strong_count(&rc), 1);
+        let empty = ParallelVec::<(u8, u8)>::concat(Vec::new());
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_truncate() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.truncate(2);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3]);
-            assert_eq!(b, &[2, 4]);
-            assert_eq!(src.len(), 2);
-            assert!(src.capacity() >= 4);
-        }
+    fn test_extend_from_within() {
+        let rc = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..4).map(|x| (x, rc.clone())).collect();
+        src.extend_from_within(1..3);
+        assert_eq!(src.as_slices().0, &[0, 1, 2, 3, 1, 2]);
+        src.extend_from_within(..);
+        assert_eq!(src.len(), 12);
+        assert_eq!(Rc::strong_count(&rc), 13);
+        drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn test_truncate_drops() {
+    fn test_splice() {
+        let rc = Rc::new(0);
+        let rows = |range: core::ops::Range<i32>| {
+            let rc = rc.clone();
+            range.map(move |x| (x, rc.clone()))
+        };
+        let mut src: ParallelVec<_> = rows(0..6).collect();
+        let removed: Vec<_> = src.splice(1..3, rows(10..14)).map(|(a, _)| a).collect();
+        assert_eq!(removed, vec![1, 2]);
+        assert_eq!(src.as_slices().0, &[0, 10, 11, 12, 13, 3, 4, 5]);
+
+        // An iterator without a size hint.
+        drop(src.splice(..2, rows(20..23).filter(|_| true)));
+        assert_eq!(src.as_slices().0, &[20, 21, 22, 11, 12, 13, 3, 4, 5]);
+
+        drop(src.splice(3..8, rows(0..0)));
+        assert_eq!(src.as_slices().0, &[20, 21, 22, 5]);
+        drop(src.splice(4.., rows(30..32)));
+        assert_eq!(src.as_slices().0, &[20, 21, 22, 5, 30, 31]);
+        assert_eq!(Rc::strong_count(&rc), 7);
+    }
+
+    #[test]
+    fn test_resize() {
         let rc = Rc::new(0);
         let mut src = ParallelVec::new();
-        src.extend(vec![
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-        ]);
-        assert_eq!(Rc::strong_count(&rc), 9);
-        src.truncate(1);
+        src.resize(3, (1, rc.clone()));
+        assert_eq!(src.as_slices().0, &[1, 1, 1]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        let mut next = 5;
+        src.resize_with(5, || {
+            next += 1;
+            (next, rc.clone())
+        });
+        assert_eq!(src.as_slices().0, &[1, 1, 1, 6, 7]);
+        src.resize(2, (0, rc.clone()));
+        assert_eq!(src.as_slices().0, &[1, 1]);
         assert_eq!(Rc::strong_count(&rc), 3);
     }
 
     #[test]
-    fn test_reverse() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.reverse();
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[7, 5, 3, 1]);
-            assert_eq!(b, &[8, 6, 4, 2]);
-        }
+    fn test_fill_drops_previous_values() {
+        let old = Rc::new(0);
+        let new = Rc::new(1);
+        let mut src: ParallelVec<_> = (0..4).map(|x| (x, old.clone())).collect();
+        src.fill((7, new.clone()));
+        assert_eq!(src.as_slices().0, &[7, 7, 7, 7]);
+        assert_eq!(Rc::strong_count(&old), 1);
+        assert_eq!(Rc::strong_count(&new), 5);
+        let mut next = 0;
+        src.index_mut(1..3).fill_with(|| {
+            next += 1;
+            (next, old.clone())
+        });
+        assert_eq!(src.as_slices().0, &[7, 1, 2, 7]);
+        assert_eq!(Rc::strong_count(&old), 3);
+        assert_eq!(Rc::strong_count(&new), 3);
     }
 
     #[test]
-    fn test_clear() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.clear();
-        assert_eq!(src.len(), 0);
-        assert!(src.capacity() > 0);
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[]);
-        assert_eq!(b, &[]);
+    fn test_split_off() {
+        let rc = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..6).map(|x| (x, rc.clone())).collect();
+        let capacity = src.capacity();
+        let tail = src.split_off(2);
+        assert_eq!(src.as_slices().0, &[0, 1]);
+        assert_eq!(tail.as_slices().0, &[2, 3, 4, 5]);
+        assert_eq!(tail.capacity(), 4);
+        assert_eq!(src.capacity(), capacity);
+        assert_eq!(Rc::strong_count(&rc), 7);
+        assert!(src.split_off(2).is_empty());
     }
 
     #[test]
-    fn test_repeat() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        let repeated = src.repeat(3);
-        let (a, b) = repeated.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7, 1, 3, 5, 7, 1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8]);
-        let (a, b) = src.as_slices();
-        assert_eq!(src.len(), 4);
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
-        assert_eq!(repeated.len(), 12);
+    fn test_split_off_into() {
+        let rc = Rc::new(0);
+        let mut src: ParallelVec<_> = (0..6).map(|x| (x, rc.clone())).collect();
+        let mut dest: ParallelVec<_> = (10..20).map(|x| (x, rc.clone())).collect();
+        let capacity = dest.capacity();
+        src.split_off_into(4, &mut dest);
+        assert_eq!(src.as_slices().0, &[0, 1, 2, 3]);
+        assert_eq!(dest.as_slices().0, &[4, 5]);
+        assert_eq!(dest.capacity(), capacity);
+        assert_eq!(Rc::strong_count(&rc), 7);
+        src.split_off_into(0, &mut dest);
+        assert!(src.is_empty());
+        assert_eq!(dest.as_slices().0, &[0, 1, 2, 3]);
+        assert_eq!(Rc::strong_count(&rc), 5);
     }
 
     #[test]
-    fn test_eq() {
-        let a = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        let b = ParallelVec::from(vec![(1, 2), (3, 4), (9, 6), (7, 8)]);
-        let c = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        assert!(a == a);
-        assert!(a != b);
-        assert!(a == c);
-        assert!(b != a);
-        assert!(b == b);
-        assert!(b != c);
-        assert!(c == a);
-        assert!(c != b);
-        assert!(c == c);
+    #[should_panic]
+    fn test_split_off_into_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 2)]);
+        src.split_off_into(2, &mut ParallelVec::new());
     }
 
     #[test]
@@ -907,6 +3755,23 @@ mod tests {
         assert_eq!(src_b.len(), 0);
     }
 
+    #[test]
+    fn test_append_moves_without_dropping() {
+        let rc = Rc::new(0);
+        let mut src_a: ParallelVec<_> = (0..3).map(|x| (x, rc.clone())).collect();
+        let mut src_b: ParallelVec<_> = (3..8).map(|x| (x, rc.clone())).collect();
+        let capacity = src_b.capacity();
+        src_a.append(&mut src_b);
+        assert_eq!(src_a.as_slices().0, &[0, 1, 2, 3, 4, 5, 6, 7]);
+        assert!(src_b.is_empty());
+        assert_eq!(src_b.capacity(), capacity);
+        assert_eq!(Rc::strong_count(&rc), 9);
+        drop(src_b);
+        assert_eq!(Rc::strong_count(&rc), 9);
+        drop(src_a);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
     #[test]
     #[should_panic]
     fn test_swap_with_panics() {
@@ -992,6 +3857,111 @@ mod tests {
         assert_eq!(src.last_mut(), Some((&mut 7, &mut 8)));
     }
 
+    #[test]
+    fn test_get_many_mut() {
+        use crate::GetManyMutError;
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        let [(a0, _), (a2, b2)] = src.get_many_mut([0, 2]).unwrap();
+        core::mem::swap(a0, a2);
+        *b2 += 10;
+        assert_eq!(src.as_slices(), (&[5, 3, 1][..], &[2, 4, 16][..]));
+        assert_eq!(
+            src.get_many_mut([1, 3]).err(),
+            Some(GetManyMutError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            src.get_many_mut([1, 0, 1]).err(),
+            Some(GetManyMutError::OverlappingIndices)
+        );
+        assert!(src.get_many_mut([]).unwrap().is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_index_ranges() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        assert!(src.index(3..3).is_empty());
+        assert!(src.get(3..).unwrap().is_empty());
+        assert!(src.get(2..1).is_none());
+        let (a, b) = src.index_mut(1);
+        *a += *b;
+        src.index_mut(2..).set(0, (0, 0));
+        assert_eq!(src, vec![(1, 2), (7, 4), (0, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_index_decreasing_range() {
+        let src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.index(2..1);
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        unsafe {
+            assert_eq!(src.get_unchecked(1), (&3, &4));
+            let (a, b) = src.get_unchecked_mut(2);
+            *a += *b;
+            assert_eq!(src.index(1..).get_unchecked(1), (&11, &6));
+        }
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let src: ParallelVec<_> = [1, 3, 3, 5, 8].iter().map(|&k| (k, k * 10)).collect();
+        assert_eq!(src.binary_search_by_key(&5, |(k, _)| *k), Ok(3));
+        assert!(matches!(
+            src.binary_search_by_key(&3, |(k, _)| *k),
+            Ok(1 | 2)
+        ));
+        assert_eq!(src.binary_search_by_key(&0, |(k, _)| *k), Err(0));
+        assert_eq!(src.binary_search_by_key(&4, |(k, _)| *k), Err(3));
+        assert_eq!(src.binary_search_by_key(&9, |(k, _)| *k), Err(5));
+        assert_eq!(src.index(1..4).binary_search_by(|(_, v)| v.cmp(&50)), Ok(2));
+        assert_eq!(src.index(..0).binary_search_by(|(k, _)| k.cmp(&1)), Err(0));
+    }
+
+    #[test]
+    fn test_partition_point_equal_range() {
+        let src: ParallelVec<_> = [1, 3, 3, 3, 5, 8].iter().map(|&k| (k, ())).collect();
+        assert_eq!(src.partition_point(|(k, _)| *k < 4), 4);
+        assert_eq!(src.partition_point(|_| true), 6);
+        assert_eq!(src.partition_point(|_| false), 0);
+        assert_eq!(src.equal_range(&3, |(k, _)| *k), 1..4);
+        assert_eq!(src.equal_range(&4, |(k, _)| *k), 4..4);
+        assert_eq!(src.equal_range(&8, |(k, _)| *k), 5..6);
+        assert_eq!(src.index(2..).equal_range(&3, |(k, _)| *k), 0..2);
+    }
+
+    #[test]
+    fn test_is_sorted_by() {
+        let mut src: ParallelVec<_> = [1, 3, 3, 5].iter().map(|&k| (k, -k)).collect();
+        assert!(src.is_sorted_by(|(a, _), (b, _)| a <= b));
+        assert!(!src.is_sorted_by(|(a, _), (b, _)| a < b));
+        assert!(src.is_sorted_by(|(_, a), (_, b)| a >= b));
+        src.swap(0, 3);
+        assert!(!src.is_sorted_by(|(a, _), (b, _)| a <= b));
+        assert!(src.index(1..3).is_sorted_by(|(a, _), (b, _)| a <= b));
+        assert!(src.index(0..1).is_sorted_by(|_, _| false));
+    }
+
+    #[test]
+    fn test_first_last_mut_write() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        if let Some((a, _)) = src.first_mut() {
+            *a = 10;
+        }
+        if let Some((_, b)) = src.last_mut() {
+            *b = 60;
+        }
+        assert_eq!(src.first(), Some((&10, &2)));
+        assert_eq!(src.last(), Some((&5, &60)));
+        assert_eq!(src.index(1..).first(), Some((&3, &4)));
+        assert_eq!(src.index(..0).last(), None);
+    }
+
     #[test]
     fn test_get_slice() {
         let mut src = ParallelVec::new();
@@ -1102,6 +4072,278 @@ mod tests {
         assert_eq!(vec, vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
     }
 
+    #[test]
+    fn test_iter_exact_size() {
+        let mut src: ParallelVec<_> = (0..10u32).map(|idx| (idx, idx as u8)).collect();
+        let mut iter = src.iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 8);
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.len(), 10);
+        iter.nth(2);
+        assert_eq!(iter.len(), 7);
+        for (idx, (a, _)) in src.iter_mut().enumerate().rev().take(2) {
+            *a = idx as u32 * 10;
+        }
+        assert_eq!(&src.as_slices().0[7..], &[7, 80, 90]);
+        let mut iter = src.clone().into_iter().fuse();
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.by_ref().count(), 10);
+        assert!(iter.next().is_none());
+        let mut drain = src.drain(2..5);
+        assert_eq!(drain.len(), 3);
+        drain.next_back();
+        assert_eq!(drain.len(), 2);
+    }
+
+    #[test]
+    fn test_iter_nth_fold() {
+        let mut src: ParallelVec<_> = (0..10u32).map(|idx| (idx, idx as u64)).collect();
+        let mut iter = src.iter();
+        assert_eq!(iter.nth(3), Some((&3, &3)));
+        assert_eq!(iter.nth_back(2), Some((&7, &7)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.fold(0, |acc, (a, _)| acc + a), 4 + 5 + 6);
+        assert_eq!(src.iter().nth(10), None);
+        assert_eq!(src.iter().skip(8).map(|(a, _)| *a).sum::<u32>(), 17);
+        let mut iter = src.iter_mut();
+        iter.nth(20);
+        assert_eq!(iter.len(), 0);
+        src.iter_mut().skip(5).for_each(|(a, b)| *b += *a as u64);
+        assert_eq!(src.as_slices().1[4..7], [4, 10, 12]);
+        assert_eq!(src.iter_mut().nth_back(0).map(|(a, _)| *a), Some(9));
+        let ptrs = src.iter_ptrs().fold(0, |count, _| count + 1);
+        assert_eq!(ptrs, 10);
+
+        let tracked = Rc::new(0);
+        let src: ParallelVec<_> = (0..6u32).map(|idx| (idx, Rc::clone(&tracked))).collect();
+        let mut iter = src.into_iter();
+        assert_eq!(iter.nth(2).map(|(a, _)| a), Some(2));
+        assert_eq!(Rc::strong_count(&tracked), 4);
+        assert!(iter.nth(5).is_none());
+        assert_eq!(Rc::strong_count(&tracked), 1);
+    }
+
+    #[test]
+    fn test_iter_enumerated() {
+        let mut src: ParallelVec<_> = (0..6u32).map(|idx| (idx * 10, idx as u8)).collect();
+        for (idx, (a, b)) in src.iter_mut_enumerated() {
+            *a += idx as u32;
+            *b *= 2;
+        }
+        let rows: Vec<_> = src
+            .iter_enumerated()
+            .map(|(idx, (a, _))| (idx, *a))
+            .collect();
+        assert_eq!(
+            rows,
+            (0..6).map(|idx| (idx, idx as u32 * 11)).collect::<Vec<_>>()
+        );
+        let mut iter = src.index(2..).iter_enumerated();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some((3, (&55, &10))));
+        assert_eq!(iter.nth(1), Some((1, (&33, &6))));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.rev().map(|(idx, _)| idx).collect::<Vec<_>>(), vec![2]);
+        let sum = src
+            .iter_enumerated()
+            .fold(0, |acc, (idx, (_, b))| acc + idx * *b as usize);
+        assert_eq!(sum, (0..6).map(|idx| idx * idx * 2).sum());
+    }
+
+    #[test]
+    fn test_zip_slices() {
+        let mut src: ParallelVec<_> = (0..4u32).map(|idx| (idx, idx as u8)).collect();
+        let offsets = [10u32, 20, 30, 40];
+        for ((a, _), offset) in src.zip_slices(&offsets[..]) {
+            *a += *offset;
+        }
+        assert_eq!(src.as_slices().0, &[10, 21, 32, 43]);
+        let mut out = vec![0u32; 4];
+        let names = ["a", "b", "c", "d"];
+        let mut iter = src.zip_slices((&mut out[..], &names[..], &offsets[..]));
+        assert_eq!(iter.len(), 4);
+        let ((a, _), (last, name, _)) = iter.next_back().unwrap();
+        *last = *a;
+        assert_eq!(*name, "d");
+        iter.for_each(|((a, b), (dst, _, offset))| *dst = *a + *b as u32 - *offset);
+        assert_eq!(out, vec![0, 2, 4, 43]);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_zip_slices_uneven() {
+        let mut src = ParallelVec::from(vec![(0u32, 0u8), (1, 1)]);
+        src.zip_slices((&[1, 2][..], &[1][..]));
+    }
+
+    #[test]
+    fn test_zip_iter() {
+        let mut current: ParallelVec<_> = (0..5u32).map(|idx| (idx as f32, idx)).collect();
+        let mut previous: ParallelVec<_> = (0..5u32).map(|idx| (idx as f32 / 2.0, idx)).collect();
+        let deltas: Vec<_> = current
+            .zip_iter(&previous)
+            .map(|((x, _), (prev, _))| *x - *prev)
+            .collect();
+        assert_eq!(deltas, vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+        for ((x, id), (prev, _)) in current.zip_iter_mut(&mut previous).rev().take(2) {
+            core::mem::swap(x, prev);
+            *id += 100;
+        }
+        assert_eq!(current.as_slices().0, &[0.0, 1.0, 2.0, 1.5, 2.0]);
+        assert_eq!(current.as_slices().1, &[0, 1, 2, 103, 104]);
+        assert_eq!(previous.as_slices().0, &[0.0, 0.5, 1.0, 3.0, 4.0]);
+        assert_eq!(current.zip_iter(&previous).len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_zip_iter_uneven() {
+        let src = ParallelVec::from(vec![(0u32, 0u8), (1, 1)]);
+        let other = ParallelVec::from(vec![(0u32, 0u8)]);
+        src.zip_iter(&other);
+    }
+
+    #[test]
+    fn test_join() {
+        let counter = Rc::new(());
+        let left: ParallelVec<_> = (0..4u32).map(|idx| (idx, counter.clone())).collect();
+        let right: ParallelVec<_> = (0..4u32)
+            .map(|idx| (String::from("row"), idx as u8, counter.clone()))
+            .collect();
+        let joined = left.join(right);
+        assert_eq!(joined.len(), 4);
+        assert_eq!(Rc::strong_count(&counter), 9);
+        let (_, _, names, bytes, _) = joined.as_slices();
+        assert_eq!(names[3], "row");
+        assert_eq!(bytes, &[0, 1, 2, 3]);
+        core::mem::drop(joined);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_split_columns() {
+        let counter = Rc::new(());
+        let table: ParallelVec<_> = (0..4u32)
+            .map(|idx| (idx, counter.clone(), idx as u8, String::from("row")))
+            .collect();
+        let (left, right) = table.split_columns::<(u32, Rc<()>), (u8, String)>();
+        assert_eq!(left.len(), 4);
+        assert_eq!(right.len(), 4);
+        assert_eq!(Rc::strong_count(&counter), 5);
+        assert_eq!(left.as_slices().0, &[0, 1, 2, 3]);
+        assert_eq!(right.as_slices().0, &[0, 1, 2, 3]);
+        let rejoined = left.join(right);
+        assert_eq!(rejoined.index(2).3, "row");
+        core::mem::drop(rejoined);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_join_uneven() {
+        let left = ParallelVec::from(vec![(0u32, 0u8), (1, 1)]);
+        let right = ParallelVec::from(vec![(0u32, 0u8)]);
+        left.join(right);
+    }
+
+    #[test]
+    fn test_map() {
+        let counter = Rc::new(());
+        let src: ParallelVec<_> = (0..5u32).map(|idx| (idx, idx as f32)).collect();
+        let mapped = src.map(|(a, b)| (*a * 2, *b + 0.5, counter.clone()));
+        assert_eq!(mapped.len(), 5);
+        assert_eq!(mapped.capacity(), 5);
+        assert_eq!(mapped.as_slices().0, &[0, 2, 4, 6, 8]);
+        assert_eq!(mapped.as_slices().1, &[0.5, 1.5, 2.5, 3.5, 4.5]);
+        assert_eq!(Rc::strong_count(&counter), 6);
+        core::mem::drop(mapped);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let empty = ParallelVec::<(u8, u8)>::new().map(|(a, b)| (*b, *a));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_map_panic_drops_written_rows() {
+        let counter = Rc::new(());
+        let src: ParallelVec<_> = (0..5u32).map(|idx| (idx, idx)).collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.map(|(a, _)| {
+                assert!(*a < 3);
+                (counter.clone(), *a)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let src: ParallelVec<_> = (0..6u32).map(|idx| (idx, String::from("row"))).collect();
+        let filtered = src.filter_map(|(a, name)| (*a % 3 == 0).then(|| (name.clone(), *a as u8)));
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered.as_slices().0, &["row", "row"]);
+        assert_eq!(filtered.as_slices().1, &[0, 3]);
+        assert!(src.filter_map(|_| None::<(u8, u8)>).is_empty());
+    }
+
+    #[test]
+    fn test_try_map() {
+        let counter = Rc::new(());
+        let src = ParallelVec::from(vec![("1", 1u8), ("2", 2), ("x", 3), ("4", 4)]);
+        let result =
+            src.try_map(|(num, b)| num.parse::<u32>().map(|num| (num, counter.clone(), *b)));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let mapped = src
+            .index(..2)
+            .try_map(|(num, b)| num.parse::<u32>().map(|num| (num, *b)))
+            .unwrap();
+        assert_eq!(mapped.as_slices(), (&[1, 2][..], &[1, 2][..]));
+    }
+
+    #[test]
+    fn test_into_iter_by_ref() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        for (a, b) in &mut src {
+            *a += *b;
+        }
+        let mut rows = Vec::new();
+        for (a, b) in &src {
+            rows.push((*a, *b));
+        }
+        assert_eq!(rows, vec![(3, 2), (7, 4), (11, 6)]);
+    }
+
+    #[test]
+    fn test_into_iter_both_ends() {
+        let tracked = Rc::new(0);
+        let src: ParallelVec<_> = (0..5u32).map(|idx| (idx, Rc::clone(&tracked))).collect();
+        let mut iter = src.into_iter();
+        assert_eq!(iter.next().map(|(a, _)| a), Some(0));
+        assert_eq!(iter.next_back().map(|(a, _)| a), Some(4));
+        assert_eq!(iter.len(), 3);
+        let mut rows = Vec::new();
+        for (a, _) in iter.by_ref().take(2) {
+            rows.push(a);
+        }
+        assert_eq!(rows, vec![1, 2]);
+        assert_eq!(iter.next_back().map(|(a, _)| a), Some(3));
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+
+        let src: ParallelVec<_> = (0..5u32).map(|idx| (idx, Rc::clone(&tracked))).collect();
+        let mut iter = src.into_iter();
+        iter.next();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+    }
+
     #[test]
     fn test_slice_is_empty() {
         let mut src = ParallelVec::new();
@@ -1205,4 +4447,114 @@ mod tests {
         (1..8).for_each(|i| v.push((i, i)));
         v.reserve(usize::MAX);
     }
+
+    #[test]
+    fn test_uninit_with_len_assume_init() {
+        use core::mem::MaybeUninit;
+        let mut src = ParallelVec::<(MaybeUninit<u32>, MaybeUninit<String>)>::uninit_with_len(3);
+        assert_eq!(src.len(), 3);
+        assert_eq!(src.capacity(), 3);
+        let (a, b) = src.as_slices_mut();
+        for (idx, (a, b)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+            a.write(idx as u32);
+            b.write(format!("{}", idx));
+        }
+        let mut src = unsafe { src.assume_init() };
+        src.push((3, String::from("3")));
+        let expected: Vec<_> = (0..4u32).map(|idx| (idx, format!("{}", idx))).collect();
+        assert_eq!(src, expected);
+    }
+
+    #[test]
+    fn test_new_uninit_assume_init() {
+        let mut src = ParallelVec::<(u32, Rc<u32>)>::new_uninit(100);
+        assert_eq!(src.len(), 100);
+        let (a, b) = src.as_slices_mut();
+        for (idx, (a, b)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+            a.write(idx as u32);
+            b.write(Rc::new(idx as u32 * 2));
+        }
+        let src = unsafe { src.assume_init() };
+        assert_eq!(src.len(), 100);
+        let expected: Vec<_> = (0..100u32).map(|idx| (idx, Rc::new(idx * 2))).collect();
+        assert_eq!(src, expected);
+    }
+
+    #[test]
+    fn test_new_uninit_drop() {
+        // Dropping without initializing must not drop any of the rows.
+        let src = ParallelVec::<(u32, Rc<u32>)>::new_uninit(8);
+        drop(src);
+        assert!(ParallelVec::<(u8, u8)>::new_uninit(0).is_empty());
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut src: ParallelVec<_> = (0..8).map(|i| (i, Rc::new(i))).collect();
+        let drained: Vec<_> = src.drain(2..5).map(|(a, _)| a).collect();
+        assert_eq!(drained, vec![2, 3, 4]);
+        assert_eq!(src.as_slices().0, &[0, 1, 5, 6, 7]);
+
+        let tracked = Rc::clone(src.index(3).1);
+        let mut drain = src.drain(1..);
+        assert_eq!(drain.next_back().map(|(a, _)| a), Some(7));
+        assert_eq!(drain.len(), 3);
+        drop(drain);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+        assert_eq!(src.as_slices().0, &[0]);
+
+        core::mem::forget(src.drain(..));
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut src: ParallelVec<_> = (0..10).map(|i| (i, Rc::new(i))).collect();
+        let tracked = Rc::clone(src.index(3).1);
+        src.retain(|(a, _)| a % 2 == 0);
+        assert_eq!(src.as_slices().0, &[0, 2, 4, 6, 8]);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+        src.retain_mut(|(a, _)| {
+            *a *= 10;
+            *a != 40
+        });
+        assert_eq!(src.as_slices().0, &[0, 20, 60, 80]);
+        let rcs: Vec<_> = src.as_slices().1.iter().map(|rc| **rc).collect();
+        assert_eq!(rcs, vec![0, 2, 6, 8]);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut src: ParallelVec<_> = (0..10).map(|i| (i, Rc::new(i))).collect();
+        let tracked = Rc::clone(src.index(9).1);
+        let odd: Vec<_> = src
+            .extract_if(|(a, _)| *a % 2 == 1)
+            .map(|(a, _)| a)
+            .collect();
+        assert_eq!(odd, vec![1, 3, 5, 7, 9]);
+        assert_eq!(src.as_slices().0, &[0, 2, 4, 6, 8]);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+
+        let mut iter = src.extract_if(|(a, _)| *a >= 4);
+        assert_eq!(iter.next().map(|(a, _)| a), Some(4));
+        drop(iter);
+        assert_eq!(src.as_slices().0, &[0, 2, 6, 8]);
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let rows = [1, 1, 2, 3, 3, 3, 1, 4, 4];
+        let mut src: ParallelVec<_> = rows.iter().map(|&i| (i, Rc::new(i))).collect();
+        let tracked = Rc::clone(src.index(5).1);
+        src.dedup_by(|(prev, _), (curr, _)| prev == curr);
+        assert_eq!(src.as_slices().0, &[1, 2, 3, 1, 4]);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+
+        src.dedup_by_key(|(a, _)| *a / 2);
+        assert_eq!(src.as_slices().0, &[1, 2, 1, 4]);
+
+        let mut empty = ParallelVec::<(u8, u8)>::new();
+        empty.dedup_by_key(|(a, _)| *a);
+        assert!(empty.is_empty());
+    }
 }
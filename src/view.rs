@@ -0,0 +1,183 @@
+use crate::iter::Iter;
+use crate::ParallelParam;
+use core::marker::PhantomData;
+
+/// A lazy, filtered view into a [`ParallelSlice`].
+///
+/// Views do not allocate or copy any rows. Filters are composed and only
+/// evaluated when the view is iterated, so chaining multiple [`filter`]
+/// calls does not materialize any intermediate tables. Views can be
+/// iterated multiple times.
+///
+/// See [`ParallelSlice::view`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`ParallelSlice::view`]: crate::ParallelSlice::view
+/// [`filter`]: Self::filter
+pub struct ParallelView<'a, Param: ParallelParam, P> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) len: usize,
+    pub(crate) predicate: P,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param, P> ParallelView<'a, Param, P>
+where
+    Param: ParallelParam + 'a,
+    P: Fn(&Param::Ref<'a>) -> bool,
+{
+    /// Narrows the view to the rows that also satisfy `predicate`.
+    pub fn filter<F>(
+        self,
+        predicate: F,
+    ) -> ParallelView<'a, Param, impl Fn(&Param::Ref<'a>) -> bool>
+    where
+        F: Fn(&Param::Ref<'a>) -> bool,
+    {
+        let current = self.predicate;
+        ParallelView {
+            ptr: self.ptr,
+            len: self.len,
+            predicate: move |row: &Param::Ref<'a>| current(row) && predicate(row),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Lazily transforms every row in the view with `f`.
+    pub fn map_columns<M, T>(self, f: M) -> MappedView<'a, Param, P, M>
+    where
+        M: Fn(Param::Ref<'a>) -> T,
+    {
+        MappedView { view: self, map: f }
+    }
+
+    /// Returns an iterator over the rows in the view.
+    pub fn iter(&self) -> impl Iterator<Item = Param::Ref<'a>> + '_ {
+        self.rows()
+            .filter(move |(_, row)| (self.predicate)(row))
+            .map(|(_, row)| row)
+    }
+
+    /// Returns an iterator over the indices of the rows in the view,
+    /// relative to the start of the viewed slice.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + use<'_, 'a, Param, P> {
+        self.rows()
+            .filter(move |(_, row)| (self.predicate)(row))
+            .map(|(idx, _)| idx)
+    }
+
+    /// Counts the number of rows in the view.
+    ///
+    /// This is a `O(N)` operation as it must evaluate the filters on every row.
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Collects the rows in the view into a collection.
+    pub fn collect<B: FromIterator<Param::Ref<'a>>>(&self) -> B {
+        self.iter().collect()
+    }
+
+    fn rows(&self) -> core::iter::Enumerate<Iter<'a, Param>> {
+        Iter {
+            ptr: self.ptr,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+        .enumerate()
+    }
+}
+
+/// A lazy, transformed view into a [`ParallelSlice`].
+///
+/// See [`ParallelView::map_columns`].
+///
+/// [`ParallelSlice`]: crate::ParallelSlice
+pub struct MappedView<'a, Param: ParallelParam, P, M> {
+    view: ParallelView<'a, Param, P>,
+    map: M,
+}
+
+impl<'a, Param, P, M, T> MappedView<'a, Param, P, M>
+where
+    Param: ParallelParam + 'a,
+    P: Fn(&Param::Ref<'a>) -> bool,
+    M: Fn(Param::Ref<'a>) -> T,
+{
+    /// Lazily transforms the output of the view with `f`.
+    pub fn map_columns<F, U>(self, f: F) -> MappedView<'a, Param, P, impl Fn(Param::Ref<'a>) -> U>
+    where
+        F: Fn(T) -> U,
+    {
+        let current = self.map;
+        MappedView {
+            view: self.view,
+            map: move |row| f(current(row)),
+        }
+    }
+
+    /// Returns an iterator over the transformed rows in the view.
+    pub fn iter(&self) -> impl Iterator<Item = T> + use<'_, 'a, Param, P, M, T> {
+        self.view.iter().map(&self.map)
+    }
+
+    /// Counts the number of rows in the view.
+    ///
+    /// This does not evaluate the transformation.
+    pub fn count(&self) -> usize {
+        self.view.count()
+    }
+
+    /// Collects the transformed rows in the view into a collection.
+    ///
+    /// Collecting into a [`ParallelVec`] keeps the data columnar end to end.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    pub fn collect<B: FromIterator<T>>(&self) -> B {
+        self.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParallelVec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_view_filter() {
+        let src = ParallelVec::from(vec![(1, 2.0), (2, 4.0), (3, 6.0), (4, 8.0), (5, 10.0)]);
+        let view = src
+            .view()
+            .filter(|(a, _)| **a % 2 == 1)
+            .filter(|(_, b)| **b > 2.0);
+        assert_eq!(view.count(), 2);
+        assert_eq!(view.indices().collect::<Vec<_>>(), vec![2, 4]);
+        let rows: Vec<_> = view.collect();
+        assert_eq!(rows, vec![(&3, &6.0), (&5, &10.0)]);
+        // Views can be iterated more than once.
+        assert_eq!(view.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_view_map_columns() {
+        let src = ParallelVec::from(vec![(1, 2u64), (2, 4), (3, 6), (4, 8)]);
+        let view = src
+            .view()
+            .filter(|(a, _)| **a > 1)
+            .map_columns(|(a, b)| (*b, *a))
+            .map_columns(|(b, a)| (b * 10, a));
+        let dst: ParallelVec<(u64, i32)> = view.collect();
+        let (b, a) = dst.as_slices();
+        assert_eq!(b, &[40, 60, 80]);
+        assert_eq!(a, &[2, 3, 4]);
+        assert_eq!(view.count(), 3);
+    }
+
+    #[test]
+    fn test_view_empty() {
+        let src: ParallelVec<(i32, i32)> = ParallelVec::new();
+        let view = src.view().filter(|_| true);
+        assert_eq!(view.count(), 0);
+        assert_eq!(view.iter().next(), None);
+    }
+}
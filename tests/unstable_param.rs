@@ -0,0 +1,247 @@
+//! Implements [`ParallelParam`] for a downstream struct with named fields,
+//! which is only possible with the `unstable-param` feature.
+#![cfg(feature = "unstable-param")]
+
+use parallel_vec::{ParallelParam, ParallelVec, TryReserveError};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Particle {
+    position: f32,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct ParticleRef<'a> {
+    position: &'a f32,
+    name: &'a String,
+}
+
+struct ParticleMut<'a> {
+    position: &'a mut f32,
+    name: &'a mut String,
+}
+
+/// The columns are stored exactly like the equivalent tuple, so everything
+/// that does not deal with rows of `Particle` delegates to it.
+type Columns = (f32, String);
+
+unsafe impl ParallelParam for Particle {
+    type Storage = <Columns as ParallelParam>::Storage;
+    type Ptr = <Columns as ParallelParam>::Ptr;
+    type Offsets = <Columns as ParallelParam>::Offsets;
+    type Ref<'a> = ParticleRef<'a>;
+    type RefMut<'a> = ParticleMut<'a>;
+    type Vecs = <Columns as ParallelParam>::Vecs;
+    type Slices<'a> = <Columns as ParallelParam>::Slices<'a>;
+    type SlicesMut<'a> = <Columns as ParallelParam>::SlicesMut<'a>;
+    type UninitSlicesMut<'a> = <Columns as ParallelParam>::UninitSlicesMut<'a>;
+    type Iters<'a> = <Columns as ParallelParam>::Iters<'a>;
+    type ItersMut<'a> = <Columns as ParallelParam>::ItersMut<'a>;
+    type Arrays<'a, const N: usize> = <Columns as ParallelParam>::Arrays<'a, N>;
+    type ArraysMut<'a, const N: usize> = <Columns as ParallelParam>::ArraysMut<'a, N>;
+
+    fn dangling() -> Self::Storage {
+        Columns::dangling()
+    }
+
+    fn as_ptr(storage: Self::Storage) -> Self::Ptr {
+        Columns::as_ptr(storage)
+    }
+
+    unsafe fn alloc(capacity: usize) -> Self::Storage {
+        Columns::alloc(capacity)
+    }
+
+    unsafe fn try_alloc(capacity: usize) -> Result<Self::Storage, TryReserveError> {
+        Columns::try_alloc(capacity)
+    }
+
+    unsafe fn realloc(
+        storage: Self::Storage,
+        current_capacity: usize,
+        new_capacity: usize,
+    ) -> Self::Storage {
+        Columns::realloc(storage, current_capacity, new_capacity)
+    }
+
+    unsafe fn dealloc(storage: Self::Storage, capacity: usize) {
+        Columns::dealloc(storage, capacity)
+    }
+
+    unsafe fn for_each_allocation(
+        storage: Self::Storage,
+        capacity: usize,
+        f: impl FnMut(*mut u8, usize),
+    ) {
+        Columns::for_each_allocation(storage, capacity, f)
+    }
+
+    fn get_vec_len(vecs: &Self::Vecs) -> Option<usize> {
+        Columns::get_vec_len(vecs)
+    }
+
+    fn get_slices_len(slices: &Self::Slices<'_>) -> Option<usize> {
+        Columns::get_slices_len(slices)
+    }
+
+    fn get_slices_ptrs(slices: Self::Slices<'_>) -> Self::Ptr {
+        Columns::get_slices_ptrs(slices)
+    }
+
+    unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr {
+        Columns::get_vec_ptrs(vecs)
+    }
+
+    unsafe fn into_vecs(src: Self::Ptr, len: usize) -> Self::Vecs {
+        Columns::into_vecs(src, len)
+    }
+
+    unsafe fn add(base: Self::Ptr, offset: usize) -> Self::Ptr {
+        Columns::add(base, offset)
+    }
+
+    unsafe fn copy_to(src: Self::Ptr, dst: Self::Ptr, size: usize) {
+        Columns::copy_to(src, dst, size)
+    }
+
+    unsafe fn copy_to_nonoverlapping(src: Self::Ptr, dst: Self::Ptr, size: usize) {
+        Columns::copy_to_nonoverlapping(src, dst, size)
+    }
+
+    unsafe fn from_rows(src: *const Self, dst: Self::Ptr, len: usize) {
+        for idx in 0..len {
+            Self::write(Self::add(dst, idx), src.add(idx).read());
+        }
+    }
+
+    unsafe fn into_rows(src: Self::Ptr, dst: *mut Self, len: usize) {
+        for idx in 0..len {
+            dst.add(idx).write(Self::read(Self::add(src, idx)));
+        }
+    }
+
+    unsafe fn as_slices<'a>(ptr: Self::Ptr, len: usize) -> Self::Slices<'a> {
+        Columns::as_slices(ptr, len)
+    }
+
+    unsafe fn as_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'a> {
+        Columns::as_slices_mut(ptr, len)
+    }
+
+    unsafe fn as_uninit_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::UninitSlicesMut<'a> {
+        Columns::as_uninit_slices_mut(ptr, len)
+    }
+
+    unsafe fn as_arrays<'a, const N: usize>(ptr: Self::Ptr) -> Self::Arrays<'a, N> {
+        Columns::as_arrays(ptr)
+    }
+
+    unsafe fn as_arrays_mut<'a, const N: usize>(ptr: Self::Ptr) -> Self::ArraysMut<'a, N> {
+        Columns::as_arrays_mut(ptr)
+    }
+
+    fn iters(slices: Self::Slices<'_>) -> Self::Iters<'_> {
+        Columns::iters(slices)
+    }
+
+    fn iters_mut(slices: Self::SlicesMut<'_>) -> Self::ItersMut<'_> {
+        Columns::iters_mut(slices)
+    }
+
+    fn reverse(slices: Self::SlicesMut<'_>) {
+        Columns::reverse(slices)
+    }
+
+    unsafe fn as_ref<'a>(ptr: Self::Ptr) -> Self::Ref<'a> {
+        let (position, name) = ptr;
+        ParticleRef {
+            position: &*position,
+            name: &*name,
+        }
+    }
+
+    fn row_ref(value: &Self) -> Self::Ref<'_> {
+        ParticleRef {
+            position: &value.position,
+            name: &value.name,
+        }
+    }
+
+    unsafe fn as_storage(ptr: Self::Ptr) -> Self::Storage {
+        Columns::as_storage(ptr)
+    }
+
+    unsafe fn as_mut<'a>(ptr: Self::Ptr) -> Self::RefMut<'a> {
+        let (position, name) = ptr;
+        ParticleMut {
+            position: &mut *position,
+            name: &mut *name,
+        }
+    }
+
+    unsafe fn read(ptr: Self::Ptr) -> Self {
+        let (position, name) = Columns::read(ptr);
+        Particle { position, name }
+    }
+
+    unsafe fn write(ptr: Self::Ptr, value: Self) {
+        Columns::write(ptr, (value.position, value.name))
+    }
+
+    unsafe fn swap(a: Self::Ptr, other: Self::Ptr) {
+        Columns::swap(a, other)
+    }
+
+    unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, count: usize) {
+        Columns::swap_nonoverlapping(a, b, count)
+    }
+
+    unsafe fn drop(ptr: Self::Ptr) {
+        Columns::drop(ptr)
+    }
+}
+
+fn particle(position: f32, name: &str) -> Particle {
+    Particle {
+        position,
+        name: String::from(name),
+    }
+}
+
+#[test]
+fn test_named_struct_param() {
+    let mut vec = ParallelVec::new();
+    vec.push(particle(1.0, "a"));
+    vec.push(particle(2.0, "b"));
+    vec.extend([particle(3.0, "c")]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(
+        vec.index(1),
+        ParticleRef {
+            position: &2.0,
+            name: &String::from("b"),
+        }
+    );
+
+    for row in vec.iter_mut() {
+        *row.position *= 10.0;
+        row.name.push('!');
+    }
+    let (positions, names) = vec.as_slices();
+    assert_eq!(positions, &[10.0, 20.0, 30.0]);
+    assert_eq!(names, &["a!", "b!", "c!"]);
+
+    let cloned = vec.clone();
+    assert_eq!(vec.pop(), Some(particle(30.0, "c!")));
+    assert_eq!(cloned.len(), 3);
+    let rows: Vec<Particle> = cloned.into();
+    assert_eq!(
+        rows,
+        vec![
+            particle(10.0, "a!"),
+            particle(20.0, "b!"),
+            particle(30.0, "c!")
+        ]
+    );
+    assert_eq!(vec.len(), 2);
+}